@@ -0,0 +1,221 @@
+// crates/a2a-agent-guest/src/lib.rs
+//! Write A2A agents as WebAssembly components.
+//!
+//! The `a2a-wasm-component` crate builds a component that serves the A2A
+//! protocol bindings and imports its agent logic through the
+//! `a2a:protocol/agent` interface. This crate builds the other half: an
+//! `a2a-agent` component that exports `a2a:protocol/agent`. Composing the two
+//! satisfies the protocol component's import, and the composed component
+//! only needs `wasi:http`, so it runs on any stock runtime:
+//!
+//! ```text
+//! cargo build -p a2a-wasm-component --target wasm32-wasip2 --release
+//! cargo build -p my-agent --target wasm32-wasip2 --release
+//! wac plug a2a_wasm_component.wasm --plug my_agent.wasm -o composed.wasm
+//! wasmtime serve -S cli composed.wasm
+//! ```
+//!
+//! # Usage
+//!
+//! Implement [`Agent`] and export it with [`export_agent!`] from a `cdylib`
+//! crate built for `wasm32-wasip2`:
+//!
+//! ```ignore
+//! use a2a_agent_guest::types::{Error, MessageSendParams, RequestContext, SendResponse, Task};
+//! use a2a_agent_guest::{Agent, export_agent};
+//!
+//! struct EchoAgent;
+//!
+//! impl Agent for EchoAgent {
+//!     fn get_agent_card(_tenant: Option<String>) -> Result<String, Error> {
+//!         Ok(r#"{"name":"echo","description":"Echo agent","version":"1.0.0"}"#.into())
+//!     }
+//!
+//!     fn on_message(
+//!         _context: RequestContext,
+//!         params: MessageSendParams,
+//!     ) -> Result<SendResponse, Error> {
+//!         Ok(SendResponse::Message(params.message))
+//!     }
+//!
+//!     fn on_get_task(
+//!         _context: RequestContext,
+//!         _name: String,
+//!         _history_length: Option<u32>,
+//!     ) -> Result<Option<Task>, Error> {
+//!         Ok(None)
+//!     }
+//!
+//!     fn on_cancel_task(_context: RequestContext, _name: String) -> Result<Option<Task>, Error> {
+//!         Ok(None)
+//!     }
+//! }
+//!
+//! export_agent!(EchoAgent);
+//! ```
+//!
+//! See `examples/echo-agent` in the repository for a complete agent, including streaming.
+//!
+//! Runtimes instantiate the composed component per request, so the agent
+//! can't keep state in memory across requests; keep it in an outside store.
+
+use std::cell::RefCell;
+
+#[doc(hidden)]
+pub mod bindings {
+    wit_bindgen::generate!({
+        world: "a2a-agent",
+        path: "../a2a-wasm-component/wit",
+        pub_export_macro: true,
+        export_macro_name: "export",
+        default_bindings_module: "a2a_agent_guest::bindings",
+    });
+}
+
+use bindings::exports::a2a::protocol::agent as agent_exports;
+
+/// The WIT types the agent interface is written in.
+pub use bindings::a2a::protocol::types;
+
+use types::{Error, MessageSendParams, RequestContext, SendResponse, StreamResponse, Task};
+
+include!("../../a2a-wasm-component/sdk/agent.rs");
+
+/// Agent logic exported as `a2a:protocol/agent`.
+///
+#[doc = include_str!("../../a2a-wasm-component/sdk/agent.md")]
+pub trait Agent: 'static {
+    /// The agent card, as JSON.
+    fn get_agent_card(tenant: Option<String>) -> Result<String, Error>;
+
+    /// Handle a message, returning the task or message it produced.
+    fn on_message(
+        context: RequestContext,
+        params: MessageSendParams,
+    ) -> Result<SendResponse, Error>;
+
+    /// Handle a message, streaming the events it produces.
+    fn on_message_stream(
+        context: RequestContext,
+        params: MessageSendParams,
+    ) -> Result<EventStream, Error> {
+        let _ = (context, params);
+        Err(message_stream_unsupported())
+    }
+
+    /// Get a task by resource name (`tasks/{task_id}`).
+    fn on_get_task(
+        context: RequestContext,
+        name: String,
+        history_length: Option<u32>,
+    ) -> Result<Option<Task>, Error>;
+
+    /// Cancel a task by resource name (`tasks/{task_id}`).
+    fn on_cancel_task(context: RequestContext, name: String) -> Result<Option<Task>, Error>;
+
+    /// Stream the events of an existing task, by resource name
+    /// (`tasks/{task_id}`).
+    fn on_subscribe_task(context: RequestContext, name: String) -> Result<EventStream, Error> {
+        let _ = (context, name);
+        Err(subscribe_task_unsupported())
+    }
+}
+
+/// Events of a streaming request, exported as an `event-stream` resource.
+///
+/// The protocol component pulls one event at a time and relays it to the
+/// client before asking for the next, so events can be produced lazily. An
+/// error ends the stream.
+pub struct EventStream {
+    events: RefCell<Box<dyn Iterator<Item = Result<StreamResponse, Error>>>>,
+}
+
+impl EventStream {
+    /// Wrap an iterator of events.
+    pub fn new<I>(events: I) -> Self
+    where
+        I: IntoIterator<Item = Result<StreamResponse, Error>>,
+        I::IntoIter: 'static,
+    {
+        Self {
+            events: RefCell::new(Box::new(events.into_iter())),
+        }
+    }
+
+    /// A stream of events that can't fail.
+    pub fn from_events<I>(events: I) -> Self
+    where
+        I: IntoIterator<Item = StreamResponse>,
+        I::IntoIter: 'static,
+    {
+        Self::new(events.into_iter().map(Ok))
+    }
+}
+
+impl agent_exports::GuestEventStream for EventStream {
+    fn next(&self) -> Result<Option<StreamResponse>, Error> {
+        self.events.borrow_mut().next().transpose()
+    }
+}
+
+impl<A: Agent> agent_exports::Guest for A {
+    type EventStream = EventStream;
+
+    fn get_agent_card(tenant: Option<String>) -> Result<String, Error> {
+        A::get_agent_card(tenant)
+    }
+
+    fn on_message(
+        context: RequestContext,
+        params: MessageSendParams,
+    ) -> Result<SendResponse, Error> {
+        A::on_message(context, params)
+    }
+
+    fn on_message_stream(
+        context: RequestContext,
+        params: MessageSendParams,
+    ) -> Result<agent_exports::EventStream, Error> {
+        A::on_message_stream(context, params).map(agent_exports::EventStream::new)
+    }
+
+    fn on_get_task(
+        context: RequestContext,
+        name: String,
+        history_length: Option<u32>,
+    ) -> Result<Option<Task>, Error> {
+        A::on_get_task(context, name, history_length)
+    }
+
+    fn on_cancel_task(context: RequestContext, name: String) -> Result<Option<Task>, Error> {
+        A::on_cancel_task(context, name)
+    }
+
+    fn on_subscribe_task(
+        context: RequestContext,
+        name: String,
+    ) -> Result<agent_exports::EventStream, Error> {
+        A::on_subscribe_task(context, name).map(agent_exports::EventStream::new)
+    }
+}
+
+/// Export an [`Agent`] implementation as the component's
+/// `a2a:protocol/agent`.
+///
+/// # Example
+///
+/// ```ignore
+/// struct MyAgent;
+///
+/// impl a2a_agent_guest::Agent for MyAgent {
+///     // ...
+/// }
+///
+/// a2a_agent_guest::export_agent!(MyAgent);
+/// ```
+#[macro_export]
+macro_rules! export_agent {
+    ($agent:ident) => {
+        $crate::bindings::export!($agent with_types_in $crate::bindings);
+    };
+}
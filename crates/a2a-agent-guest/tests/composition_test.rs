@@ -0,0 +1,240 @@
+//! Composition tests for agent components.
+//!
+//! These tests compose the example echo agent with the A2A protocol
+//! component, as `wac plug` does, and serve the composed component with a
+//! plain `wasi:http` host: the same setup as `wasmtime serve`, with no agent
+//! import left for the host to provide.
+//!
+//! Run with: `cargo test -p a2a-agent-guest --test composition_test`
+//!
+//! Prerequisites:
+//! - Build the protocol component: `cargo build -p a2a-wasm-component --target wasm32-wasip2 --release`
+//! - Build the echo agent: `cargo build -p echo-agent --target wasm32-wasip2 --release`
+
+use std::sync::OnceLock;
+
+use http_body_util::{BodyExt, Full};
+use hyper::body::Bytes;
+use hyper::{Request, Response};
+use wac_graph::types::Package;
+use wac_graph::{CompositionGraph, EncodeOptions};
+use wasmtime::component::types::ComponentItem;
+use wasmtime::component::{Component, Linker, ResourceTable};
+use wasmtime::{Config, Engine, Store};
+use wasmtime_wasi::{WasiCtx, WasiCtxBuilder, WasiView};
+use wasmtime_wasi_http::bindings::ProxyPre;
+use wasmtime_wasi_http::bindings::http::types::Scheme;
+use wasmtime_wasi_http::{WasiHttpCtx, WasiHttpView};
+
+/// Path to the compiled protocol component.
+const COMPONENT_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../target/wasm32-wasip2/release/a2a_wasm_component.wasm"
+);
+
+/// Path to the compiled echo agent.
+const AGENT_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../target/wasm32-wasip2/release/echo_agent.wasm"
+);
+
+/// Plug the echo agent into the protocol component's `agent` import.
+fn compose() -> Vec<u8> {
+    let mut graph = CompositionGraph::new();
+    let socket = Package::from_file("a2a:component", None, COMPONENT_PATH, graph.types_mut())
+        .expect("Failed to load protocol component");
+    let socket = graph.register_package(socket).unwrap();
+    let plug = Package::from_file("a2a:echo-agent", None, AGENT_PATH, graph.types_mut())
+        .expect("Failed to load echo agent");
+    let plug = graph.register_package(plug).unwrap();
+
+    wac_graph::plug(&mut graph, vec![plug], socket).expect("Failed to plug the agent");
+    graph
+        .encode(EncodeOptions::default())
+        .expect("Failed to encode the composition")
+}
+
+struct ProxyState {
+    wasi: WasiCtx,
+    http: WasiHttpCtx,
+    table: ResourceTable,
+}
+
+impl WasiView for ProxyState {
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.wasi
+    }
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+}
+
+impl WasiHttpView for ProxyState {
+    fn ctx(&mut self) -> &mut WasiHttpCtx {
+        &mut self.http
+    }
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+}
+
+/// A `wasi:http` host for the composed component, linking only WASI.
+struct ProxyHost {
+    engine: Engine,
+    component: Component,
+    pre: ProxyPre<ProxyState>,
+}
+
+impl ProxyHost {
+    /// The host, shared by the tests since compiling the composition is slow.
+    fn get() -> &'static Self {
+        static HOST: OnceLock<ProxyHost> = OnceLock::new();
+        HOST.get_or_init(Self::new)
+    }
+
+    fn new() -> Self {
+        let mut config = Config::new();
+        config.async_support(true);
+        config.wasm_component_model(true);
+        let engine = Engine::new(&config).unwrap();
+        let component = Component::new(&engine, compose()).expect("Invalid composed component");
+
+        let mut linker = Linker::new(&engine);
+        wasmtime_wasi::add_to_linker_async(&mut linker).unwrap();
+        wasmtime_wasi_http::add_only_http_to_linker_async(&mut linker).unwrap();
+        let pre = ProxyPre::new(
+            linker
+                .instantiate_pre(&component)
+                .expect("Composed component has imports beyond WASI"),
+        )
+        .unwrap();
+
+        Self {
+            engine,
+            component,
+            pre,
+        }
+    }
+
+    /// Handle a request with a fresh instance, returning the status and body.
+    async fn handle(&self, request: Request<Full<Bytes>>) -> (u16, String) {
+        let state = ProxyState {
+            wasi: WasiCtxBuilder::new().build(),
+            http: WasiHttpCtx::new(),
+            table: ResourceTable::new(),
+        };
+        let mut store = Store::new(&self.engine, state);
+        let request = request.map(|body| body.map_err(|never| match never {}));
+        let request = store
+            .data_mut()
+            .new_incoming_request(Scheme::Http, request)
+            .unwrap();
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+        let out = store.data_mut().new_response_outparam(sender).unwrap();
+        let proxy = self.pre.instantiate_async(&mut store).await.unwrap();
+
+        let task = tokio::spawn(async move {
+            proxy
+                .wasi_http_incoming_handler()
+                .call_handle(store, request, out)
+                .await
+        });
+
+        let response: Response<_> = receiver.await.unwrap().expect("Error response");
+        let status = response.status().as_u16();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        task.await.unwrap().expect("Handler failed");
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+}
+
+fn json_rpc(method: &str, text: &str) -> Request<Full<Bytes>> {
+    let body = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": "1",
+        "method": method,
+        "params": {
+            "message": {
+                "messageId": "msg-1",
+                "role": "ROLE_USER",
+                "parts": [{"text": text}],
+            },
+        },
+    });
+    Request::post("http://localhost/")
+        .header("content-type", "application/json")
+        .body(Full::new(Bytes::from(body.to_string())))
+        .unwrap()
+}
+
+#[test]
+fn composed_component_only_imports_wasi() {
+    let host = ProxyHost::get();
+    let component_type = host.component.component_type();
+
+    for (name, item) in component_type.imports(&host.engine) {
+        if name.starts_with("wasi:") {
+            continue;
+        }
+        // Type-only interfaces such as a2a:protocol/types need no host
+        let ComponentItem::ComponentInstance(instance) = item else {
+            panic!("unexpected import {name}");
+        };
+        for (export, item) in instance.exports(&host.engine) {
+            assert!(
+                matches!(item, ComponentItem::Type(_)),
+                "unexpected import {name}#{export}"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn composed_agent_card() {
+    let host = ProxyHost::get();
+    let request = Request::get("http://localhost/.well-known/agent-card.json")
+        .body(Full::default())
+        .unwrap();
+
+    let (status, body) = host.handle(request).await;
+
+    assert_eq!(status, 200);
+    let card: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(card["name"], "echo-agent");
+}
+
+#[tokio::test]
+async fn composed_send_message_echoes() {
+    let host = ProxyHost::get();
+
+    let (status, body) = host.handle(json_rpc("SendMessage", "hello world")).await;
+
+    assert_eq!(status, 200);
+    let response: serde_json::Value = serde_json::from_str(&body).unwrap();
+    let message = &response["result"]["message"];
+    assert_eq!(message["role"], "ROLE_AGENT");
+    assert_eq!(message["parts"][0]["text"], "hello world");
+}
+
+#[tokio::test]
+async fn composed_streaming_message_yields_words() {
+    let host = ProxyHost::get();
+
+    let (status, body) = host
+        .handle(json_rpc("SendStreamingMessage", "hello world"))
+        .await;
+
+    assert_eq!(status, 200);
+    let words: Vec<String> = body
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .map(|data| {
+            let event: serde_json::Value = serde_json::from_str(data).unwrap();
+            event["result"]["message"]["parts"][0]["text"]
+                .as_str()
+                .unwrap()
+                .to_string()
+        })
+        .collect();
+    assert_eq!(words, ["hello", "world"]);
+}
@@ -8,25 +8,39 @@ use a2a_types::{AgentCard, Binding, ProtocolBinding};
 pub enum SelectedBinding {
     JsonRpc { url: String },
     Rest { url: String },
+    Grpc { url: String },
 }
 
 impl SelectedBinding {
+    /// Create a selected binding for the given protocol and endpoint URL.
+    pub fn new(binding: Binding, url: impl Into<String>) -> Self {
+        let url = url.into();
+        match binding {
+            Binding::JsonRpc => Self::JsonRpc { url },
+            Binding::Rest => Self::Rest { url },
+            Binding::Grpc => Self::Grpc { url },
+        }
+    }
+
     pub fn binding(&self) -> Binding {
         match self {
             Self::JsonRpc { .. } => Binding::JsonRpc,
             Self::Rest { .. } => Binding::Rest,
+            Self::Grpc { .. } => Binding::Grpc,
         }
     }
 
     pub fn url(&self) -> &str {
         match self {
-            Self::JsonRpc { url } | Self::Rest { url } => url,
+            Self::JsonRpc { url } | Self::Rest { url } | Self::Grpc { url } => url,
         }
     }
 }
 
 /// Default binding preference order.
-pub const DEFAULT_PREFERENCE: &[Binding] = &[Binding::JsonRpc, Binding::Rest];
+///
+/// gRPC comes last: it needs an HTTP/2 transport that exposes trailers.
+pub const DEFAULT_PREFERENCE: &[Binding] = &[Binding::JsonRpc, Binding::Rest, Binding::Grpc];
 
 /// Convert a protocol binding string to a Binding.
 fn protocol_binding_to_binding(protocol_binding: &str) -> Option<Binding> {
@@ -38,41 +52,36 @@ fn protocol_binding_to_binding(protocol_binding: &str) -> Option<Binding> {
 
 /// Extract all available interfaces from an agent card.
 ///
-/// Uses `supported_interfaces` which contains url and protocol_binding pairs.
-/// Falls back to deprecated fields (`url`, `preferred_transport`, `additional_interfaces`)
-/// for backwards compatibility.
-#[allow(deprecated)]
+/// Uses `supported_interfaces`, or for legacy cards the interfaces derived
+/// from the deprecated fields (see [`AgentCard::interfaces`]). Interfaces
+/// with an unknown protocol binding are skipped.
 pub fn extract_interfaces(card: &AgentCard) -> Vec<(String, Binding)> {
-    let mut interfaces = Vec::new();
+    card.interfaces()
+        .into_iter()
+        .filter_map(|iface| {
+            protocol_binding_to_binding(&iface.protocol_binding).map(|b| (iface.url, b))
+        })
+        .collect()
+}
 
-    // Prefer supported_interfaces if available
-    if !card.supported_interfaces.is_empty() {
-        for iface in &card.supported_interfaces {
-            if let Some(binding) = protocol_binding_to_binding(&iface.protocol_binding) {
-                interfaces.push((iface.url.clone(), binding));
+/// Rank all usable interfaces by preference order.
+///
+/// Interfaces are grouped by binding in preference order; within a binding,
+/// the agent card's interface order is kept. Bindings absent from the
+/// preference list are dropped.
+pub fn rank_bindings(
+    interfaces: &[(String, Binding)],
+    preference: &[Binding],
+) -> Vec<SelectedBinding> {
+    let mut ranked = Vec::new();
+    for preferred in preference {
+        for (url, binding) in interfaces {
+            if binding == preferred {
+                ranked.push(SelectedBinding::new(*binding, url.clone()));
             }
         }
-        return interfaces;
-    }
-
-    // Fallback to deprecated fields for backwards compatibility
-    if let Some(url) = &card.url {
-        let main_binding = card
-            .preferred_transport
-            .as_ref()
-            .and_then(|t| protocol_binding_to_binding(t))
-            .unwrap_or(Binding::JsonRpc);
-        interfaces.push((url.clone(), main_binding));
-    }
-
-    // Additional interfaces (deprecated)
-    for iface in &card.additional_interfaces {
-        if let Some(binding) = protocol_binding_to_binding(&iface.protocol_binding) {
-            interfaces.push((iface.url.clone(), binding));
-        }
     }
-
-    interfaces
+    ranked
 }
 
 /// Select a binding from available interfaces based on preference order.
@@ -83,17 +92,7 @@ pub fn select_binding(
     interfaces: &[(String, Binding)],
     preference: &[Binding],
 ) -> Option<SelectedBinding> {
-    for preferred in preference {
-        for (url, binding) in interfaces {
-            if binding == preferred {
-                return match binding {
-                    Binding::JsonRpc => Some(SelectedBinding::JsonRpc { url: url.clone() }),
-                    Binding::Rest => Some(SelectedBinding::Rest { url: url.clone() }),
-                };
-            }
-        }
-    }
-    None
+    rank_bindings(interfaces, preference).into_iter().next()
 }
 
 #[cfg(test)]
@@ -137,6 +136,70 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    #[test]
+    fn test_rank_bindings_keeps_card_order_within_binding() {
+        let interfaces = vec![
+            ("https://a.example.com/".to_string(), Binding::JsonRpc),
+            ("https://example.com/v1".to_string(), Binding::Rest),
+            ("https://b.example.com/".to_string(), Binding::JsonRpc),
+        ];
+        let result = rank_bindings(&interfaces, &[Binding::JsonRpc, Binding::Rest]);
+        assert_eq!(
+            result,
+            vec![
+                SelectedBinding::JsonRpc {
+                    url: "https://a.example.com/".to_string()
+                },
+                SelectedBinding::JsonRpc {
+                    url: "https://b.example.com/".to_string()
+                },
+                SelectedBinding::Rest {
+                    url: "https://example.com/v1".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rank_bindings_drops_unpreferred() {
+        let interfaces = vec![
+            ("https://example.com/v1".to_string(), Binding::Rest),
+            ("https://example.com/".to_string(), Binding::JsonRpc),
+        ];
+        let result = rank_bindings(&interfaces, &[Binding::Rest]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].binding(), Binding::Rest);
+    }
+
+    #[test]
+    fn test_extract_interfaces_includes_grpc() {
+        let json = r#"{
+            "name": "test",
+            "description": "test",
+            "version": "1.0",
+            "skills": [],
+            "capabilities": {},
+            "supportedInterfaces": [
+                {"url": "https://example.com:50051", "protocolBinding": "GRPC"}
+            ],
+            "defaultInputModes": [],
+            "defaultOutputModes": []
+        }"#;
+        let card: AgentCard = serde_json::from_str(json).unwrap();
+
+        let interfaces = extract_interfaces(&card);
+        assert_eq!(
+            interfaces,
+            vec![("https://example.com:50051".to_string(), Binding::Grpc)]
+        );
+        assert_eq!(
+            select_binding(&interfaces, DEFAULT_PREFERENCE),
+            Some(SelectedBinding::Grpc {
+                url: "https://example.com:50051".to_string()
+            })
+        );
+    }
+
     #[test]
     fn test_extract_interfaces_from_agent_card() {
         // Construct AgentCard via JSON using new supported_interfaces format
@@ -1,9 +1,16 @@
 //! Client builder for configuration.
 
 use crate::binding::{self, DEFAULT_PREFERENCE, SelectedBinding};
+use crate::cache::{self, AgentCardCache};
 use crate::error::{Error, Result};
-use a2a_transport::{HttpClient, HttpRequest};
+use crate::failover::{FailoverPolicy, Interfaces};
+use crate::signature::CardVerifier;
+use a2a_transport::HttpClient;
+use a2a_types::codec::Codec;
+use a2a_types::extension::ExtensionRegistry;
+use a2a_types::version::ProtocolVersion;
 use a2a_types::{AgentCard, Binding};
+use std::sync::Arc;
 
 /// Builder for configuring client behavior.
 pub struct ClientBuilder<T: HttpClient> {
@@ -11,6 +18,11 @@ pub struct ClientBuilder<T: HttpClient> {
     pub(crate) base_url: String,
     pub(crate) preference: Option<Vec<Binding>>,
     pub(crate) forced_binding: Option<Binding>,
+    pub(crate) failover: FailoverPolicy,
+    pub(crate) card_cache: Option<Arc<dyn AgentCardCache>>,
+    pub(crate) verifier: Option<CardVerifier>,
+    pub(crate) rest_codec: Codec,
+    pub(crate) extensions: ExtensionRegistry,
 }
 
 impl<T: HttpClient> ClientBuilder<T> {
@@ -21,6 +33,11 @@ impl<T: HttpClient> ClientBuilder<T> {
             base_url: base_url.into(),
             preference: None,
             forced_binding: None,
+            failover: FailoverPolicy::default(),
+            card_cache: None,
+            verifier: None,
+            rest_codec: Codec::Json,
+            extensions: ExtensionRegistry::new(),
         }
     }
 
@@ -40,64 +57,112 @@ impl<T: HttpClient> ClientBuilder<T> {
         self
     }
 
+    /// Set the failover policy.
+    ///
+    /// When an interface fails at the transport level, the client falls back
+    /// to the next interface advertised by the agent card (in preference
+    /// order) and opens that interface's circuit after repeated failures.
+    pub fn failover(mut self, policy: FailoverPolicy) -> Self {
+        self.failover = policy;
+        self
+    }
+
+    /// Cache agent cards.
+    ///
+    /// The card is reused while fresh per its `Cache-Control` header and
+    /// revalidated with its `ETag` once stale. The same cache can be shared
+    /// across clients.
+    pub fn card_cache(mut self, cache: Arc<dyn AgentCardCache>) -> Self {
+        self.card_cache = Some(cache);
+        self
+    }
+
+    /// Verify agent card signatures.
+    ///
+    /// Cards that are unsigned (unless allowed by the verifier) or whose
+    /// signatures don't verify against a trusted key are refused, both when
+    /// building and when refreshing the card.
+    pub fn verify_card(mut self, verifier: CardVerifier) -> Self {
+        self.verifier = Some(verifier);
+        self
+    }
+
+    /// Set the body encoding of unary REST requests.
+    ///
+    /// With [`Codec::Protobuf`] requests are sent as `application/x-protobuf`
+    /// and protobuf responses are preferred. Once an agent answers `415
+    /// Unsupported Media Type`, the request is retried and later requests
+    /// are sent with JSON.
+    pub fn rest_codec(mut self, codec: Codec) -> Self {
+        self.rest_codec = codec;
+        self
+    }
+
+    /// Support an extension.
+    ///
+    /// Supported extensions the agent card declares are requested on every
+    /// call via the `X-A2A-Extensions` header. Building fails if the card
+    /// requires an extension that isn't supported.
+    pub fn extension(mut self, uri: impl Into<String>) -> Self {
+        self.extensions = self.extensions.register(uri);
+        self
+    }
+
     /// Build the client by discovering the agent and selecting a binding.
+    ///
+    /// The card is fetched from `/.well-known/agent-card.json`, falling back
+    /// to the pre-1.0 `/.well-known/agent.json`; legacy interface fields are
+    /// normalized into `supported_interfaces`.
     pub async fn build(self) -> Result<crate::Client<T>> {
         // Fetch agent card
-        let agent_card = self.discover_agent().await?;
-
-        // Extract available interfaces
-        let interfaces = binding::extract_interfaces(&agent_card);
-
-        // Select binding
-        let selected_binding = if let Some(forced) = self.forced_binding {
-            // Forced binding - must be available
-            interfaces
-                .iter()
-                .find(|(_, b)| *b == forced)
-                .map(|(url, b)| match b {
-                    Binding::JsonRpc => SelectedBinding::JsonRpc { url: url.clone() },
-                    Binding::Rest => SelectedBinding::Rest { url: url.clone() },
-                })
-                .ok_or_else(|| Error::NoCompatibleBinding {
-                    available: interfaces.iter().map(|(_, b)| *b).collect(),
-                })?
-        } else {
-            let pref = self.preference.as_deref().unwrap_or(DEFAULT_PREFERENCE);
-            binding::select_binding(&interfaces, pref).ok_or_else(|| {
-                Error::NoCompatibleBinding {
-                    available: interfaces.iter().map(|(_, b)| *b).collect(),
-                }
-            })?
+        let agent_card = cache::fetch_agent_card(
+            &self.transport,
+            &self.base_url,
+            self.card_cache.as_deref(),
+            self.verifier.as_ref(),
+        )
+        .await?;
+
+        // Forced binding - only its interfaces are candidates
+        let preference = match self.forced_binding {
+            Some(forced) => vec![forced],
+            None => self
+                .preference
+                .unwrap_or_else(|| DEFAULT_PREFERENCE.to_vec()),
         };
+        let ranked = rank_card(&agent_card, &preference)?;
+        let protocol_version = ProtocolVersion::of(&agent_card);
+        let requested_extensions = self.extensions.request(&agent_card)?;
 
         Ok(crate::Client {
             transport: self.transport,
+            base_url: self.base_url,
             agent_card,
-            binding: selected_binding,
+            card_cache: self.card_cache,
+            verifier: self.verifier,
+            preference,
+            interfaces: Interfaces::new(ranked, self.failover),
+            protocol_version,
+            rest_codec: self.rest_codec,
+            rest_json_only: Default::default(),
+            extensions: self.extensions,
+            requested_extensions,
             request_id: std::sync::atomic::AtomicU64::new(1),
         })
     }
+}
 
-    async fn discover_agent(&self) -> Result<AgentCard> {
-        let url = format!(
-            "{}/.well-known/agent-card.json",
-            self.base_url.trim_end_matches('/')
-        );
-        let request = HttpRequest::get(&url).with_header("Accept", "application/json");
-
-        let response = self
-            .transport
-            .request(request)
-            .await
-            .map_err(|e| Error::Transport(e.to_string()))?;
-
-        if response.status != 200 {
-            return Err(Error::AgentNotFound(url));
-        }
+/// Rank the interfaces of an agent card by preference order.
+pub(crate) fn rank_card(card: &AgentCard, preference: &[Binding]) -> Result<Vec<SelectedBinding>> {
+    let interfaces = binding::extract_interfaces(card);
+    let ranked = binding::rank_bindings(&interfaces, preference);
 
-        let agent_card: AgentCard = serde_json::from_slice(&response.body)?;
-        Ok(agent_card)
+    if ranked.is_empty() {
+        return Err(Error::NoCompatibleBinding {
+            available: interfaces.iter().map(|(_, b)| *b).collect(),
+        });
     }
+    Ok(ranked)
 }
 
 #[cfg(test)]
@@ -111,7 +176,7 @@ mod tests {
     struct EmptyStream;
 
     impl futures_core::Stream for EmptyStream {
-        type Item = std::result::Result<bytes::Bytes, a2a_transport::Error>;
+        type Item = std::result::Result<a2a_transport::BodyFrame, a2a_transport::Error>;
 
         fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
             Poll::Ready(None)
@@ -138,11 +203,19 @@ mod tests {
             _req: a2a_transport::HttpRequest,
         ) -> impl std::future::Future<
             Output = std::result::Result<
-                impl futures_core::Stream<Item = std::result::Result<bytes::Bytes, Self::Error>> + Send,
+                a2a_transport::StreamingHttpResponse<
+                    impl futures_core::Stream<
+                        Item = std::result::Result<a2a_transport::BodyFrame, Self::Error>,
+                    > + Send,
+                >,
                 Self::Error,
             >,
         > + Send {
-            async { Err::<EmptyStream, _>(a2a_transport::Error::Connection("mock".to_string())) }
+            async {
+                Err::<a2a_transport::StreamingHttpResponse<EmptyStream>, _>(
+                    a2a_transport::Error::Connection("mock".to_string()),
+                )
+            }
         }
     }
 
@@ -168,4 +241,21 @@ mod tests {
             ClientBuilder::new(MockTransport, "https://example.com").binding(Binding::Rest);
         assert_eq!(builder.forced_binding, Some(Binding::Rest));
     }
+
+    #[test]
+    fn test_builder_failover_policy() {
+        let policy = FailoverPolicy {
+            failure_threshold: 1,
+            reset_timeout: std::time::Duration::from_secs(5),
+        };
+        let builder = ClientBuilder::new(MockTransport, "https://example.com").failover(policy);
+        assert_eq!(builder.failover, policy);
+    }
+
+    #[test]
+    fn test_builder_card_cache() {
+        let builder = ClientBuilder::new(MockTransport, "https://example.com")
+            .card_cache(Arc::new(crate::cache::InMemoryCardCache::new()));
+        assert!(builder.card_cache.is_some());
+    }
 }
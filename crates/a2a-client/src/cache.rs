@@ -0,0 +1,528 @@
+// crates/a2a-client/src/cache.rs
+//! Agent card caching.
+//!
+//! Agent cards are fetched from `/.well-known/agent-card.json`. With a cache
+//! configured, a card is reused while it is fresh according to the
+//! response's `Cache-Control: max-age`, and revalidated with `If-None-Match`
+//! once it is stale. `no-store` responses are never cached.
+//!
+//! Agents that don't serve a card there are looked up at the pre-1.0 path,
+//! `/.well-known/agent.json`.
+
+use crate::error::{Error, Result};
+use crate::signature::CardVerifier;
+use a2a_transport::{HttpClient, HttpRequest, HttpResponse};
+use a2a_types::AgentCard;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+/// A cached agent card with its HTTP validators.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedCard {
+    pub card: AgentCard,
+    /// `ETag` of the response the card came from.
+    pub etag: Option<String>,
+    /// When the card becomes stale; `None` means it must always be revalidated.
+    pub expires_at: Option<SystemTime>,
+    /// [`CardVerifier::fingerprint`] of the verifier that accepted the card
+    /// when it was fetched; `None` if it wasn't verified.
+    pub verified_by: Option<String>,
+}
+
+impl CachedCard {
+    /// Whether the card can be used without contacting the agent.
+    pub fn is_fresh(&self, now: SystemTime) -> bool {
+        self.expires_at.is_some_and(|expires| now < expires)
+    }
+}
+
+/// Storage for agent cards, keyed by card URL.
+///
+/// Implement this to share cards across clients or processes.
+pub trait AgentCardCache: Send + Sync {
+    /// Look up the card stored for a URL.
+    fn get(&self, url: &str) -> Option<CachedCard>;
+
+    /// Store the card for a URL, replacing any previous entry.
+    fn put(&self, url: &str, entry: CachedCard);
+
+    /// Remove the card stored for a URL.
+    fn remove(&self, url: &str);
+}
+
+/// In-memory agent card cache.
+#[derive(Debug, Default)]
+pub struct InMemoryCardCache {
+    entries: Mutex<HashMap<String, CachedCard>>,
+}
+
+impl InMemoryCardCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl AgentCardCache for InMemoryCardCache {
+    fn get(&self, url: &str) -> Option<CachedCard> {
+        self.entries.lock().unwrap().get(url).cloned()
+    }
+
+    fn put(&self, url: &str, entry: CachedCard) {
+        self.entries.lock().unwrap().insert(url.to_string(), entry);
+    }
+
+    fn remove(&self, url: &str) {
+        self.entries.lock().unwrap().remove(url);
+    }
+}
+
+/// Largest `max-age` honoured, in seconds; larger ages are clamped to it as
+/// RFC 9111 (section 1.2.2) recommends.
+const MAX_AGE_LIMIT: u64 = 1 << 31;
+
+/// Caching directives of a `Cache-Control` header.
+#[derive(Debug, Default, PartialEq)]
+struct CacheControl {
+    no_store: bool,
+    no_cache: bool,
+    max_age: Option<u64>,
+}
+
+impl CacheControl {
+    fn parse(value: &str) -> Self {
+        let mut directives = Self::default();
+        for directive in value.split(',') {
+            let directive = directive.trim();
+            let (name, arg) = match directive.split_once('=') {
+                Some((name, arg)) => (name.trim(), Some(arg.trim().trim_matches('"'))),
+                None => (directive, None),
+            };
+            match name.to_ascii_lowercase().as_str() {
+                "no-store" => directives.no_store = true,
+                "no-cache" => directives.no_cache = true,
+                "max-age" => directives.max_age = arg.and_then(|a| a.parse().ok()),
+                _ => {}
+            }
+        }
+        directives
+    }
+
+    fn expires_at(&self, now: SystemTime) -> Option<SystemTime> {
+        if self.no_cache {
+            return None;
+        }
+        // An age the clock can't represent isn't cacheable
+        self.max_age
+            .and_then(|age| now.checked_add(Duration::from_secs(age.min(MAX_AGE_LIMIT))))
+    }
+}
+
+fn cache_control(response: &HttpResponse) -> CacheControl {
+    response
+        .header("Cache-Control")
+        .map(CacheControl::parse)
+        .unwrap_or_default()
+}
+
+/// URL of an agent's well-known card.
+pub fn card_url(base_url: &str) -> String {
+    format!(
+        "{}/.well-known/agent-card.json",
+        base_url.trim_end_matches('/')
+    )
+}
+
+/// URL of an agent's card under the pre-1.0 well-known path.
+pub fn legacy_card_url(base_url: &str) -> String {
+    format!("{}/.well-known/agent.json", base_url.trim_end_matches('/'))
+}
+
+/// Fetch an agent card, going through the cache when one is given.
+///
+/// Falls back to the legacy path when the agent has no card at the current
+/// one. With a verifier, fetched cards are verified before use and cached
+/// cards are only reused if a verifier with the same trust accepted them.
+pub(crate) async fn fetch_agent_card<T: HttpClient>(
+    transport: &T,
+    base_url: &str,
+    cache: Option<&dyn AgentCardCache>,
+    verifier: Option<&CardVerifier>,
+) -> Result<AgentCard> {
+    let url = card_url(base_url);
+    if let Some(card) = fetch_card_at(transport, &url, cache, verifier).await? {
+        return Ok(card);
+    }
+    fetch_card_at(transport, &legacy_card_url(base_url), cache, verifier)
+        .await?
+        .ok_or(Error::AgentNotFound(url))
+}
+
+/// Fetch the card at `url`; `None` when the agent has no card there.
+async fn fetch_card_at<T: HttpClient>(
+    transport: &T,
+    url: &str,
+    cache: Option<&dyn AgentCardCache>,
+    verifier: Option<&CardVerifier>,
+) -> Result<Option<AgentCard>> {
+    let now = SystemTime::now();
+    let fingerprint = verifier.map(CardVerifier::fingerprint);
+
+    let cached = cache
+        .and_then(|c| c.get(url))
+        .filter(|entry| fingerprint.is_none() || entry.verified_by == fingerprint);
+    if let Some(entry) = &cached
+        && entry.is_fresh(now)
+    {
+        return Ok(Some(entry.card.clone()));
+    }
+
+    let mut request = HttpRequest::get(url).with_header("Accept", "application/json");
+    if let Some(etag) = cached.as_ref().and_then(|e| e.etag.as_deref()) {
+        request = request.with_header("If-None-Match", etag);
+    }
+
+    let response = transport
+        .request(request)
+        .await
+        .map_err(Error::from_transport::<T>)?;
+    let directives = cache_control(&response);
+
+    let entry = match (response.status, cached) {
+        (304, Some(entry)) => CachedCard {
+            expires_at: directives.expires_at(now),
+            etag: response.header("ETag").map(str::to_string).or(entry.etag),
+            ..entry
+        },
+        (200, _) => {
+            // Signatures cover the card as published, so verify the raw JSON.
+            let json: serde_json::Value = serde_json::from_slice(&response.body)?;
+            if let Some(verifier) = verifier {
+                verifier.verify(transport, &json).await?;
+            }
+            CachedCard {
+                card: a2a_types::card::from_json(json)?,
+                etag: response.header("ETag").map(str::to_string),
+                expires_at: directives.expires_at(now),
+                verified_by: fingerprint,
+            }
+        }
+        (404, _) => return Ok(None),
+        _ => return Err(Error::AgentNotFound(url.to_string())),
+    };
+
+    if let Some(cache) = cache {
+        if directives.no_store {
+            cache.remove(url);
+        } else {
+            cache.put(url, entry.clone());
+        }
+    }
+    Ok(Some(entry.card))
+}
+
+#[cfg(test)]
+#[allow(clippy::manual_async_fn)] // Mock trait impl matches HttpClient trait signature
+mod tests {
+    use super::*;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    const CARD: &str = r#"{
+        "name": "test",
+        "description": "test",
+        "version": "1.0",
+        "capabilities": {},
+        "supportedInterfaces": [],
+        "defaultInputModes": [],
+        "defaultOutputModes": [],
+        "skills": []
+    }"#;
+
+    struct EmptyStream;
+
+    impl futures_core::Stream for EmptyStream {
+        type Item = std::result::Result<a2a_transport::BodyFrame, a2a_transport::Error>;
+
+        fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(None)
+        }
+    }
+
+    /// Serves the card with fixed caching headers and honors `If-None-Match`.
+    struct CardServer {
+        cache_control: &'static str,
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    impl CardServer {
+        fn new(cache_control: &'static str) -> Self {
+            Self {
+                cache_control,
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn conditional_requests(&self) -> Vec<bool> {
+            self.requests
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|r| r.headers.iter().any(|(n, _)| n == "If-None-Match"))
+                .collect()
+        }
+    }
+
+    impl HttpClient for CardServer {
+        type Error = a2a_transport::Error;
+
+        fn request(
+            &self,
+            req: HttpRequest,
+        ) -> impl std::future::Future<Output = std::result::Result<HttpResponse, Self::Error>> + Send
+        {
+            let revalidating = req
+                .headers
+                .iter()
+                .any(|(n, v)| n == "If-None-Match" && v == "\"v1\"");
+            self.requests.lock().unwrap().push(req);
+            let response = if revalidating {
+                HttpResponse::ok(Vec::new()).with_status(304)
+            } else {
+                HttpResponse::ok(CARD).with_header("ETag", "\"v1\"")
+            };
+            let response = response.with_header("Cache-Control", self.cache_control);
+            async move { Ok(response) }
+        }
+
+        fn request_stream(
+            &self,
+            _req: HttpRequest,
+        ) -> impl std::future::Future<
+            Output = std::result::Result<
+                a2a_transport::StreamingHttpResponse<
+                    impl futures_core::Stream<
+                        Item = std::result::Result<a2a_transport::BodyFrame, Self::Error>,
+                    > + Send,
+                >,
+                Self::Error,
+            >,
+        > + Send {
+            async {
+                Err::<a2a_transport::StreamingHttpResponse<EmptyStream>, _>(
+                    a2a_transport::Error::Connection("mock".to_string()),
+                )
+            }
+        }
+    }
+
+    /// A pre-1.0 agent: legacy card shape, served at the legacy path only.
+    struct LegacyServer {
+        requested: Mutex<Vec<String>>,
+    }
+
+    impl HttpClient for LegacyServer {
+        type Error = a2a_transport::Error;
+
+        fn request(
+            &self,
+            req: HttpRequest,
+        ) -> impl std::future::Future<Output = std::result::Result<HttpResponse, Self::Error>> + Send
+        {
+            let response = if req.url.ends_with("/.well-known/agent.json") {
+                HttpResponse::ok(
+                    r#"{
+                        "name": "legacy",
+                        "description": "test",
+                        "version": "1.0",
+                        "url": "http://agent.test/a2a",
+                        "preferredTransport": "JSONRPC",
+                        "additionalInterfaces": [
+                            {"url": "http://agent.test/v1", "transport": "HTTP+JSON"}
+                        ],
+                        "capabilities": {},
+                        "defaultInputModes": [],
+                        "defaultOutputModes": [],
+                        "skills": []
+                    }"#,
+                )
+            } else {
+                HttpResponse::ok(Vec::new()).with_status(404)
+            };
+            self.requested.lock().unwrap().push(req.url);
+            async move { Ok(response) }
+        }
+
+        fn request_stream(
+            &self,
+            _req: HttpRequest,
+        ) -> impl std::future::Future<
+            Output = std::result::Result<
+                a2a_transport::StreamingHttpResponse<
+                    impl futures_core::Stream<
+                        Item = std::result::Result<a2a_transport::BodyFrame, Self::Error>,
+                    > + Send,
+                >,
+                Self::Error,
+            >,
+        > + Send {
+            async {
+                Err::<a2a_transport::StreamingHttpResponse<EmptyStream>, _>(
+                    a2a_transport::Error::Connection("mock".to_string()),
+                )
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_cache_control() {
+        assert_eq!(
+            CacheControl::parse("public, max-age=600"),
+            CacheControl {
+                max_age: Some(600),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            CacheControl::parse("no-cache, NO-STORE"),
+            CacheControl {
+                no_store: true,
+                no_cache: true,
+                max_age: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_no_cache_always_revalidates() {
+        let now = SystemTime::now();
+        assert_eq!(
+            CacheControl::parse("no-cache, max-age=60").expires_at(now),
+            None
+        );
+        assert_eq!(
+            CacheControl::parse("max-age=60").expires_at(now),
+            Some(now + Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn test_huge_max_age_is_clamped() {
+        let now = SystemTime::now();
+        let max_age = format!("max-age={}", u64::MAX);
+        assert_eq!(
+            CacheControl::parse(&max_age).expires_at(now),
+            Some(now + Duration::from_secs(MAX_AGE_LIMIT))
+        );
+        // A clock near its end can't represent even the clamped age
+        let end = SystemTime::UNIX_EPOCH + Duration::from_secs(i64::MAX as u64);
+        assert_eq!(CacheControl::parse(&max_age).expires_at(end), None);
+    }
+
+    #[tokio::test]
+    async fn test_fresh_card_is_served_from_cache() {
+        let server = CardServer::new("max-age=3600");
+        let cache = InMemoryCardCache::new();
+
+        fetch_agent_card(&server, "http://agent.test", Some(&cache), None)
+            .await
+            .unwrap();
+        fetch_agent_card(&server, "http://agent.test", Some(&cache), None)
+            .await
+            .unwrap();
+
+        assert_eq!(server.conditional_requests(), vec![false]);
+    }
+
+    #[tokio::test]
+    async fn test_stale_card_is_revalidated() {
+        let server = CardServer::new("max-age=0");
+        let cache = InMemoryCardCache::new();
+
+        fetch_agent_card(&server, "http://agent.test", Some(&cache), None)
+            .await
+            .unwrap();
+        let card = fetch_agent_card(&server, "http://agent.test", Some(&cache), None)
+            .await
+            .unwrap();
+
+        assert_eq!(card.name, "test");
+        assert_eq!(server.conditional_requests(), vec![false, true]);
+        let entry = cache.get(&card_url("http://agent.test")).unwrap();
+        assert_eq!(entry.etag.as_deref(), Some("\"v1\""));
+    }
+
+    #[tokio::test]
+    async fn test_cards_verified_under_other_trust_are_refetched() {
+        let server = CardServer::new("max-age=3600");
+        let cache = InMemoryCardCache::new();
+        let verifier = CardVerifier::new().allow_unsigned(true);
+        let other = CardVerifier::new()
+            .jwks_url("https://keys.example.com/jwks.json")
+            .allow_unsigned(true);
+
+        fetch_agent_card(&server, "http://agent.test", Some(&cache), Some(&verifier))
+            .await
+            .unwrap();
+        fetch_agent_card(&server, "http://agent.test", Some(&cache), Some(&verifier))
+            .await
+            .unwrap();
+        assert_eq!(server.requests.lock().unwrap().len(), 1);
+
+        fetch_agent_card(&server, "http://agent.test", Some(&cache), Some(&other))
+            .await
+            .unwrap();
+        assert_eq!(server.conditional_requests(), vec![false, false]);
+        let entry = cache.get(&card_url("http://agent.test")).unwrap();
+        assert_eq!(entry.verified_by, Some(other.fingerprint()));
+
+        // Unverifying clients may use any cached card
+        fetch_agent_card(&server, "http://agent.test", Some(&cache), None)
+            .await
+            .unwrap();
+        assert_eq!(server.requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_store_is_not_cached() {
+        let server = CardServer::new("no-store");
+        let cache = InMemoryCardCache::new();
+
+        fetch_agent_card(&server, "http://agent.test", Some(&cache), None)
+            .await
+            .unwrap();
+
+        assert!(cache.get(&card_url("http://agent.test")).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_legacy_card_path() {
+        let server = LegacyServer {
+            requested: Mutex::new(Vec::new()),
+        };
+
+        let card = fetch_agent_card(&server, "http://agent.test/", None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *server.requested.lock().unwrap(),
+            vec![
+                card_url("http://agent.test"),
+                legacy_card_url("http://agent.test")
+            ]
+        );
+        let interfaces: Vec<_> = card
+            .supported_interfaces
+            .iter()
+            .map(|i| (i.url.as_str(), i.protocol_binding.as_str()))
+            .collect();
+        assert_eq!(
+            interfaces,
+            vec![
+                ("http://agent.test/a2a", "JSONRPC"),
+                ("http://agent.test/v1", "HTTP+JSON"),
+            ]
+        );
+    }
+}
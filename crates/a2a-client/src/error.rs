@@ -1,7 +1,10 @@
 // crates/a2a-client/src/error.rs
+use a2a_transport::HttpClient;
 use a2a_types::{Binding, TaskId};
 use thiserror::Error;
 
+pub use a2a_types::grpc::StatusCode as GrpcStatusCode;
+
 /// Client errors with layered protocol details.
 #[derive(Debug, Error)]
 pub enum Error {
@@ -24,14 +27,70 @@ pub enum Error {
         source: ProtocolError,
     },
 
+    /// The request failed in transport, possibly after it was sent.
     #[error("transport error: {0}")]
     Transport(String),
 
+    /// The request failed before it was sent, e.g. the connection was
+    /// refused, so the agent never saw it.
+    #[error("connection failed: {0}")]
+    Connect(String),
+
     #[error("json error: {0}")]
     Json(#[from] serde_json::Error),
 
+    #[error("protobuf error: {0}")]
+    Protobuf(#[from] prost::DecodeError),
+
     #[error("invalid url: {0}")]
     InvalidUrl(String),
+
+    #[error("agent card rejected: {0}")]
+    Signature(#[from] crate::signature::SignatureError),
+
+    #[error("extension negotiation failed: {0}")]
+    Extension(#[from] a2a_types::extension::ExtensionError),
+}
+
+/// JSON-RPC error code of an unknown task or push notification config.
+const TASK_NOT_FOUND: i32 = -32001;
+
+impl Error {
+    /// The error for a failed call of the `T` transport.
+    pub(crate) fn from_transport<T: HttpClient>(error: T::Error) -> Self {
+        if T::is_connect_error(&error) {
+            Self::Connect(error.to_string())
+        } else {
+            Self::Transport(error.to_string())
+        }
+    }
+
+    /// Whether the agent answered that the task or push notification
+    /// config doesn't exist, on any binding.
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Self::TaskNotFound(_) => true,
+            Self::Agent { source, .. } => match source {
+                ProtocolError::JsonRpc { code, .. } => code.code() == TASK_NOT_FOUND,
+                ProtocolError::Rest { status, .. } => *status == 404,
+                ProtocolError::Grpc { code, .. } => *code == GrpcStatusCode::NotFound,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl From<a2a_types::error::Error> for Error {
+    fn from(error: a2a_types::error::Error) -> Self {
+        match error {
+            a2a_types::error::Error::Json(e) => Self::Json(e),
+            a2a_types::error::Error::Protobuf(e) => Self::Protobuf(e),
+            other => Self::InvalidParams(ParamError::InvalidValue {
+                field: "message",
+                reason: other.to_string(),
+            }),
+        }
+    }
 }
 
 /// Parameter validation error.
@@ -59,6 +118,12 @@ pub enum ProtocolError {
         status: u16,
         body: Option<serde_json::Value>,
     },
+
+    #[error("gRPC error {code}: {message}")]
+    Grpc {
+        code: GrpcStatusCode,
+        message: String,
+    },
 }
 
 /// Standard JSON-RPC error codes.
@@ -0,0 +1,333 @@
+// crates/a2a-client/src/failover.rs
+//! Interface failover with a circuit breaker per interface.
+//!
+//! An agent card may advertise several interfaces (e.g. JSON-RPC and
+//! HTTP+JSON). The client ranks them once at build time and then, for each
+//! request, tries them in rank order, skipping interfaces whose circuit is
+//! open. A circuit opens after `failure_threshold` consecutive failures
+//! (transport errors, 5xx and unparsable answers) and half-opens again once `reset_timeout` has elapsed, so a
+//! recovered preferred interface is picked up again automatically.
+
+use crate::binding::SelectedBinding;
+use crate::error::{Error, GrpcStatusCode, ProtocolError};
+use a2a_types::version::JsonRpcMethod;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Failover configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FailoverPolicy {
+    /// Consecutive transport failures before an interface's circuit opens.
+    pub failure_threshold: u32,
+    /// How long an open circuit rejects requests before it is retried.
+    pub reset_timeout: Duration,
+}
+
+impl Default for FailoverPolicy {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 3,
+            reset_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Circuit breaker state of an interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally.
+    Closed,
+    /// Too many recent failures; requests skip this interface.
+    Open,
+    /// The reset timeout elapsed; the next request probes this interface.
+    HalfOpen,
+}
+
+/// Per-interface circuit breaker.
+#[derive(Debug, Default)]
+struct CircuitBreaker {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    fn state(&self, policy: &FailoverPolicy, now: Instant) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(opened) if now.duration_since(opened) >= policy.reset_timeout => {
+                CircuitState::HalfOpen
+            }
+            Some(_) => CircuitState::Open,
+        }
+    }
+
+    fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.opened_at = None;
+    }
+
+    fn record_failure(&mut self, policy: &FailoverPolicy, now: Instant) {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        // A failed probe while half-open re-opens the circuit immediately.
+        if self.opened_at.is_some() || self.consecutive_failures >= policy.failure_threshold {
+            self.opened_at = Some(now);
+        }
+    }
+}
+
+struct Entry {
+    binding: SelectedBinding,
+    breaker: Mutex<CircuitBreaker>,
+}
+
+/// Ranked interfaces of an agent with their circuit breakers.
+pub(crate) struct Interfaces {
+    entries: Vec<Entry>,
+    active: AtomicUsize,
+    policy: FailoverPolicy,
+}
+
+impl Interfaces {
+    /// Create from bindings in rank order (most preferred first).
+    ///
+    /// `bindings` must not be empty.
+    pub(crate) fn new(bindings: Vec<SelectedBinding>, policy: FailoverPolicy) -> Self {
+        debug_assert!(!bindings.is_empty());
+        Self {
+            entries: bindings
+                .into_iter()
+                .map(|binding| Entry {
+                    binding,
+                    breaker: Mutex::new(CircuitBreaker::default()),
+                })
+                .collect(),
+            active: AtomicUsize::new(0),
+            policy,
+        }
+    }
+
+    pub(crate) fn policy(&self) -> FailoverPolicy {
+        self.policy
+    }
+
+    /// The binding that served the most recent successful request.
+    pub(crate) fn active(&self) -> &SelectedBinding {
+        &self.entries[self.active.load(Ordering::Relaxed)].binding
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &SelectedBinding {
+        &self.entries[index].binding
+    }
+
+    /// Indices of interfaces to try, in rank order.
+    ///
+    /// Interfaces with an open circuit are skipped. If every circuit is open,
+    /// all interfaces are returned so the request is still attempted.
+    pub(crate) fn candidates(&self) -> Vec<usize> {
+        self.candidates_matching(|_| true)
+    }
+
+    /// Indices of interfaces matching `filter` to try, in rank order.
+    ///
+    /// Like [`candidates`](Self::candidates), restricted to the matching
+    /// interfaces.
+    pub(crate) fn candidates_matching(
+        &self,
+        filter: impl Fn(&SelectedBinding) -> bool,
+    ) -> Vec<usize> {
+        let now = Instant::now();
+        let matching: Vec<usize> = (0..self.entries.len())
+            .filter(|&i| filter(&self.entries[i].binding))
+            .collect();
+        let available: Vec<usize> = matching
+            .iter()
+            .copied()
+            .filter(|&i| self.state_at(i, now) != CircuitState::Open)
+            .collect();
+        if available.is_empty() {
+            matching
+        } else {
+            available
+        }
+    }
+
+    pub(crate) fn record_success(&self, index: usize) {
+        self.entries[index].breaker.lock().unwrap().record_success();
+        self.active.store(index, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_failure(&self, index: usize) {
+        self.entries[index]
+            .breaker
+            .lock()
+            .unwrap()
+            .record_failure(&self.policy, Instant::now());
+    }
+
+    /// Snapshot of every interface with its current circuit state.
+    pub(crate) fn states(&self) -> Vec<(SelectedBinding, CircuitState)> {
+        let now = Instant::now();
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| (entry.binding.clone(), self.state_at(i, now)))
+            .collect()
+    }
+
+    fn state_at(&self, index: usize, now: Instant) -> CircuitState {
+        self.entries[index]
+            .breaker
+            .lock()
+            .unwrap()
+            .state(&self.policy, now)
+    }
+}
+
+/// Whether an error means the interface itself is failing.
+///
+/// Besides transport failures, these are server errors (5xx) and answers
+/// that aren't protocol responses at all, such as a proxy's HTML error page.
+/// Other protocol errors returned by the agent are real answers and are
+/// passed through.
+pub(crate) fn is_interface_failure(error: &Error) -> bool {
+    match error {
+        Error::Transport(_) | Error::Connect(_) => true,
+        Error::Json(_) | Error::Protobuf(_) => true,
+        Error::Agent { source, .. } => match source {
+            ProtocolError::Rest { status, .. } => *status >= 500,
+            ProtocolError::Grpc { code, .. } => *code == GrpcStatusCode::Unavailable,
+            ProtocolError::JsonRpc { .. } => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether a failed request never reached the agent, so it may be resent
+/// elsewhere whatever its method.
+pub(crate) fn never_sent(error: &Error) -> bool {
+    matches!(error, Error::Connect(_))
+}
+
+/// Whether a request that failed after it was sent may be resent elsewhere.
+///
+/// Such a failure can happen after the agent received the request, so only
+/// requests that are safe to repeat fail over. A resent message could be
+/// processed twice, so sending a message is tried on one interface only,
+/// unless it failed to connect (see [`never_sent`]).
+pub(crate) fn may_retry(method: JsonRpcMethod) -> bool {
+    !matches!(
+        method,
+        JsonRpcMethod::SendMessage | JsonRpcMethod::SendStreamingMessage
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jsonrpc() -> SelectedBinding {
+        SelectedBinding::JsonRpc {
+            url: "https://example.com/".to_string(),
+        }
+    }
+
+    fn rest() -> SelectedBinding {
+        SelectedBinding::Rest {
+            url: "https://example.com/v1".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_breaker_opens_after_threshold() {
+        let policy = FailoverPolicy {
+            failure_threshold: 2,
+            reset_timeout: Duration::from_secs(60),
+        };
+        let now = Instant::now();
+        let mut breaker = CircuitBreaker::default();
+
+        breaker.record_failure(&policy, now);
+        assert_eq!(breaker.state(&policy, now), CircuitState::Closed);
+
+        breaker.record_failure(&policy, now);
+        assert_eq!(breaker.state(&policy, now), CircuitState::Open);
+        assert_eq!(
+            breaker.state(&policy, now + Duration::from_secs(61)),
+            CircuitState::HalfOpen
+        );
+    }
+
+    #[test]
+    fn test_breaker_success_closes() {
+        let policy = FailoverPolicy {
+            failure_threshold: 1,
+            reset_timeout: Duration::from_secs(60),
+        };
+        let now = Instant::now();
+        let mut breaker = CircuitBreaker::default();
+
+        breaker.record_failure(&policy, now);
+        assert_eq!(breaker.state(&policy, now), CircuitState::Open);
+
+        breaker.record_success();
+        assert_eq!(breaker.state(&policy, now), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_candidates_skip_open_circuits() {
+        let policy = FailoverPolicy {
+            failure_threshold: 1,
+            reset_timeout: Duration::from_secs(60),
+        };
+        let interfaces = Interfaces::new(vec![jsonrpc(), rest()], policy);
+        assert_eq!(interfaces.candidates(), vec![0, 1]);
+
+        interfaces.record_failure(0);
+        assert_eq!(interfaces.candidates(), vec![1]);
+
+        interfaces.record_failure(1);
+        assert_eq!(interfaces.candidates(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_candidates_matching() {
+        let policy = FailoverPolicy {
+            failure_threshold: 1,
+            reset_timeout: Duration::from_secs(60),
+        };
+        let interfaces = Interfaces::new(vec![jsonrpc(), rest(), jsonrpc()], policy);
+        let is_jsonrpc = |b: &SelectedBinding| matches!(b, SelectedBinding::JsonRpc { .. });
+        assert_eq!(interfaces.candidates_matching(is_jsonrpc), vec![0, 2]);
+
+        interfaces.record_failure(0);
+        interfaces.record_failure(2);
+        assert_eq!(interfaces.candidates_matching(is_jsonrpc), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_interface_failures() {
+        let rest_error = |status| Error::Agent {
+            message: String::new(),
+            source: ProtocolError::Rest { status, body: None },
+        };
+        assert!(is_interface_failure(&Error::Connect("refused".into())));
+        assert!(is_interface_failure(&Error::Transport("reset".into())));
+        assert!(is_interface_failure(&rest_error(502)));
+        assert!(!is_interface_failure(&rest_error(404)));
+        let html = serde_json::from_str::<serde_json::Value>("<html>").unwrap_err();
+        assert!(is_interface_failure(&Error::Json(html)));
+
+        assert!(never_sent(&Error::Connect("refused".into())));
+        assert!(!never_sent(&Error::Transport("reset".into())));
+    }
+
+    #[test]
+    fn test_active_follows_last_success() {
+        let interfaces = Interfaces::new(vec![jsonrpc(), rest()], FailoverPolicy::default());
+        assert_eq!(interfaces.active(), &jsonrpc());
+
+        interfaces.record_success(1);
+        assert_eq!(interfaces.active(), &rest());
+    }
+}
@@ -0,0 +1,161 @@
+// crates/a2a-client/src/grpc.rs
+//! gRPC binding implementation.
+//!
+//! Messages are the prost types from `a2a-types`, framed per the gRPC
+//! over HTTP/2 wire format (see [`a2a_types::grpc`]). The transport must speak HTTP/2 and append response
+//! trailers (`grpc-status`, `grpc-message`) to `HttpResponse::headers`.
+//! Streaming responses end with a trailers frame carrying the status, or
+//! carry it in their headers if they have no messages (Trailers-Only).
+
+use crate::error::{Error, GrpcStatusCode, ProtocolError, Result};
+use a2a_transport::{HttpRequest, HttpResponse};
+use a2a_types::grpc::{CONTENT_TYPE, FrameError, percent_decode};
+pub use a2a_types::grpc::{FrameDecoder, SERVICE, encode_frame};
+use prost::Message;
+
+/// Build the URL of a service method.
+pub fn method_url(base_url: &str, method: &str) -> String {
+    format!("{}/{}/{}", base_url.trim_end_matches('/'), SERVICE, method)
+}
+
+/// POST /a2a.v1.A2AService/{method}
+pub fn request(base_url: &str, method: &str, message: &impl Message) -> HttpRequest {
+    HttpRequest::post(method_url(base_url, method), encode_frame(message))
+        .with_header("Content-Type", CONTENT_TYPE)
+        .with_header("TE", "trailers")
+}
+
+/// Read the gRPC status from response headers or trailers.
+///
+/// Returns `None` when they carry no `grpc-status`, which happens for unary
+/// responses with transports that drop trailers; the body is then trusted
+/// as-is.
+pub fn status(headers: &[(String, String)]) -> Option<(GrpcStatusCode, String)> {
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    };
+    let code = header("grpc-status")?.trim().parse().ok()?;
+    let message = header("grpc-message")
+        .map(percent_decode)
+        .unwrap_or_default();
+    Some((GrpcStatusCode::from_code(code), message))
+}
+
+/// Decode a unary response.
+///
+/// Returns `Ok(None)` for a `NOT_FOUND` status.
+pub fn decode_unary<M: Message + Default>(response: &HttpResponse) -> Result<Option<M>> {
+    if response.status != 200 {
+        return Err(grpc_error(
+            http_status_code(response.status),
+            format!("HTTP status {}", response.status),
+        ));
+    }
+
+    match status(&response.headers) {
+        Some((GrpcStatusCode::Ok, _)) | None => {}
+        Some((GrpcStatusCode::NotFound, _)) => return Ok(None),
+        Some((code, message)) => return Err(grpc_error(code, message)),
+    }
+
+    let mut decoder = FrameDecoder::new();
+    let payload = decoder
+        .feed(&response.body)
+        .map_err(frame_error)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            grpc_error(
+                GrpcStatusCode::Internal,
+                "response contains no message".to_string(),
+            )
+        })?;
+    Ok(Some(decode_message(&payload)?))
+}
+
+/// Decode a single message payload.
+pub fn decode_message<M: Message + Default>(payload: &[u8]) -> Result<M> {
+    M::decode(payload).map_err(|e| grpc_error(GrpcStatusCode::Internal, e.to_string()))
+}
+
+/// The error for malformed framing from the agent.
+pub(crate) fn frame_error(error: FrameError) -> Error {
+    grpc_error(error.status_code(), error.to_string())
+}
+
+/// The status of a call answered with a non-200 HTTP status.
+///
+/// Follows gRPC's HTTP to gRPC status code mapping, except that every 5xx
+/// means the server is unavailable, not only 502 to 504.
+fn http_status_code(status: u16) -> GrpcStatusCode {
+    match status {
+        400 => GrpcStatusCode::Internal,
+        401 => GrpcStatusCode::Unauthenticated,
+        403 => GrpcStatusCode::PermissionDenied,
+        404 => GrpcStatusCode::Unimplemented,
+        429 | 500..=599 => GrpcStatusCode::Unavailable,
+        _ => GrpcStatusCode::Unknown,
+    }
+}
+
+pub(crate) fn grpc_error(code: GrpcStatusCode, message: String) -> Error {
+    Error::Agent {
+        message: message.clone(),
+        source: ProtocolError::Grpc { code, message },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use a2a_types::Task;
+
+    #[test]
+    fn test_method_url() {
+        assert_eq!(
+            method_url("https://example.com/", "GetTask"),
+            "https://example.com/a2a.v1.A2AService/GetTask"
+        );
+    }
+
+    #[test]
+    fn test_decode_unary_not_found() {
+        let response = HttpResponse::ok(Vec::new())
+            .with_header("grpc-status", "5")
+            .with_header("grpc-message", "task%20not%20found");
+        let result: Option<Task> = decode_unary(&response).unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_decode_unary_error_status() {
+        let response = HttpResponse::ok(Vec::new())
+            .with_header("grpc-status", "3")
+            .with_header("grpc-message", "bad%20name");
+        let err = decode_unary::<Task>(&response).unwrap_err();
+        match err {
+            Error::Agent {
+                source: ProtocolError::Grpc { code, message },
+                ..
+            } => {
+                assert_eq!(code, GrpcStatusCode::InvalidArgument);
+                assert_eq!(message, "bad name");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_decode_unary_success() {
+        let task = Task {
+            id: "task-1".to_string(),
+            ..Default::default()
+        };
+        let response = HttpResponse::ok(encode_frame(&task)).with_header("grpc-status", "0");
+        let decoded: Option<Task> = decode_unary(&response).unwrap();
+        assert_eq!(decoded, Some(task));
+    }
+}
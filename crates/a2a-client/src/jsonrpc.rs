@@ -1,4 +1,7 @@
 // crates/a2a-client/src/jsonrpc.rs
+use crate::error::{Error, JsonRpcErrorCode, ProtocolError, Result};
+use a2a_types::version::{Dialect, JsonRpcMethod};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -43,6 +46,62 @@ pub struct JsonRpcError {
     pub data: Option<Value>,
 }
 
+impl From<JsonRpcError> for Error {
+    fn from(error: JsonRpcError) -> Self {
+        Error::Agent {
+            message: error.message.clone(),
+            source: ProtocolError::JsonRpc {
+                code: JsonRpcErrorCode::from_code(error.code),
+                message: error.message,
+                data: error.data,
+            },
+        }
+    }
+}
+
+/// Encode a request for an A2A method in the agent's dialect.
+pub(crate) fn encode_request(
+    id: String,
+    method: JsonRpcMethod,
+    params: &impl Serialize,
+    dialect: Dialect,
+) -> Result<Vec<u8>> {
+    let mut params = serde_json::to_value(params)?;
+    if dialect == Dialect::Legacy {
+        params = method.params_to_legacy(params);
+    }
+    let request = JsonRpcRequest::new(id, method.name(dialect), params);
+    Ok(serde_json::to_vec(&request)?)
+}
+
+/// Decode the result of a JSON-RPC response.
+pub(crate) fn decode_result<R: DeserializeOwned>(body: &[u8]) -> Result<R> {
+    let response: JsonRpcResponse<R> = serde_json::from_slice(body)?;
+    match response.result {
+        JsonRpcResult::Success { result } => Ok(result),
+        JsonRpcResult::Error { error } => Err(error.into()),
+    }
+}
+
+/// Decode the response to an A2A method sent in `dialect`.
+pub(crate) fn decode_response<R: DeserializeOwned>(
+    body: &[u8],
+    method: JsonRpcMethod,
+    dialect: Dialect,
+) -> Result<R> {
+    let response: JsonRpcResponse<Value> = serde_json::from_slice(body)?;
+    match response.result {
+        JsonRpcResult::Success { result } => {
+            let result = match dialect {
+                Dialect::Legacy => method.result_from_legacy(result),
+                Dialect::V1 => result,
+            };
+            Ok(serde_json::from_value(result)?)
+        }
+        JsonRpcResult::Error { error } => Err(error.into()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,4 +128,30 @@ mod tests {
         let resp: JsonRpcResponse<Value> = serde_json::from_str(json).unwrap();
         assert!(matches!(resp.result, JsonRpcResult::Error { .. }));
     }
+
+    #[test]
+    fn test_legacy_round_trip() {
+        let body = encode_request(
+            "1".to_string(),
+            JsonRpcMethod::GetTask,
+            &serde_json::json!({"id": "task-1", "historyLength": 2}),
+            Dialect::Legacy,
+        )
+        .unwrap();
+        let request: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(request["method"], "tasks/get");
+        assert_eq!(
+            request["params"],
+            serde_json::json!({"id": "task-1", "historyLength": 2})
+        );
+
+        let body = br#"{"jsonrpc":"2.0","id":"1","result":{"kind":"message","messageId":"m1","role":"agent","parts":[{"kind":"text","text":"hi"}]}}"#;
+        let response: a2a_types::SendMessageResponse =
+            decode_response(body, JsonRpcMethod::SendMessage, Dialect::Legacy).unwrap();
+        let Some(a2a_types::send_message_response::Payload::Msg(message)) = response.payload else {
+            panic!("expected a message");
+        };
+        assert_eq!(message.message_id, "m1");
+        assert_eq!(message.role, a2a_types::Role::Agent as i32);
+    }
 }
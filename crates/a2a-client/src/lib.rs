@@ -3,32 +3,66 @@
 
 pub mod binding;
 pub mod builder;
+pub mod cache;
 pub mod error;
+pub mod failover;
+pub mod grpc;
 pub mod jsonrpc;
+pub mod owned;
 pub mod rest;
+pub mod signature;
 pub mod sse;
+pub mod stream;
 
 pub use builder::ClientBuilder;
-pub use error::{Error, JsonRpcErrorCode, ParamError, ProtocolError, Result};
+pub use cache::{AgentCardCache, CachedCard, InMemoryCardCache};
+pub use error::{Error, GrpcStatusCode, JsonRpcErrorCode, ParamError, ProtocolError, Result};
+pub use failover::{CircuitState, FailoverPolicy};
+pub use owned::OwnedEventStream;
+pub use signature::{CardVerifier, Jwk, JwkSet, SignatureError};
+pub use stream::EventStream;
 
-use a2a_transport::{HttpClient, HttpRequest};
-use a2a_types::AgentCard;
+use a2a_transport::{BodyFrame, HttpClient, HttpRequest, HttpResponse};
+use a2a_types::codec::Codec;
+use a2a_types::extension::{self, ExtensionRegistry};
+use a2a_types::version::{Dialect, JsonRpcMethod, ProtocolVersion};
+use a2a_types::{AgentCard, Binding, PushConfigName, TaskName};
 use binding::SelectedBinding;
-use jsonrpc::{JsonRpcRequest, JsonRpcResponse, JsonRpcResult};
-use std::sync::atomic::{AtomicU64, Ordering};
+use failover::Interfaces;
+use futures_core::Stream;
+use jsonrpc::JsonRpcRequest;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use stream::Decoder;
 
 /// A2A client for communicating with A2A agents.
+///
+/// Requests go to the most preferred interface advertised by the agent card.
+/// When that interface is unreachable the client fails over to the next one
+/// (see [`FailoverPolicy`]). Messages are never resent on another interface:
+/// the agent may have received them before the transport failed.
 pub struct Client<T: HttpClient> {
     transport: T,
+    base_url: String,
     agent_card: AgentCard,
-    binding: SelectedBinding,
+    card_cache: Option<Arc<dyn AgentCardCache>>,
+    verifier: Option<CardVerifier>,
+    preference: Vec<Binding>,
+    interfaces: Interfaces,
+    protocol_version: ProtocolVersion,
+    rest_codec: Codec,
+    /// Set once the agent refused a protobuf body.
+    rest_json_only: AtomicBool,
+    extensions: ExtensionRegistry,
+    requested_extensions: Vec<String>,
     request_id: AtomicU64,
 }
 
 impl<T: HttpClient> Client<T> {
     /// Create a new client by discovering the agent.
     ///
-    /// Uses default binding preference (JSON-RPC > REST).
+    /// Uses default binding preference (JSON-RPC > REST > gRPC).
     pub async fn connect(transport: T, base_url: impl Into<String>) -> Result<Self> {
         ClientBuilder::new(transport, base_url).build().await
     }
@@ -43,9 +77,63 @@ impl<T: HttpClient> Client<T> {
         &self.agent_card
     }
 
+    /// Refresh the agent card in place.
+    ///
+    /// With a card cache, a fresh cached card is reused and a stale one is
+    /// revalidated; otherwise the card is refetched. When the card's
+    /// interfaces change, bindings are re-ranked and circuit breakers reset.
+    /// Returns whether the card changed.
+    pub async fn refresh_card(&mut self) -> Result<bool> {
+        let card = cache::fetch_agent_card(
+            &self.transport,
+            &self.base_url,
+            self.card_cache.as_deref(),
+            self.verifier.as_ref(),
+        )
+        .await?;
+        if card == self.agent_card {
+            return Ok(false);
+        }
+
+        if binding::extract_interfaces(&card) != binding::extract_interfaces(&self.agent_card) {
+            let ranked = builder::rank_card(&card, &self.preference)?;
+            self.interfaces = Interfaces::new(ranked, self.interfaces.policy());
+        }
+        self.requested_extensions = self.extensions.request(&card)?;
+        self.protocol_version = ProtocolVersion::of(&card);
+        self.agent_card = card;
+        Ok(true)
+    }
+
     /// Get the selected binding.
+    ///
+    /// This is the interface that served the most recent successful request,
+    /// which changes when the client fails over to another interface.
     pub fn binding(&self) -> &SelectedBinding {
-        &self.binding
+        self.interfaces.active()
+    }
+
+    /// Get the protocol version the agent declares.
+    ///
+    /// JSON-RPC requests to 0.2/0.3 agents use that version's method names
+    /// and parameter shapes.
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    fn dialect(&self) -> Dialect {
+        self.protocol_version.dialect()
+    }
+
+    /// Get every candidate interface, in rank order, with its circuit state.
+    pub fn circuit_states(&self) -> Vec<(SelectedBinding, CircuitState)> {
+        self.interfaces.states()
+    }
+
+    /// Get the extensions requested on every call: those the client
+    /// supports that the agent card declares.
+    pub fn requested_extensions(&self) -> &[String] {
+        &self.requested_extensions
     }
 
     /// Get the next request ID.
@@ -53,7 +141,51 @@ impl<T: HttpClient> Client<T> {
         self.request_id.fetch_add(1, Ordering::SeqCst).to_string()
     }
 
+    fn with_extensions(&self, http_request: HttpRequest) -> HttpRequest {
+        if self.requested_extensions.is_empty() {
+            return http_request;
+        }
+        http_request.with_header(
+            extension::HEADER,
+            extension::format_header(&self.requested_extensions),
+        )
+    }
+
+    /// Send an HTTP request to the agent, requesting the client's extensions.
+    async fn send(&self, http_request: HttpRequest) -> Result<HttpResponse> {
+        self.transport
+            .request(self.with_extensions(http_request))
+            .await
+            .map_err(Error::from_transport::<T>)
+    }
+
+    /// Send a REST request built for the client's body codec.
+    ///
+    /// Agents without protobuf support answer `415 Unsupported Media Type`;
+    /// the request is then resent as JSON, and later REST requests use JSON
+    /// from the start.
+    async fn send_rest(
+        &self,
+        build: impl Fn(Codec) -> Result<HttpRequest>,
+    ) -> Result<HttpResponse> {
+        let codec = if self.rest_json_only.load(Ordering::Relaxed) {
+            Codec::Json
+        } else {
+            self.rest_codec
+        };
+        let response = self.send(build(codec)?).await?;
+        if response.status == 415 && codec != Codec::Json {
+            self.rest_json_only.store(true, Ordering::Relaxed);
+            return self.send(build(Codec::Json)?).await;
+        }
+        Ok(response)
+    }
+
     /// Send a JSON-RPC request to the agent.
+    ///
+    /// Fails over across the agent's JSON-RPC interfaces. After a transport
+    /// failure, only A2A methods that are safe to repeat are resent; any
+    /// other method is tried on one interface.
     pub async fn rpc<P, R>(&self, method: &str, params: P) -> Result<R>
     where
         P: serde::Serialize,
@@ -62,121 +194,199 @@ impl<T: HttpClient> Client<T> {
         let request = JsonRpcRequest::new(self.next_id(), method, params);
         let body = serde_json::to_vec(&request)?;
 
-        let url = format!("{}/", self.binding.url().trim_end_matches('/'));
-        let http_request = HttpRequest::post(&url, body)
-            .with_header("Content-Type", "application/json")
-            .with_header("Accept", "application/json");
+        let candidates = self
+            .interfaces
+            .candidates_matching(|binding| matches!(binding, SelectedBinding::JsonRpc { .. }));
+        let retry =
+            JsonRpcMethod::from_name(method).is_some_and(|(method, _)| failover::may_retry(method));
+        self.try_interfaces(candidates, retry, |binding| {
+            let url = format!("{}/", binding.url().trim_end_matches('/'));
+            let http_request = HttpRequest::post(&url, body.clone())
+                .with_header("Content-Type", "application/json")
+                .with_header("Accept", "application/json");
+            async move {
+                let response = self.send(http_request).await?;
+                jsonrpc::decode_result(&response.body)
+            }
+        })
+        .await
+    }
 
-        let response = self
-            .transport
-            .request(http_request)
+    /// Run a request against each candidate interface until one is reachable.
+    ///
+    /// Interface failures (see [`failover::is_interface_failure`]) are
+    /// recorded against the interface's circuit breaker. The next interface
+    /// is tried if the request never left the client, or if `method` may be
+    /// retried (see [`failover::may_retry`]); any other outcome is final.
+    async fn with_failover<'a, R, F, Fut>(&'a self, method: JsonRpcMethod, call: F) -> Result<R>
+    where
+        F: FnMut(&'a SelectedBinding) -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        self.try_interfaces(
+            self.interfaces.candidates(),
+            failover::may_retry(method),
+            call,
+        )
+        .await
+    }
+
+    /// Run a request that is safe to repeat, failing over across every
+    /// interface.
+    async fn with_retries<'a, R, F, Fut>(&'a self, call: F) -> Result<R>
+    where
+        F: FnMut(&'a SelectedBinding) -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        self.try_interfaces(self.interfaces.candidates(), true, call)
             .await
-            .map_err(|e| Error::Transport(e.to_string()))?;
-
-        let rpc_response: JsonRpcResponse<R> = serde_json::from_slice(&response.body)?;
-
-        match rpc_response.result {
-            JsonRpcResult::Success { result } => Ok(result),
-            JsonRpcResult::Error { error } => Err(Error::Agent {
-                message: error.message.clone(),
-                source: ProtocolError::JsonRpc {
-                    code: JsonRpcErrorCode::from_code(error.code),
-                    message: error.message,
-                    data: error.data,
-                },
-            }),
+    }
+
+    /// Run a request against the `candidates` interfaces, in order.
+    ///
+    /// After an interface failure the next interface is tried if `retry` is
+    /// set, or if the request provably never reached the agent.
+    async fn try_interfaces<'a, R, F, Fut>(
+        &'a self,
+        candidates: Vec<usize>,
+        retry: bool,
+        mut call: F,
+    ) -> Result<R>
+    where
+        F: FnMut(&'a SelectedBinding) -> Fut,
+        Fut: Future<Output = Result<R>>,
+    {
+        let mut last_error = None;
+        for index in candidates {
+            match call(self.interfaces.get(index)).await {
+                Err(e) if failover::is_interface_failure(&e) => {
+                    self.interfaces.record_failure(index);
+                    if !retry && !failover::never_sent(&e) {
+                        return Err(e);
+                    }
+                    last_error = Some(e);
+                }
+                result => {
+                    self.interfaces.record_success(index);
+                    return result;
+                }
+            }
         }
+        Err(last_error.unwrap_or(Error::NoCompatibleBinding { available: vec![] }))
     }
 
     /// Send a message to the agent.
     ///
-    /// Uses the negotiated binding (JSON-RPC or REST).
+    /// Uses the negotiated binding (JSON-RPC, REST or gRPC).
     pub async fn send_message(
         &self,
-        params: a2a_types::SendMessageRequest,
+        params: impl Into<a2a_types::SendMessageRequest>,
+    ) -> Result<a2a_types::SendMessageResponse> {
+        let params = params.into();
+        self.with_failover(JsonRpcMethod::SendMessage, |binding| {
+            self.send_message_on(binding, &params)
+        })
+        .await
+    }
+
+    async fn send_message_on(
+        &self,
+        binding: &SelectedBinding,
+        params: &a2a_types::SendMessageRequest,
     ) -> Result<a2a_types::SendMessageResponse> {
-        match &self.binding {
+        match binding {
             SelectedBinding::JsonRpc { url } => self.send_message_jsonrpc(url, params).await,
             SelectedBinding::Rest { url } => self.send_message_rest(url, params).await,
+            SelectedBinding::Grpc { url } => self.send_message_grpc(url, params).await,
         }
     }
 
     async fn send_message_jsonrpc(
         &self,
         url: &str,
-        params: a2a_types::SendMessageRequest,
+        params: &a2a_types::SendMessageRequest,
     ) -> Result<a2a_types::SendMessageResponse> {
-        let request = jsonrpc::JsonRpcRequest::new(self.next_id(), "SendMessage", &params);
-        let body = serde_json::to_vec(&request)?;
-
+        let body = jsonrpc::encode_request(
+            self.next_id(),
+            JsonRpcMethod::SendMessage,
+            params,
+            self.dialect(),
+        )?;
         let http_request = HttpRequest::post(url, body)
             .with_header("Content-Type", "application/json")
             .with_header("Accept", "application/json");
 
-        let response = self
-            .transport
-            .request(http_request)
-            .await
-            .map_err(|e| Error::Transport(e.to_string()))?;
-
-        let rpc_response: jsonrpc::JsonRpcResponse<a2a_types::SendMessageResponse> =
-            serde_json::from_slice(&response.body)?;
-
-        match rpc_response.result {
-            jsonrpc::JsonRpcResult::Success { result } => Ok(result),
-            jsonrpc::JsonRpcResult::Error { error } => Err(Error::Agent {
-                message: error.message.clone(),
-                source: ProtocolError::JsonRpc {
-                    code: JsonRpcErrorCode::from_code(error.code),
-                    message: error.message,
-                    data: error.data,
-                },
-            }),
-        }
+        let response = self.send(http_request).await?;
+
+        jsonrpc::decode_response(&response.body, JsonRpcMethod::SendMessage, self.dialect())
     }
 
     async fn send_message_rest(
         &self,
         url: &str,
-        params: a2a_types::SendMessageRequest,
+        params: &a2a_types::SendMessageRequest,
     ) -> Result<a2a_types::SendMessageResponse> {
-        let body = serde_json::to_vec(&params)?;
-        let http_request = rest::send_message_request(url, body);
-
         let response = self
-            .transport
-            .request(http_request)
-            .await
-            .map_err(|e| Error::Transport(e.to_string()))?;
+            .send_rest(|codec| {
+                Ok(rest::send_message_request(
+                    url,
+                    codec.encode(params)?,
+                    codec,
+                ))
+            })
+            .await?;
 
         if response.status != 200 {
-            let body: Option<serde_json::Value> = serde_json::from_slice(&response.body).ok();
-            return Err(Error::Agent {
-                message: format!("REST error {}", response.status),
-                source: ProtocolError::Rest {
-                    status: response.status,
-                    body,
-                },
-            });
+            return Err(rest::error(&response));
         }
 
-        let result: a2a_types::SendMessageResponse = serde_json::from_slice(&response.body)?;
-        Ok(result)
+        Ok(rest::response_codec(&response).decode(&response.body)?)
+    }
+
+    async fn send_message_grpc(
+        &self,
+        url: &str,
+        params: &a2a_types::SendMessageRequest,
+    ) -> Result<a2a_types::SendMessageResponse> {
+        let http_request = grpc::request(url, "SendMessage", params);
+
+        let response = self.send(http_request).await?;
+
+        grpc::decode_unary(&response)?.ok_or_else(|| Error::Agent {
+            message: "agent returned NOT_FOUND".to_string(),
+            source: ProtocolError::Grpc {
+                code: GrpcStatusCode::NotFound,
+                message: String::new(),
+            },
+        })
     }
 
     /// Get a task by ID.
     ///
-    /// Uses the negotiated binding (JSON-RPC or REST).
+    /// Uses the negotiated binding (JSON-RPC, REST or gRPC).
     pub async fn get_task(
         &self,
         task_id: &a2a_types::TaskId,
         history_length: Option<u32>,
     ) -> Result<Option<a2a_types::Task>> {
-        match &self.binding {
+        self.with_failover(JsonRpcMethod::GetTask, |binding| {
+            self.get_task_on(binding, task_id, history_length)
+        })
+        .await
+    }
+
+    async fn get_task_on(
+        &self,
+        binding: &SelectedBinding,
+        task_id: &a2a_types::TaskId,
+        history_length: Option<u32>,
+    ) -> Result<Option<a2a_types::Task>> {
+        match binding {
             SelectedBinding::JsonRpc { url } => {
                 self.get_task_jsonrpc(url, task_id, history_length).await
             }
             SelectedBinding::Rest { url } => self.get_task_rest(url, task_id, history_length).await,
+            SelectedBinding::Grpc { url } => self.get_task_grpc(url, task_id, history_length).await,
         }
     }
 
@@ -194,40 +404,22 @@ impl<T: HttpClient> Client<T> {
             history_length: Option<u32>,
         }
 
-        let request = jsonrpc::JsonRpcRequest::new(
+        let body = jsonrpc::encode_request(
             self.next_id(),
-            "GetTask",
-            Params {
+            JsonRpcMethod::GetTask,
+            &Params {
                 id: task_id.as_str(),
                 history_length,
             },
-        );
-        let body = serde_json::to_vec(&request)?;
-
+            self.dialect(),
+        )?;
         let http_request = HttpRequest::post(url, body)
             .with_header("Content-Type", "application/json")
             .with_header("Accept", "application/json");
 
-        let response = self
-            .transport
-            .request(http_request)
-            .await
-            .map_err(|e| Error::Transport(e.to_string()))?;
-
-        let rpc_response: jsonrpc::JsonRpcResponse<Option<a2a_types::Task>> =
-            serde_json::from_slice(&response.body)?;
-
-        match rpc_response.result {
-            jsonrpc::JsonRpcResult::Success { result } => Ok(result),
-            jsonrpc::JsonRpcResult::Error { error } => Err(Error::Agent {
-                message: error.message.clone(),
-                source: ProtocolError::JsonRpc {
-                    code: JsonRpcErrorCode::from_code(error.code),
-                    message: error.message,
-                    data: error.data,
-                },
-            }),
-        }
+        let response = self.send(http_request).await?;
+
+        jsonrpc::decode_response(&response.body, JsonRpcMethod::GetTask, self.dialect())
     }
 
     async fn get_task_rest(
@@ -236,46 +428,68 @@ impl<T: HttpClient> Client<T> {
         task_id: &a2a_types::TaskId,
         history_length: Option<u32>,
     ) -> Result<Option<a2a_types::Task>> {
-        let http_request = match history_length {
-            Some(len) => rest::get_task_with_history_request(url, task_id, len),
-            None => rest::get_task_request(url, task_id),
-        };
-
         let response = self
-            .transport
-            .request(http_request)
-            .await
-            .map_err(|e| Error::Transport(e.to_string()))?;
+            .send_rest(|codec| {
+                Ok(match history_length {
+                    Some(len) => rest::get_task_with_history_request(url, task_id, len, codec),
+                    None => rest::get_task_request(url, task_id, codec),
+                })
+            })
+            .await?;
 
         if response.status == 404 {
             return Ok(None);
         }
 
         if response.status != 200 {
-            let body: Option<serde_json::Value> = serde_json::from_slice(&response.body).ok();
-            return Err(Error::Agent {
-                message: format!("REST error {}", response.status),
-                source: ProtocolError::Rest {
-                    status: response.status,
-                    body,
-                },
-            });
+            return Err(rest::error(&response));
         }
 
-        let task: a2a_types::Task = serde_json::from_slice(&response.body)?;
-        Ok(Some(task))
+        Ok(Some(
+            rest::response_codec(&response).decode(&response.body)?,
+        ))
+    }
+
+    async fn get_task_grpc(
+        &self,
+        url: &str,
+        task_id: &a2a_types::TaskId,
+        history_length: Option<u32>,
+    ) -> Result<Option<a2a_types::Task>> {
+        let request = a2a_types::GetTaskRequest {
+            name: TaskName::new(task_id.clone()).to_string(),
+            history_length: history_length.map(|len| len.min(i32::MAX as u32) as i32),
+            ..Default::default()
+        };
+        let http_request = grpc::request(url, "GetTask", &request);
+
+        let response = self.send(http_request).await?;
+
+        grpc::decode_unary(&response)
     }
 
     /// Cancel a task by ID.
     ///
-    /// Uses the negotiated binding (JSON-RPC or REST).
+    /// Uses the negotiated binding (JSON-RPC, REST or gRPC).
     pub async fn cancel_task(
         &self,
         task_id: &a2a_types::TaskId,
     ) -> Result<Option<a2a_types::Task>> {
-        match &self.binding {
+        self.with_failover(JsonRpcMethod::CancelTask, |binding| {
+            self.cancel_task_on(binding, task_id)
+        })
+        .await
+    }
+
+    async fn cancel_task_on(
+        &self,
+        binding: &SelectedBinding,
+        task_id: &a2a_types::TaskId,
+    ) -> Result<Option<a2a_types::Task>> {
+        match binding {
             SelectedBinding::JsonRpc { url } => self.cancel_task_jsonrpc(url, task_id).await,
             SelectedBinding::Rest { url } => self.cancel_task_rest(url, task_id).await,
+            SelectedBinding::Grpc { url } => self.cancel_task_grpc(url, task_id).await,
         }
     }
 
@@ -289,39 +503,21 @@ impl<T: HttpClient> Client<T> {
             id: &'a str,
         }
 
-        let request = jsonrpc::JsonRpcRequest::new(
+        let body = jsonrpc::encode_request(
             self.next_id(),
-            "CancelTask",
-            Params {
+            JsonRpcMethod::CancelTask,
+            &Params {
                 id: task_id.as_str(),
             },
-        );
-        let body = serde_json::to_vec(&request)?;
-
+            self.dialect(),
+        )?;
         let http_request = HttpRequest::post(url, body)
             .with_header("Content-Type", "application/json")
             .with_header("Accept", "application/json");
 
-        let response = self
-            .transport
-            .request(http_request)
-            .await
-            .map_err(|e| Error::Transport(e.to_string()))?;
-
-        let rpc_response: jsonrpc::JsonRpcResponse<Option<a2a_types::Task>> =
-            serde_json::from_slice(&response.body)?;
-
-        match rpc_response.result {
-            jsonrpc::JsonRpcResult::Success { result } => Ok(result),
-            jsonrpc::JsonRpcResult::Error { error } => Err(Error::Agent {
-                message: error.message.clone(),
-                source: ProtocolError::JsonRpc {
-                    code: JsonRpcErrorCode::from_code(error.code),
-                    message: error.message,
-                    data: error.data,
-                },
-            }),
-        }
+        let response = self.send(http_request).await?;
+
+        jsonrpc::decode_response(&response.body, JsonRpcMethod::CancelTask, self.dialect())
     }
 
     async fn cancel_task_rest(
@@ -329,30 +525,907 @@ impl<T: HttpClient> Client<T> {
         url: &str,
         task_id: &a2a_types::TaskId,
     ) -> Result<Option<a2a_types::Task>> {
-        let http_request = rest::cancel_task_request(url, task_id);
-
         let response = self
-            .transport
-            .request(http_request)
-            .await
-            .map_err(|e| Error::Transport(e.to_string()))?;
+            .send_rest(|codec| Ok(rest::cancel_task_request(url, task_id, codec)))
+            .await?;
 
         if response.status == 404 {
             return Ok(None);
         }
 
         if response.status != 200 {
-            let body: Option<serde_json::Value> = serde_json::from_slice(&response.body).ok();
-            return Err(Error::Agent {
-                message: format!("REST error {}", response.status),
-                source: ProtocolError::Rest {
-                    status: response.status,
-                    body,
+            return Err(rest::error(&response));
+        }
+
+        Ok(Some(
+            rest::response_codec(&response).decode(&response.body)?,
+        ))
+    }
+
+    async fn cancel_task_grpc(
+        &self,
+        url: &str,
+        task_id: &a2a_types::TaskId,
+    ) -> Result<Option<a2a_types::Task>> {
+        let request = a2a_types::CancelTaskRequest {
+            name: TaskName::new(task_id.clone()).to_string(),
+            ..Default::default()
+        };
+        let http_request = grpc::request(url, "CancelTask", &request);
+
+        let response = self.send(http_request).await?;
+
+        grpc::decode_unary(&response)
+    }
+
+    /// List the agent's tasks, a page at a time.
+    ///
+    /// Uses the negotiated binding (JSON-RPC, REST or gRPC).
+    pub async fn list_tasks(
+        &self,
+        params: a2a_types::ListTasksRequest,
+    ) -> Result<a2a_types::ListTasksResponse> {
+        self.with_retries(|binding| {
+            self.unary_on(binding, "ListTasks", &params, |url, codec| {
+                Ok(rest::list_tasks_request(url, &params, codec))
+            })
+        })
+        .await
+    }
+
+    /// Create or replace a push notification config of a task.
+    ///
+    /// Uses the negotiated binding (JSON-RPC, REST or gRPC).
+    pub async fn set_task_push_notification_config(
+        &self,
+        params: a2a_types::SetTaskPushNotificationConfigRequest,
+    ) -> Result<a2a_types::TaskPushNotificationConfig> {
+        // The config is stored under its ID, so resending it is harmless
+        self.with_retries(|binding| {
+            self.unary_on(
+                binding,
+                "SetTaskPushNotificationConfig",
+                &params,
+                |url, codec| {
+                    let config = params.config.clone().unwrap_or_default();
+                    Ok(rest::set_push_config_request(
+                        url,
+                        &params,
+                        codec.encode(&config)?,
+                        codec,
+                    ))
                 },
-            });
+            )
+        })
+        .await
+    }
+
+    /// Get a push notification config of a task.
+    ///
+    /// Returns `Ok(None)` if the agent doesn't know the task or config (see
+    /// [`Error::is_not_found`]). Uses the negotiated binding (JSON-RPC, REST
+    /// or gRPC).
+    pub async fn get_task_push_notification_config(
+        &self,
+        name: &PushConfigName,
+    ) -> Result<Option<a2a_types::TaskPushNotificationConfig>> {
+        let params = a2a_types::GetTaskPushNotificationConfigRequest {
+            tenant: name.tenant().unwrap_or_default().to_string(),
+            name: name.resource_name(),
+        };
+        let result = self
+            .with_retries(|binding| {
+                self.unary_on(
+                    binding,
+                    "GetTaskPushNotificationConfig",
+                    &params,
+                    |url, codec| Ok(rest::get_push_config_request(url, name, codec)),
+                )
+            })
+            .await;
+        match result {
+            Err(e) if e.is_not_found() => Ok(None),
+            result => result.map(Some),
+        }
+    }
+
+    /// List the push notification configs of a task, a page at a time.
+    ///
+    /// Uses the negotiated binding (JSON-RPC, REST or gRPC).
+    pub async fn list_task_push_notification_config(
+        &self,
+        params: a2a_types::ListTaskPushNotificationConfigRequest,
+    ) -> Result<a2a_types::ListTaskPushNotificationConfigResponse> {
+        self.with_retries(|binding| {
+            self.unary_on(
+                binding,
+                "ListTaskPushNotificationConfig",
+                &params,
+                |url, codec| Ok(rest::list_push_configs_request(url, &params, codec)),
+            )
+        })
+        .await
+    }
+
+    /// Delete a push notification config of a task.
+    ///
+    /// Uses the negotiated binding (JSON-RPC, REST or gRPC).
+    pub async fn delete_task_push_notification_config(&self, name: &PushConfigName) -> Result<()> {
+        let params = a2a_types::DeleteTaskPushNotificationConfigRequest {
+            tenant: name.tenant().unwrap_or_default().to_string(),
+            name: name.resource_name(),
+        };
+        let _: Empty = self
+            .with_retries(|binding| {
+                self.unary_on(
+                    binding,
+                    "DeleteTaskPushNotificationConfig",
+                    &params,
+                    |url, codec| Ok(rest::delete_push_config_request(url, name, codec)),
+                )
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Call a unary method on `binding`.
+    ///
+    /// JSON-RPC and gRPC send `params` to `method` as is; REST sends the
+    /// request `rest` builds for the interface URL and body codec.
+    async fn unary_on<P, R>(
+        &self,
+        binding: &SelectedBinding,
+        method: &str,
+        params: &P,
+        rest: impl Fn(&str, Codec) -> Result<HttpRequest>,
+    ) -> Result<R>
+    where
+        P: prost::Message + serde::Serialize,
+        R: prost::Message + Default + serde::de::DeserializeOwned,
+    {
+        match binding {
+            SelectedBinding::JsonRpc { url } => {
+                let request = JsonRpcRequest::new(self.next_id(), method, params);
+                let http_request = HttpRequest::post(url, serde_json::to_vec(&request)?)
+                    .with_header("Content-Type", "application/json")
+                    .with_header("Accept", "application/json");
+
+                let response = self.send(http_request).await?;
+
+                jsonrpc::decode_result(&response.body)
+            }
+            SelectedBinding::Rest { url } => {
+                let response = self.send_rest(|codec| rest(url, codec)).await?;
+
+                if response.status != 200 {
+                    return Err(rest::error(&response));
+                }
+                // Deletions may answer without a body
+                if response.body.is_empty() {
+                    return Ok(R::default());
+                }
+
+                Ok(rest::response_codec(&response).decode(&response.body)?)
+            }
+            SelectedBinding::Grpc { url } => {
+                let response = self.send(grpc::request(url, method, params)).await?;
+
+                grpc::decode_unary(&response)?.ok_or_else(|| {
+                    grpc::grpc_error(GrpcStatusCode::NotFound, format!("{method}: not found"))
+                })
+            }
+        }
+    }
+
+    /// Send a message and stream the agent's events.
+    ///
+    /// Uses the negotiated binding (JSON-RPC, REST or gRPC).
+    pub async fn send_streaming_message(
+        &self,
+        params: impl Into<a2a_types::SendMessageRequest>,
+    ) -> Result<impl Stream<Item = Result<a2a_types::StreamResponse>> + Send + '_> {
+        let params = params.into();
+        self.with_failover(JsonRpcMethod::SendStreamingMessage, |binding| {
+            let request = self.send_streaming_message_request(binding, &params);
+            async move {
+                let (http_request, decoder) = request?;
+                self.open_stream(http_request, decoder).await
+            }
+        })
+        .await
+    }
+
+    fn send_streaming_message_request(
+        &self,
+        binding: &SelectedBinding,
+        params: &a2a_types::SendMessageRequest,
+    ) -> Result<(HttpRequest, Decoder)> {
+        let (http_request, decoder) = match binding {
+            SelectedBinding::JsonRpc { url } => {
+                let method = JsonRpcMethod::SendStreamingMessage;
+                let body = jsonrpc::encode_request(self.next_id(), method, params, self.dialect())?;
+                let http_request = HttpRequest::post(url, body)
+                    .with_header("Content-Type", "application/json")
+                    .with_header("Accept", "text/event-stream");
+                (http_request, Decoder::json_rpc(method, self.dialect()))
+            }
+            SelectedBinding::Rest { url } => (
+                rest::send_streaming_message_request(url, serde_json::to_vec(params)?),
+                Decoder::rest(),
+            ),
+            SelectedBinding::Grpc { url } => (
+                grpc::request(url, "SendStreamingMessage", params),
+                Decoder::grpc(),
+            ),
+        };
+        Ok((http_request, decoder))
+    }
+
+    /// Subscribe to the events of an existing task.
+    ///
+    /// Uses the negotiated binding (JSON-RPC, REST or gRPC).
+    pub async fn subscribe_to_task(
+        &self,
+        task_id: &a2a_types::TaskId,
+    ) -> Result<impl Stream<Item = Result<a2a_types::StreamResponse>> + Send + '_> {
+        self.with_failover(JsonRpcMethod::SubscribeToTask, |binding| {
+            let request = self.subscribe_to_task_request(binding, task_id);
+            async move {
+                let (http_request, decoder) = request?;
+                self.open_stream(http_request, decoder).await
+            }
+        })
+        .await
+    }
+
+    /// Like [`send_streaming_message`](Self::send_streaming_message), but
+    /// the stream owns a reference to the client rather than borrowing it.
+    pub async fn send_streaming_message_owned(
+        self: Arc<Self>,
+        params: impl Into<a2a_types::SendMessageRequest>,
+    ) -> Result<OwnedEventStream>
+    where
+        T: 'static,
+    {
+        let params = params.into();
+        OwnedEventStream::open(|sender| async move {
+            sender
+                .forward(self.send_streaming_message(params).await)
+                .await
+        })
+        .await
+    }
+
+    /// Like [`subscribe_to_task`](Self::subscribe_to_task), but the stream
+    /// owns a reference to the client rather than borrowing it.
+    pub async fn subscribe_to_task_owned(
+        self: Arc<Self>,
+        task_id: a2a_types::TaskId,
+    ) -> Result<OwnedEventStream>
+    where
+        T: 'static,
+    {
+        OwnedEventStream::open(|sender| async move {
+            sender.forward(self.subscribe_to_task(&task_id).await).await
+        })
+        .await
+    }
+
+    fn subscribe_to_task_request(
+        &self,
+        binding: &SelectedBinding,
+        task_id: &a2a_types::TaskId,
+    ) -> Result<(HttpRequest, Decoder)> {
+        let (http_request, decoder) = match binding {
+            SelectedBinding::JsonRpc { url } => {
+                #[derive(serde::Serialize)]
+                struct Params<'a> {
+                    id: &'a str,
+                }
+
+                let method = JsonRpcMethod::SubscribeToTask;
+                let params = Params {
+                    id: task_id.as_str(),
+                };
+                let body =
+                    jsonrpc::encode_request(self.next_id(), method, &params, self.dialect())?;
+                let http_request = HttpRequest::post(url, body)
+                    .with_header("Content-Type", "application/json")
+                    .with_header("Accept", "text/event-stream");
+                (http_request, Decoder::json_rpc(method, self.dialect()))
+            }
+            SelectedBinding::Rest { url } => (
+                rest::subscribe_to_task_request(url, task_id),
+                Decoder::rest(),
+            ),
+            SelectedBinding::Grpc { url } => {
+                let request = a2a_types::SubscribeToTaskRequest {
+                    name: TaskName::new(task_id.clone()).to_string(),
+                    ..Default::default()
+                };
+                (
+                    grpc::request(url, "SubscribeToTask", &request),
+                    Decoder::grpc(),
+                )
+            }
+        };
+        Ok((http_request, decoder))
+    }
+
+    async fn open_stream(
+        &self,
+        http_request: HttpRequest,
+        decoder: Decoder,
+    ) -> Result<EventStream<impl Stream<Item = std::result::Result<BodyFrame, T::Error>> + Send + '_>>
+    {
+        let response = self
+            .transport
+            .request_stream(self.with_extensions(http_request))
+            .await
+            .map_err(Error::from_transport::<T>)?;
+        Ok(EventStream::new(response, decoder))
+    }
+}
+
+/// `google.protobuf.Empty`, the answer to deletions.
+#[derive(Clone, PartialEq, prost::Message, serde::Deserialize)]
+struct Empty {}
+
+#[cfg(test)]
+#[allow(clippy::manual_async_fn)] // Mock trait impl matches HttpClient trait signature
+mod tests {
+    use super::*;
+    use a2a_transport::HttpResponse;
+    use a2a_types::TaskId;
+    use a2a_types::extension::ExtensionError;
+    use std::pin::Pin;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll};
+    use std::time::Duration;
+
+    const CARD: &str = r#"{
+        "name": "test",
+        "description": "test",
+        "version": "1.0",
+        "capabilities": {},
+        "supportedInterfaces": [
+            {"url": "http://jsonrpc.test/", "protocolBinding": "JSONRPC"},
+            {"url": "http://rest.test", "protocolBinding": "HTTP+JSON"},
+            {"url": "http://grpc.test", "protocolBinding": "GRPC"}
+        ],
+        "defaultInputModes": [],
+        "defaultOutputModes": [],
+        "skills": []
+    }"#;
+
+    const TASK: &str =
+        r#"{"id": "task-1", "contextId": "ctx-1", "status": {"state": "TASK_STATE_COMPLETED"}}"#;
+
+    struct EmptyStream;
+
+    impl futures_core::Stream for EmptyStream {
+        type Item = std::result::Result<a2a_transport::BodyFrame, a2a_transport::Error>;
+
+        fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(None)
+        }
+    }
+
+    /// Mock agent exposing a JSON-RPC and a REST interface.
+    #[derive(Clone, Default)]
+    struct MockAgent {
+        /// Refuse connections to the JSON-RPC interface.
+        jsonrpc_down: Arc<AtomicBool>,
+        /// Fail JSON-RPC requests after they were sent.
+        jsonrpc_reset: Arc<AtomicBool>,
+        /// Answer JSON-RPC requests with a gateway's 503 error page.
+        jsonrpc_unavailable: Arc<AtomicBool>,
+        card: Arc<Mutex<Option<String>>>,
+        requests: Arc<Mutex<Vec<String>>>,
+        jsonrpc_calls: Arc<Mutex<Vec<serde_json::Value>>>,
+        rest_json_only: Arc<AtomicBool>,
+        /// `X-A2A-Extensions` header of each request, if any.
+        extension_headers: Arc<Mutex<Vec<Option<String>>>>,
+    }
+
+    impl MockAgent {
+        fn respond(
+            &self,
+            req: &HttpRequest,
+        ) -> std::result::Result<HttpResponse, a2a_transport::Error> {
+            self.requests.lock().unwrap().push(req.url.clone());
+            if req.url.ends_with("/.well-known/agent-card.json") {
+                let card = self.card.lock().unwrap().clone();
+                return Ok(HttpResponse::ok(card.unwrap_or_else(|| CARD.to_string())));
+            }
+            if req.url.starts_with("http://jsonrpc.test") {
+                if self.jsonrpc_down.load(Ordering::SeqCst) {
+                    return Err(a2a_transport::Error::Connection("refused".to_string()));
+                }
+                if self.jsonrpc_reset.load(Ordering::SeqCst) {
+                    return Err(a2a_transport::Error::Timeout);
+                }
+                if self.jsonrpc_unavailable.load(Ordering::SeqCst) {
+                    return Ok(HttpResponse::ok("<html>Service Unavailable</html>")
+                        .with_status(503)
+                        .with_header("Content-Type", "text/html"));
+                }
+                if let Some(body) = &req.body {
+                    let call: serde_json::Value = serde_json::from_slice(body).unwrap();
+                    let method = call["method"].as_str().unwrap_or_default().to_string();
+                    self.jsonrpc_calls.lock().unwrap().push(call);
+                    // No push notification config exists
+                    match method.as_str() {
+                        "GetTaskPushNotificationConfig" => {
+                            return Ok(HttpResponse::ok(
+                                r#"{"jsonrpc": "2.0", "id": "1", "error": {"code": -32001, "message": "not found"}}"#,
+                            ));
+                        }
+                        "DeleteTaskPushNotificationConfig" => {
+                            return Ok(HttpResponse::ok(
+                                r#"{"jsonrpc": "2.0", "id": "1", "result": {}}"#,
+                            ));
+                        }
+                        _ => {}
+                    }
+                }
+                return Ok(HttpResponse::ok(format!(
+                    r#"{{"jsonrpc": "2.0", "id": "1", "result": {TASK}}}"#
+                )));
+            }
+            if req.url.starts_with("http://grpc.test") {
+                if req.url.ends_with("/GetTaskPushNotificationConfig") {
+                    return Ok(HttpResponse::ok("").with_header("grpc-status", "5"));
+                }
+                if req.url.ends_with("/DeleteTaskPushNotificationConfig") {
+                    return Ok(HttpResponse::ok(grpc::encode_frame(&Empty {}))
+                        .with_header("grpc-status", "0"));
+                }
+                let task = a2a_types::Task {
+                    id: "task-1".to_string(),
+                    ..Default::default()
+                };
+                return Ok(
+                    HttpResponse::ok(grpc::encode_frame(&task)).with_header("grpc-status", "0")
+                );
+            }
+            if req.url.starts_with("http://rest.test") {
+                return Ok(self.respond_rest(req));
+            }
+            Ok(HttpResponse::ok(TASK))
+        }
+
+        /// Answer a REST request, in protobuf when the agent supports it.
+        fn respond_rest(&self, req: &HttpRequest) -> HttpResponse {
+            if req.url.contains("/pushNotificationConfigs/") {
+                return match req.method {
+                    a2a_transport::Method::Delete => HttpResponse::ok(""),
+                    _ => HttpResponse::ok("").with_status(404),
+                };
+            }
+            let header = |name: &str| {
+                req.headers
+                    .iter()
+                    .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                    .map(|(_, v)| v.as_str())
+            };
+            let json_only = self.rest_json_only.load(Ordering::SeqCst);
+            let content_type = header("Content-Type").and_then(Codec::from_content_type);
+            if json_only && content_type == Some(Codec::Protobuf) {
+                return HttpResponse::ok("").with_status(415);
+            }
+            if json_only || Codec::negotiate(header("Accept")) != Some(Codec::Protobuf) {
+                return HttpResponse::ok(TASK);
+            }
+
+            let task = a2a_types::Task {
+                id: "task-pb".to_string(),
+                ..Default::default()
+            };
+            let body = if req.url.ends_with("/message:send") {
+                let request: a2a_types::SendMessageRequest = Codec::Protobuf
+                    .decode(req.body.as_deref().unwrap())
+                    .unwrap();
+                assert_eq!(request.request.unwrap().text(), "hello");
+                prost::Message::encode_to_vec(&a2a_types::SendMessageResponse {
+                    payload: Some(a2a_types::send_message_response::Payload::Task(task)),
+                })
+            } else {
+                prost::Message::encode_to_vec(&task)
+            };
+            HttpResponse::ok(body).with_header("Content-Type", a2a_types::codec::PROTOBUF)
+        }
+
+        fn jsonrpc_requests(&self) -> usize {
+            self.requests
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|url| url.starts_with("http://jsonrpc.test"))
+                .count()
+        }
+    }
+
+    impl a2a_transport::HttpClient for MockAgent {
+        type Error = a2a_transport::Error;
+
+        fn request(
+            &self,
+            req: HttpRequest,
+        ) -> impl std::future::Future<Output = std::result::Result<HttpResponse, Self::Error>> + Send
+        {
+            let extensions = req
+                .headers
+                .iter()
+                .find(|(name, _)| name == extension::HEADER)
+                .map(|(_, value)| value.clone());
+            self.extension_headers.lock().unwrap().push(extensions);
+            let result = self.respond(&req);
+            async move { result }
+        }
+
+        fn request_stream(
+            &self,
+            _req: HttpRequest,
+        ) -> impl std::future::Future<
+            Output = std::result::Result<
+                a2a_transport::StreamingHttpResponse<
+                    impl futures_core::Stream<
+                        Item = std::result::Result<a2a_transport::BodyFrame, Self::Error>,
+                    > + Send,
+                >,
+                Self::Error,
+            >,
+        > + Send {
+            async {
+                Err::<a2a_transport::StreamingHttpResponse<EmptyStream>, _>(
+                    a2a_transport::Error::Connection("mock".to_string()),
+                )
+            }
+        }
+
+        fn is_connect_error(error: &Self::Error) -> bool {
+            matches!(error, a2a_transport::Error::Connection(_))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failover_to_next_interface() {
+        let agent = MockAgent::default();
+        agent.jsonrpc_down.store(true, Ordering::SeqCst);
+
+        let client = Client::connect(agent.clone(), "http://agent.test")
+            .await
+            .unwrap();
+        let task = client.get_task(&TaskId::new("task-1"), None).await.unwrap();
+
+        assert_eq!(task.unwrap().id, "task-1");
+        assert_eq!(client.binding().binding(), a2a_types::Binding::Rest);
+        assert_eq!(agent.jsonrpc_requests(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_send_message_does_not_fail_over() {
+        let agent = MockAgent::default();
+        agent.jsonrpc_reset.store(true, Ordering::SeqCst);
+
+        let client = Client::connect(agent.clone(), "http://agent.test")
+            .await
+            .unwrap();
+        let result = client
+            .send_message(a2a_types::Message::user_text("hello"))
+            .await;
+
+        assert!(matches!(result, Err(Error::Transport(_))));
+        assert_eq!(agent.jsonrpc_requests(), 1);
+        assert!(
+            !agent
+                .requests
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|url| url.starts_with("http://rest.test"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_message_fails_over_when_refused() {
+        let agent = MockAgent::default();
+        agent.jsonrpc_down.store(true, Ordering::SeqCst);
+
+        let client = Client::connect(agent.clone(), "http://agent.test")
+            .await
+            .unwrap();
+        client
+            .send_message(a2a_types::Message::user_text("hello"))
+            .await
+            .unwrap();
+
+        // The refused request never reached the agent, so resending it is safe
+        assert_eq!(agent.jsonrpc_requests(), 1);
+        assert_eq!(client.binding().binding(), a2a_types::Binding::Rest);
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_interface_opens_circuit() {
+        let agent = MockAgent::default();
+        agent.jsonrpc_unavailable.store(true, Ordering::SeqCst);
+
+        let client = Client::builder(agent.clone(), "http://agent.test")
+            .failover(FailoverPolicy {
+                failure_threshold: 1,
+                reset_timeout: Duration::from_secs(3600),
+            })
+            .build()
+            .await
+            .unwrap();
+
+        let task = client.get_task(&TaskId::new("task-1"), None).await.unwrap();
+        assert_eq!(task.unwrap().id, "task-1");
+        assert_eq!(client.circuit_states()[0].1, CircuitState::Open);
+
+        client.get_task(&TaskId::new("task-1"), None).await.unwrap();
+        assert_eq!(agent.jsonrpc_requests(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_open_circuit_skips_interface() {
+        let agent = MockAgent::default();
+        agent.jsonrpc_down.store(true, Ordering::SeqCst);
+
+        let client = Client::builder(agent.clone(), "http://agent.test")
+            .failover(FailoverPolicy {
+                failure_threshold: 1,
+                reset_timeout: Duration::from_secs(3600),
+            })
+            .build()
+            .await
+            .unwrap();
+
+        client.get_task(&TaskId::new("task-1"), None).await.unwrap();
+        client.get_task(&TaskId::new("task-1"), None).await.unwrap();
+
+        assert_eq!(agent.jsonrpc_requests(), 1);
+        assert_eq!(client.circuit_states()[0].1, CircuitState::Open);
+    }
+
+    #[tokio::test]
+    async fn test_recovered_interface_is_reselected() {
+        let agent = MockAgent::default();
+        agent.jsonrpc_down.store(true, Ordering::SeqCst);
+
+        let client = Client::builder(agent.clone(), "http://agent.test")
+            .failover(FailoverPolicy {
+                failure_threshold: 1,
+                reset_timeout: Duration::ZERO,
+            })
+            .build()
+            .await
+            .unwrap();
+
+        client.get_task(&TaskId::new("task-1"), None).await.unwrap();
+        assert_eq!(client.binding().binding(), a2a_types::Binding::Rest);
+
+        agent.jsonrpc_down.store(false, Ordering::SeqCst);
+        client.get_task(&TaskId::new("task-1"), None).await.unwrap();
+        assert_eq!(client.binding().binding(), a2a_types::Binding::JsonRpc);
+        assert_eq!(client.circuit_states()[0].1, CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_forced_binding_does_not_fail_over() {
+        let agent = MockAgent::default();
+        agent.jsonrpc_down.store(true, Ordering::SeqCst);
+
+        let client = Client::builder(agent.clone(), "http://agent.test")
+            .binding(a2a_types::Binding::JsonRpc)
+            .build()
+            .await
+            .unwrap();
+        let result = client.get_task(&TaskId::new("task-1"), None).await;
+
+        assert!(matches!(result, Err(Error::Connect(_))));
+    }
+
+    #[tokio::test]
+    async fn test_grpc_binding() {
+        let agent = MockAgent::default();
+
+        let client = Client::builder(agent.clone(), "http://agent.test")
+            .binding(a2a_types::Binding::Grpc)
+            .build()
+            .await
+            .unwrap();
+        let task = client.get_task(&TaskId::new("task-1"), None).await.unwrap();
+
+        assert_eq!(task.unwrap().id, "task-1");
+        assert_eq!(
+            agent.requests.lock().unwrap().last().unwrap(),
+            "http://grpc.test/a2a.v1.A2AService/GetTask"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_push_config_on_every_binding() {
+        let name: PushConfigName = "tasks/task-1/pushNotificationConfigs/config-1"
+            .parse()
+            .unwrap();
+        let bindings = [
+            (a2a_types::Binding::JsonRpc, "http://jsonrpc.test/"),
+            (
+                a2a_types::Binding::Rest,
+                "http://rest.test/v1/tasks/task-1/pushNotificationConfigs/config-1",
+            ),
+            (
+                a2a_types::Binding::Grpc,
+                "http://grpc.test/a2a.v1.A2AService/DeleteTaskPushNotificationConfig",
+            ),
+        ];
+        for (binding, url) in bindings {
+            let agent = MockAgent::default();
+            let client = Client::builder(agent.clone(), "http://agent.test")
+                .binding(binding)
+                .build()
+                .await
+                .unwrap();
+
+            let config = client.get_task_push_notification_config(&name).await;
+            assert!(config.unwrap().is_none(), "{binding:?}");
+            client
+                .delete_task_push_notification_config(&name)
+                .await
+                .unwrap();
+            assert_eq!(agent.requests.lock().unwrap().last().unwrap(), url);
         }
+    }
+
+    #[tokio::test]
+    async fn test_rest_protobuf_codec() {
+        let agent = MockAgent::default();
+
+        let client = Client::builder(agent.clone(), "http://agent.test")
+            .binding(a2a_types::Binding::Rest)
+            .rest_codec(Codec::Protobuf)
+            .build()
+            .await
+            .unwrap();
+        let task = client.get_task(&TaskId::new("task-1"), None).await.unwrap();
+        assert_eq!(task.unwrap().id, "task-pb");
+
+        let response = client
+            .send_message(a2a_types::Message::user_text("hello"))
+            .await
+            .unwrap();
+        assert_eq!(response.as_task().unwrap().id, "task-pb");
+    }
+
+    #[tokio::test]
+    async fn test_rest_protobuf_falls_back_to_json() {
+        let agent = MockAgent::default();
+        agent.rest_json_only.store(true, Ordering::SeqCst);
+
+        let client = Client::builder(agent.clone(), "http://agent.test")
+            .binding(a2a_types::Binding::Rest)
+            .rest_codec(Codec::Protobuf)
+            .build()
+            .await
+            .unwrap();
+        let task = client.get_task(&TaskId::new("task-1"), None).await.unwrap();
+        assert_eq!(task.unwrap().id, "task-1");
+
+        let sends = || {
+            let requests = agent.requests.lock().unwrap();
+            requests
+                .iter()
+                .filter(|url| url.ends_with("/message:send"))
+                .count()
+        };
+        client
+            .send_message(a2a_types::Message::user_text("hello"))
+            .await
+            .unwrap();
+        assert_eq!(sends(), 2);
+
+        // The downgrade to JSON is remembered
+        client
+            .send_message(a2a_types::Message::user_text("hello"))
+            .await
+            .unwrap();
+        assert_eq!(sends(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_card_reselects_binding() {
+        let agent = MockAgent::default();
+
+        let mut client = Client::connect(agent.clone(), "http://agent.test")
+            .await
+            .unwrap();
+        assert_eq!(client.binding().binding(), a2a_types::Binding::JsonRpc);
+        assert!(!client.refresh_card().await.unwrap());
+
+        *agent.card.lock().unwrap() = Some(CARD.replace(
+            r#"{"url": "http://jsonrpc.test/", "protocolBinding": "JSONRPC"},"#,
+            "",
+        ));
+        assert!(client.refresh_card().await.unwrap());
+
+        assert_eq!(client.binding().binding(), a2a_types::Binding::Rest);
+        assert_eq!(client.agent_card().supported_interfaces.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_legacy_agent_gets_legacy_dialect() {
+        let agent = MockAgent::default();
+        *agent.card.lock().unwrap() = Some(CARD.replace(
+            r#""name": "test","#,
+            r#""name": "test", "protocolVersion": "0.3.0","#,
+        ));
+
+        let client = Client::connect(agent.clone(), "http://agent.test")
+            .await
+            .unwrap();
+        assert_eq!(client.protocol_version(), ProtocolVersion::V0_3);
+        let task = client
+            .get_task(&TaskId::new("task-1"), Some(5))
+            .await
+            .unwrap();
+
+        assert_eq!(task.unwrap().id, "task-1");
+        let calls = agent.jsonrpc_calls.lock().unwrap();
+        assert_eq!(calls[0]["method"], "tasks/get");
+        assert_eq!(
+            calls[0]["params"],
+            serde_json::json!({"id": "task-1", "historyLength": 5})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extension_negotiation() {
+        const TRACING: &str = "https://example.com/ext/tracing/v1";
+        let agent = MockAgent::default();
+        *agent.card.lock().unwrap() = Some(CARD.replace(
+            r#""capabilities": {}"#,
+            &format!(
+                r#""capabilities": {{"extensions": [{{"uri": "{TRACING}", "required": true}}]}}"#
+            ),
+        ));
+
+        let result = Client::connect(agent.clone(), "http://agent.test").await;
+        assert!(matches!(
+            result,
+            Err(Error::Extension(ExtensionError::Unsupported(_)))
+        ));
+
+        let client = Client::builder(agent.clone(), "http://agent.test")
+            .extension(TRACING)
+            .extension("https://example.com/ext/other/v1")
+            .build()
+            .await
+            .unwrap();
+        assert_eq!(client.requested_extensions(), [TRACING]);
+
+        client.get_task(&TaskId::new("task-1"), None).await.unwrap();
+        let headers = agent.extension_headers.lock().unwrap();
+        assert_eq!(headers.last().unwrap().as_deref(), Some(TRACING));
+    }
+
+    #[tokio::test]
+    async fn test_verify_card_refuses_unsigned() {
+        let result = Client::builder(MockAgent::default(), "http://agent.test")
+            .verify_card(CardVerifier::new())
+            .build()
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::Signature(SignatureError::Unsigned))
+        ));
 
-        let task: a2a_types::Task = serde_json::from_slice(&response.body)?;
-        Ok(Some(task))
+        let client = Client::builder(MockAgent::default(), "http://agent.test")
+            .verify_card(CardVerifier::new().allow_unsigned(true))
+            .build()
+            .await;
+        assert!(client.is_ok());
     }
 }
@@ -0,0 +1,198 @@
+//! Event streams that own their client.
+//!
+//! [`Client::send_streaming_message`](crate::Client::send_streaming_message)
+//! and [`Client::subscribe_to_task`](crate::Client::subscribe_to_task)
+//! return streams borrowing the client. An [`OwnedEventStream`] instead runs
+//! the borrowing stream inside a future that owns the client, and hands its
+//! events over one at a time. The future only runs while the stream is
+//! polled, and pauses after each event until the next poll.
+
+use crate::Result;
+use a2a_types::StreamResponse;
+use futures_core::Stream;
+use std::future::{Future, poll_fn};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+/// State shared between an [`OwnedEventStream`] and its producer.
+#[derive(Default)]
+struct Handoff {
+    event: Option<Result<StreamResponse>>,
+    opened: bool,
+}
+
+/// Stream of events from a streaming operation, owning its client.
+pub struct OwnedEventStream {
+    handoff: Arc<Mutex<Handoff>>,
+    producer: Option<Pin<Box<dyn Future<Output = ()> + Send>>>,
+}
+
+impl OwnedEventStream {
+    /// Run `produce` until it opened its stream or failed to.
+    ///
+    /// `produce` reports through the [`Sender`] it's given: either
+    /// [`Sender::opened`] followed by the events, or a single error. An
+    /// error before opening is returned here rather than streamed.
+    pub(crate) async fn open<F, Fut>(produce: F) -> Result<Self>
+    where
+        F: FnOnce(Sender) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handoff = Arc::new(Mutex::new(Handoff::default()));
+        let producer = produce(Sender {
+            handoff: handoff.clone(),
+        });
+        let mut stream = Self {
+            handoff,
+            producer: Some(Box::pin(producer)),
+        };
+
+        poll_fn(|cx| {
+            let done = stream.poll_producer(cx);
+            let mut handoff = stream.handoff.lock().unwrap();
+            match handoff.event.take() {
+                Some(Err(e)) if !handoff.opened => return Poll::Ready(Err(e)),
+                event => handoff.event = event,
+            }
+            if handoff.opened || done {
+                return Poll::Ready(Ok(()));
+            }
+            Poll::Pending
+        })
+        .await?;
+        Ok(stream)
+    }
+
+    /// Poll the producer, returning whether it finished.
+    fn poll_producer(&mut self, cx: &mut Context<'_>) -> bool {
+        let Some(producer) = self.producer.as_mut() else {
+            return true;
+        };
+        if producer.as_mut().poll(cx).is_pending() {
+            return false;
+        }
+        self.producer = None;
+        true
+    }
+}
+
+impl Stream for OwnedEventStream {
+    type Item = Result<StreamResponse>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        // An event may be left over from opening
+        if let Some(event) = this.handoff.lock().unwrap().event.take() {
+            return Poll::Ready(Some(event));
+        }
+        let done = this.poll_producer(cx);
+        match this.handoff.lock().unwrap().event.take() {
+            Some(event) => Poll::Ready(Some(event)),
+            None if done => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+}
+
+/// The producer's end of an [`OwnedEventStream`].
+pub(crate) struct Sender {
+    handoff: Arc<Mutex<Handoff>>,
+}
+
+impl Sender {
+    /// Report that the stream was opened.
+    pub(crate) fn opened(&self) {
+        self.handoff.lock().unwrap().opened = true;
+    }
+
+    /// Hand `event` over, resuming once the stream is polled again.
+    pub(crate) async fn send(&self, event: Result<StreamResponse>) {
+        self.handoff.lock().unwrap().event = Some(event);
+        let mut paused = false;
+        poll_fn(|_| {
+            if std::mem::replace(&mut paused, true) {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    /// Open `events` with [`opened`](Self::opened) and hand over each of
+    /// its events, or hand over the error that kept it from opening.
+    pub(crate) async fn forward<S>(self, events: Result<S>)
+    where
+        S: Stream<Item = Result<StreamResponse>>,
+    {
+        let events = match events {
+            Ok(events) => events,
+            Err(e) => return self.send(Err(e)).await,
+        };
+        self.opened();
+        let mut events = std::pin::pin!(events);
+        while let Some(event) = poll_fn(|cx| events.as_mut().poll_next(cx)).await {
+            self.send(event).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use std::task::Waker;
+
+    fn poll(stream: &mut OwnedEventStream) -> Poll<Option<Result<StreamResponse>>> {
+        let mut cx = Context::from_waker(Waker::noop());
+        Pin::new(stream).poll_next(&mut cx)
+    }
+
+    /// A stream of `count` default events.
+    struct Events {
+        count: usize,
+    }
+
+    impl Stream for Events {
+        type Item = Result<StreamResponse>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            if self.count == 0 {
+                return Poll::Ready(None);
+            }
+            self.count -= 1;
+            Poll::Ready(Some(Ok(StreamResponse::default())))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_events_handed_over_one_at_a_time() {
+        let owned = Arc::new(3);
+        let producer_owned = owned.clone();
+        let mut stream = OwnedEventStream::open(|sender| async move {
+            let count = *producer_owned;
+            sender.forward(Ok(Events { count })).await
+        })
+        .await
+        .unwrap();
+
+        for _ in 0..3 {
+            assert!(matches!(poll(&mut stream), Poll::Ready(Some(Ok(_)))));
+        }
+        assert!(matches!(poll(&mut stream), Poll::Ready(None)));
+        // The finished producer released what it owned
+        assert_eq!(Arc::strong_count(&owned), 1);
+    }
+
+    #[tokio::test]
+    async fn test_open_error_returned() {
+        let result = OwnedEventStream::open(|sender| async move {
+            sender
+                .forward(Err::<Events, _>(Error::Transport("refused".to_string())))
+                .await
+        })
+        .await;
+        assert!(matches!(result, Err(Error::Transport(_))));
+    }
+}
@@ -1,28 +1,103 @@
 // crates/a2a-client/src/rest.rs
 //! REST binding implementation.
+//!
+//! Unary requests and responses are encoded with the client's [`Codec`];
+//! response bodies are decoded per their `Content-Type`. Streaming requests
+//! are always JSON, as are the server-sent events.
 
-use a2a_transport::HttpRequest;
-use a2a_types::TaskId;
+use crate::error::{Error, ProtocolError};
+use a2a_transport::{HttpRequest, HttpResponse};
+use a2a_types::codec::{self, Codec};
+use a2a_types::{PushConfigName, TaskId, TaskName, TaskState};
 
 /// Build REST endpoint URL.
 pub fn endpoint(base_url: &str, path: &str) -> String {
     format!("{}{}", base_url.trim_end_matches('/'), path)
 }
 
+/// Path of a task resource, `/v1/tasks/{id}`.
+fn task_path(task_id: &TaskId) -> String {
+    format!("/v1/{}", TaskName::new(task_id.clone()))
+}
+
+/// Path of a resource or collection, `/v1/[{tenant}/]{name}`.
+fn resource_path(tenant: &str, name: &str) -> String {
+    match tenant {
+        "" => format!("/v1/{name}"),
+        tenant => format!("/v1/{tenant}/{name}"),
+    }
+}
+
+/// Append the parameters with a value to `path` as its query string.
+fn with_query(mut path: String, params: &[(&str, Option<String>)]) -> String {
+    let mut separator = '?';
+    for (name, value) in params {
+        if let Some(value) = value {
+            path.push(separator);
+            path.push_str(name);
+            path.push('=');
+            path.push_str(&encode_query_value(value));
+            separator = '&';
+        }
+    }
+    path
+}
+
+/// Percent-encode everything but unreserved characters (RFC 3986).
+fn encode_query_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// `Some` for a non-empty string.
+fn non_empty(value: &str) -> Option<String> {
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// The error for a response with a status other than 200.
+pub(crate) fn error(response: &HttpResponse) -> Error {
+    let body: Option<serde_json::Value> = serde_json::from_slice(&response.body).ok();
+    Error::Agent {
+        message: format!("REST error {}", response.status),
+        source: ProtocolError::Rest {
+            status: response.status,
+            body,
+        },
+    }
+}
+
+/// The codec of a response body per its `Content-Type`, JSON if it has none.
+pub fn response_codec(response: &HttpResponse) -> Codec {
+    response
+        .header("Content-Type")
+        .and_then(Codec::from_content_type)
+        .unwrap_or_default()
+}
+
 /// POST /v1/message:send
-pub fn send_message_request(base_url: &str, body: Vec<u8>) -> HttpRequest {
+pub fn send_message_request(base_url: &str, body: Vec<u8>, codec: Codec) -> HttpRequest {
     HttpRequest::post(endpoint(base_url, "/v1/message:send"), body)
-        .with_header("Content-Type", "application/json")
-        .with_header("Accept", "application/json")
+        .with_header("Content-Type", codec.media_type())
+        .with_header("Accept", codec.accept())
+}
+
+/// POST /v1/message:stream
+pub fn send_streaming_message_request(base_url: &str, body: Vec<u8>) -> HttpRequest {
+    HttpRequest::post(endpoint(base_url, "/v1/message:stream"), body)
+        .with_header("Content-Type", codec::JSON)
+        .with_header("Accept", "text/event-stream")
 }
 
 /// GET /v1/tasks/{id}
-pub fn get_task_request(base_url: &str, task_id: &TaskId) -> HttpRequest {
-    HttpRequest::get(endpoint(
-        base_url,
-        &format!("/v1/tasks/{}", task_id.as_str()),
-    ))
-    .with_header("Accept", "application/json")
+pub fn get_task_request(base_url: &str, task_id: &TaskId, codec: Codec) -> HttpRequest {
+    HttpRequest::get(endpoint(base_url, &task_path(task_id))).with_header("Accept", codec.accept())
 }
 
 /// GET /v1/tasks/{id}?historyLength={n}
@@ -30,25 +105,124 @@ pub fn get_task_with_history_request(
     base_url: &str,
     task_id: &TaskId,
     history_length: u32,
+    codec: Codec,
 ) -> HttpRequest {
     HttpRequest::get(endpoint(
         base_url,
-        &format!(
-            "/v1/tasks/{}?historyLength={}",
-            task_id.as_str(),
-            history_length
-        ),
+        &format!("{}?historyLength={history_length}", task_path(task_id)),
     ))
-    .with_header("Accept", "application/json")
+    .with_header("Accept", codec.accept())
 }
 
 /// POST /v1/tasks/{id}:cancel
-pub fn cancel_task_request(base_url: &str, task_id: &TaskId) -> HttpRequest {
+pub fn cancel_task_request(base_url: &str, task_id: &TaskId, codec: Codec) -> HttpRequest {
     HttpRequest::post(
-        endpoint(base_url, &format!("/v1/tasks/{}:cancel", task_id.as_str())),
+        endpoint(base_url, &format!("{}:cancel", task_path(task_id))),
         vec![],
     )
-    .with_header("Accept", "application/json")
+    .with_header("Accept", codec.accept())
+}
+
+/// GET /v1/tasks/{id}:subscribe
+pub fn subscribe_to_task_request(base_url: &str, task_id: &TaskId) -> HttpRequest {
+    HttpRequest::get(endpoint(
+        base_url,
+        &format!("{}:subscribe", task_path(task_id)),
+    ))
+    .with_header("Accept", "text/event-stream")
+}
+
+/// GET /v1/tasks?{filters}
+pub fn list_tasks_request(
+    base_url: &str,
+    params: &a2a_types::ListTasksRequest,
+    codec: Codec,
+) -> HttpRequest {
+    let status = TaskState::try_from(params.status)
+        .ok()
+        .filter(|state| *state != TaskState::Unspecified)
+        .map(|state| state.as_str_name().to_string());
+    let path = with_query(
+        resource_path(&params.tenant, "tasks"),
+        &[
+            ("contextId", non_empty(&params.context_id)),
+            ("status", status),
+            ("pageSize", params.page_size.map(|size| size.to_string())),
+            ("pageToken", non_empty(&params.page_token)),
+            (
+                "historyLength",
+                params.history_length.map(|len| len.to_string()),
+            ),
+            (
+                "lastUpdatedAfter",
+                (params.last_updated_after != 0).then(|| params.last_updated_after.to_string()),
+            ),
+            (
+                "includeArtifacts",
+                params.include_artifacts.map(|include| include.to_string()),
+            ),
+        ],
+    );
+    HttpRequest::get(endpoint(base_url, &path)).with_header("Accept", codec.accept())
+}
+
+/// POST /v1/tasks/{id}/pushNotificationConfigs?configId={config_id}
+///
+/// `body` is the config, encoded with `codec`.
+pub fn set_push_config_request(
+    base_url: &str,
+    params: &a2a_types::SetTaskPushNotificationConfigRequest,
+    body: Vec<u8>,
+    codec: Codec,
+) -> HttpRequest {
+    let path = with_query(
+        resource_path(
+            &params.tenant,
+            &format!("{}/pushNotificationConfigs", params.parent),
+        ),
+        &[("configId", non_empty(&params.config_id))],
+    );
+    HttpRequest::post(endpoint(base_url, &path), body)
+        .with_header("Content-Type", codec.media_type())
+        .with_header("Accept", codec.accept())
+}
+
+/// GET /v1/tasks/{id}/pushNotificationConfigs/{config_id}
+pub fn get_push_config_request(base_url: &str, name: &PushConfigName, codec: Codec) -> HttpRequest {
+    HttpRequest::get(endpoint(base_url, &format!("/v1/{name}")))
+        .with_header("Accept", codec.accept())
+}
+
+/// GET /v1/tasks/{id}/pushNotificationConfigs?pageSize={n}&pageToken={token}
+pub fn list_push_configs_request(
+    base_url: &str,
+    params: &a2a_types::ListTaskPushNotificationConfigRequest,
+    codec: Codec,
+) -> HttpRequest {
+    let path = with_query(
+        resource_path(
+            &params.tenant,
+            &format!("{}/pushNotificationConfigs", params.parent),
+        ),
+        &[
+            (
+                "pageSize",
+                (params.page_size != 0).then(|| params.page_size.to_string()),
+            ),
+            ("pageToken", non_empty(&params.page_token)),
+        ],
+    );
+    HttpRequest::get(endpoint(base_url, &path)).with_header("Accept", codec.accept())
+}
+
+/// DELETE /v1/tasks/{id}/pushNotificationConfigs/{config_id}
+pub fn delete_push_config_request(
+    base_url: &str,
+    name: &PushConfigName,
+    codec: Codec,
+) -> HttpRequest {
+    HttpRequest::delete(endpoint(base_url, &format!("/v1/{name}")))
+        .with_header("Accept", codec.accept())
 }
 
 #[cfg(test)]
@@ -73,13 +247,116 @@ mod tests {
 
     #[test]
     fn test_get_task_request() {
-        let req = get_task_request("https://example.com", &TaskId::new("task-123"));
+        let req = get_task_request("https://example.com", &TaskId::new("task-123"), Codec::Json);
         assert!(req.url.contains("/v1/tasks/task-123"));
     }
 
     #[test]
     fn test_cancel_task_request() {
-        let req = cancel_task_request("https://example.com", &TaskId::new("task-456"));
+        let req = cancel_task_request("https://example.com", &TaskId::new("task-456"), Codec::Json);
         assert!(req.url.contains("/v1/tasks/task-456:cancel"));
     }
+
+    #[test]
+    fn test_subscribe_to_task_request() {
+        let req = subscribe_to_task_request("https://example.com", &TaskId::new("task-789"));
+        assert!(req.url.ends_with("/v1/tasks/task-789:subscribe"));
+        assert!(
+            req.headers
+                .contains(&("Accept".to_string(), "text/event-stream".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_list_tasks_request() {
+        let params = a2a_types::ListTasksRequest {
+            context_id: "ctx 1".to_string(),
+            status: TaskState::Working as i32,
+            page_size: Some(10),
+            ..Default::default()
+        };
+        let req = list_tasks_request("https://example.com", &params, Codec::Json);
+        assert_eq!(
+            req.url,
+            "https://example.com/v1/tasks?contextId=ctx%201&status=TASK_STATE_WORKING&pageSize=10"
+        );
+
+        let params = a2a_types::ListTasksRequest {
+            tenant: "acme".to_string(),
+            ..Default::default()
+        };
+        let req = list_tasks_request("https://example.com", &params, Codec::Json);
+        assert_eq!(req.url, "https://example.com/v1/acme/tasks");
+    }
+
+    #[test]
+    fn test_push_config_requests() {
+        let params = a2a_types::SetTaskPushNotificationConfigRequest {
+            parent: "tasks/task-1".to_string(),
+            config_id: "config-1".to_string(),
+            ..Default::default()
+        };
+        let req = set_push_config_request("https://example.com", &params, vec![], Codec::Json);
+        assert_eq!(req.method, a2a_transport::Method::Post);
+        assert_eq!(
+            req.url,
+            "https://example.com/v1/tasks/task-1/pushNotificationConfigs?configId=config-1"
+        );
+
+        let params = a2a_types::ListTaskPushNotificationConfigRequest {
+            parent: "tasks/task-1".to_string(),
+            page_size: 5,
+            ..Default::default()
+        };
+        let req = list_push_configs_request("https://example.com", &params, Codec::Json);
+        assert_eq!(
+            req.url,
+            "https://example.com/v1/tasks/task-1/pushNotificationConfigs?pageSize=5"
+        );
+
+        let name: PushConfigName = "acme/tasks/task-1/pushNotificationConfigs/config-1"
+            .parse()
+            .unwrap();
+        let req = get_push_config_request("https://example.com", &name, Codec::Json);
+        assert_eq!(req.method, a2a_transport::Method::Get);
+        assert_eq!(
+            req.url,
+            "https://example.com/v1/acme/tasks/task-1/pushNotificationConfigs/config-1"
+        );
+        let req = delete_push_config_request("https://example.com", &name, Codec::Json);
+        assert_eq!(req.method, a2a_transport::Method::Delete);
+        assert_eq!(
+            req.url,
+            get_push_config_request("https://example.com", &name, Codec::Json).url
+        );
+    }
+
+    #[test]
+    fn test_protobuf_send_message_request() {
+        let req = send_message_request("https://example.com", vec![], Codec::Protobuf);
+        assert!(req.headers.contains(&(
+            "Content-Type".to_string(),
+            "application/x-protobuf".to_string()
+        )));
+        assert_eq!(
+            Codec::negotiate(Some(&req.headers[1].1)),
+            Some(Codec::Protobuf)
+        );
+    }
+
+    #[test]
+    fn test_response_codec_per_content_type() {
+        let task = a2a_types::Task {
+            id: "task-1".to_string(),
+            ..Default::default()
+        };
+        let response = HttpResponse::ok(prost::Message::encode_to_vec(&task))
+            .with_header("Content-Type", "application/x-protobuf");
+        assert_eq!(response_codec(&response), Codec::Protobuf);
+        let decoded: a2a_types::Task = response_codec(&response).decode(&response.body).unwrap();
+        assert_eq!(decoded, task);
+
+        let response = HttpResponse::ok(r#"{"id": "task-1"}"#);
+        assert_eq!(response_codec(&response), Codec::Json);
+    }
 }
@@ -0,0 +1,505 @@
+// crates/a2a-client/src/signature.rs
+//! Agent card signature verification.
+//!
+//! Each entry of `AgentCard.signatures` is a JWS (RFC 7515) with a detached
+//! payload: the JCS canonical form of the card without its `signatures`
+//! member (see [`a2a_types::jcs::card_signing_payload`]). A card is accepted
+//! when at least one signature verifies against a trusted key.
+//!
+//! Supported algorithms are `ES256`, `RS256` and `EdDSA` (Ed25519).
+
+use crate::error::{Error, Result};
+use a2a_transport::{HttpClient, HttpRequest};
+use a2a_types::jcs::canonicalize;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// Agent card signature verification errors.
+#[derive(Debug, Error)]
+pub enum SignatureError {
+    #[error("agent card is not signed")]
+    Unsigned,
+
+    #[error("malformed signature: {0}")]
+    Malformed(String),
+
+    #[error("unsupported algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+
+    #[error("no trusted key for signature (kid: {kid:?})")]
+    UnknownKey { kid: Option<String> },
+
+    #[error("signature does not match the agent card")]
+    Invalid,
+
+    #[error("failed to fetch JWKS from {url}: {reason}")]
+    Jwks { url: String, reason: String },
+}
+
+/// A public JSON Web Key (RFC 7517).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Jwk {
+    pub kty: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+}
+
+/// A JSON Web Key Set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JwkSet {
+    pub keys: Vec<Jwk>,
+}
+
+/// Protected header of a card signature.
+#[derive(Debug, Deserialize)]
+struct ProtectedHeader {
+    alg: String,
+    kid: Option<String>,
+    jku: Option<String>,
+}
+
+/// A signature that verified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedSignature {
+    /// Algorithm of the signature.
+    pub alg: String,
+    /// Key ID of the signing key, if the header named one.
+    pub kid: Option<String>,
+}
+
+/// Verify the signatures of an agent card against a set of trusted keys.
+///
+/// `card` is the card's JSON object as published. Returns the first
+/// signature that verifies.
+pub fn verify_card(
+    card: &Value,
+    keys: &[Jwk],
+) -> std::result::Result<VerifiedSignature, SignatureError> {
+    let signatures = card
+        .get("signatures")
+        .and_then(Value::as_array)
+        .filter(|s| !s.is_empty())
+        .ok_or(SignatureError::Unsigned)?;
+    let payload = URL_SAFE_NO_PAD.encode(a2a_types::jcs::card_signing_payload(card));
+
+    let mut last_error = SignatureError::Unsigned;
+    for signature in signatures {
+        match verify_signature(signature, &payload, keys) {
+            Ok(verified) => return Ok(verified),
+            Err(e) => last_error = e,
+        }
+    }
+    Err(last_error)
+}
+
+fn verify_signature(
+    signature: &Value,
+    payload: &str,
+    keys: &[Jwk],
+) -> std::result::Result<VerifiedSignature, SignatureError> {
+    let field = |name: &str| {
+        signature
+            .get(name)
+            .and_then(Value::as_str)
+            .ok_or_else(|| SignatureError::Malformed(format!("missing {name}")))
+    };
+    let protected = field("protected")?;
+    let signature = decode(field("signature")?)?;
+
+    let header: ProtectedHeader = serde_json::from_slice(&decode(protected)?)
+        .map_err(|e| SignatureError::Malformed(e.to_string()))?;
+    let signing_input = format!("{protected}.{payload}");
+
+    let mut candidates = keys
+        .iter()
+        .filter(|key| header.kid.is_none() || key.kid == header.kid)
+        .filter(|key| key.alg.as_ref().is_none_or(|alg| *alg == header.alg))
+        .peekable();
+    if candidates.peek().is_none() {
+        return Err(SignatureError::UnknownKey { kid: header.kid });
+    }
+
+    for key in candidates {
+        if verify_with_key(&header.alg, key, signing_input.as_bytes(), &signature)? {
+            return Ok(VerifiedSignature {
+                alg: header.alg,
+                kid: header.kid,
+            });
+        }
+    }
+    Err(SignatureError::Invalid)
+}
+
+/// Verify a JWS signature with one key.
+///
+/// Returns `Ok(false)` when the key does not fit the algorithm or the
+/// signature does not match.
+fn verify_with_key(
+    alg: &str,
+    key: &Jwk,
+    message: &[u8],
+    signature: &[u8],
+) -> std::result::Result<bool, SignatureError> {
+    use p256::ecdsa::signature::Verifier;
+
+    let param = |value: &Option<String>| value.as_deref().map(decode).transpose();
+
+    match alg {
+        "ES256" => {
+            let (Some(x), Some(y)) = (param(&key.x)?, param(&key.y)?) else {
+                return Ok(false);
+            };
+            if key.kty != "EC" || key.crv.as_deref() != Some("P-256") {
+                return Ok(false);
+            }
+            let mut point = vec![0x04];
+            point.extend(x);
+            point.extend(y);
+            let Ok(key) = p256::ecdsa::VerifyingKey::from_sec1_bytes(&point) else {
+                return Ok(false);
+            };
+            let Ok(signature) = p256::ecdsa::Signature::from_slice(signature) else {
+                return Ok(false);
+            };
+            Ok(key.verify(message, &signature).is_ok())
+        }
+        "RS256" => {
+            let (Some(n), Some(e)) = (param(&key.n)?, param(&key.e)?) else {
+                return Ok(false);
+            };
+            if key.kty != "RSA" {
+                return Ok(false);
+            }
+            let Ok(key) = rsa::RsaPublicKey::new(
+                rsa::BigUint::from_bytes_be(&n),
+                rsa::BigUint::from_bytes_be(&e),
+            ) else {
+                return Ok(false);
+            };
+            let key = rsa::pkcs1v15::VerifyingKey::<sha2::Sha256>::new(key);
+            let Ok(signature) = rsa::pkcs1v15::Signature::try_from(signature) else {
+                return Ok(false);
+            };
+            Ok(key.verify(message, &signature).is_ok())
+        }
+        "EdDSA" => {
+            let Some(x) = param(&key.x)? else {
+                return Ok(false);
+            };
+            if key.kty != "OKP" || key.crv.as_deref() != Some("Ed25519") {
+                return Ok(false);
+            }
+            let Ok(x) = <[u8; 32]>::try_from(x.as_slice()) else {
+                return Ok(false);
+            };
+            let Ok(key) = ed25519_dalek::VerifyingKey::from_bytes(&x) else {
+                return Ok(false);
+            };
+            let Ok(signature) = ed25519_dalek::Signature::from_slice(signature) else {
+                return Ok(false);
+            };
+            Ok(key.verify(message, &signature).is_ok())
+        }
+        other => Err(SignatureError::UnsupportedAlgorithm(other.to_string())),
+    }
+}
+
+fn decode(value: &str) -> std::result::Result<Vec<u8>, SignatureError> {
+    URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .map_err(|e| SignatureError::Malformed(e.to_string()))
+}
+
+/// Agent card verification settings for [`ClientBuilder::verify_card`].
+///
+/// Keys come from a local trust store and from JWKS URLs. A `jku` in a
+/// signature header is only followed when it is one of the configured JWKS
+/// URLs, so an agent cannot vouch for itself.
+///
+/// [`ClientBuilder::verify_card`]: crate::ClientBuilder::verify_card
+#[derive(Debug, Clone, Default)]
+pub struct CardVerifier {
+    trusted_keys: Vec<Jwk>,
+    jwks_urls: Vec<String>,
+    allow_unsigned: bool,
+}
+
+impl CardVerifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Trust a public key.
+    pub fn trust_key(mut self, key: Jwk) -> Self {
+        self.trusted_keys.push(key);
+        self
+    }
+
+    /// Trust every key of a key set.
+    pub fn trust_key_set(mut self, keys: JwkSet) -> Self {
+        self.trusted_keys.extend(keys.keys);
+        self
+    }
+
+    /// Trust the keys published at a JWKS URL.
+    pub fn jwks_url(mut self, url: impl Into<String>) -> Self {
+        self.jwks_urls.push(url.into());
+        self
+    }
+
+    /// Accept cards without signatures.
+    ///
+    /// Signed cards are still verified and rejected when invalid.
+    pub fn allow_unsigned(mut self, allow: bool) -> Self {
+        self.allow_unsigned = allow;
+        self
+    }
+
+    /// Identifier of what the verifier trusts.
+    ///
+    /// Verifiers trusting the same keys and JWKS URLs, with the same policy
+    /// for unsigned cards, have the same fingerprint.
+    pub fn fingerprint(&self) -> String {
+        let mut keys: Vec<String> = self
+            .trusted_keys
+            .iter()
+            .map(|key| canonicalize(&serde_json::to_value(key).expect("JWKs serialize")))
+            .collect();
+        keys.sort();
+        keys.dedup();
+        let mut jwks_urls = self.jwks_urls.clone();
+        jwks_urls.sort();
+        jwks_urls.dedup();
+
+        let trust = serde_json::json!({
+            "keys": keys,
+            "jwksUrls": jwks_urls,
+            "allowUnsigned": self.allow_unsigned,
+        });
+        URL_SAFE_NO_PAD.encode(Sha256::digest(canonicalize(&trust)))
+    }
+
+    /// Verify a card fetched through `transport`.
+    pub(crate) async fn verify<T: HttpClient>(&self, transport: &T, card: &Value) -> Result<()> {
+        let signed = card
+            .get("signatures")
+            .and_then(Value::as_array)
+            .is_some_and(|s| !s.is_empty());
+        if !signed {
+            return if self.allow_unsigned {
+                Ok(())
+            } else {
+                Err(SignatureError::Unsigned.into())
+            };
+        }
+
+        let mut keys = self.trusted_keys.clone();
+        for url in self.referenced_jwks_urls(card) {
+            keys.extend(fetch_jwks(transport, url).await?.keys);
+        }
+        verify_card(card, &keys)?;
+        Ok(())
+    }
+
+    /// Configured JWKS URLs, restricted to those named by a signature's
+    /// `jku` when any signature names one.
+    fn referenced_jwks_urls(&self, card: &Value) -> Vec<&str> {
+        let jkus: Vec<String> = card
+            .get("signatures")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|s| s.get("protected").and_then(Value::as_str))
+            .filter_map(|p| decode(p).ok())
+            .filter_map(|h| serde_json::from_slice::<ProtectedHeader>(&h).ok())
+            .filter_map(|h| h.jku)
+            .collect();
+
+        self.jwks_urls
+            .iter()
+            .map(String::as_str)
+            .filter(|url| jkus.is_empty() || jkus.iter().any(|jku| jku == url))
+            .collect()
+    }
+}
+
+async fn fetch_jwks<T: HttpClient>(transport: &T, url: &str) -> Result<JwkSet> {
+    let jwks_error = |reason: String| {
+        Error::from(SignatureError::Jwks {
+            url: url.to_string(),
+            reason,
+        })
+    };
+
+    let request = HttpRequest::get(url).with_header("Accept", "application/json");
+    let response = transport
+        .request(request)
+        .await
+        .map_err(|e| jwks_error(e.to_string()))?;
+    if response.status != 200 {
+        return Err(jwks_error(format!("HTTP status {}", response.status)));
+    }
+    serde_json::from_slice(&response.body).map_err(|e| jwks_error(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::signature::Signer;
+    use serde_json::json;
+
+    fn card() -> Value {
+        json!({
+            "name": "signed agent",
+            "description": "test",
+            "version": "1.0",
+            "skills": [],
+        })
+    }
+
+    fn sign(card: &mut Value, header: Value, sign: impl Fn(&[u8]) -> Vec<u8>) {
+        let protected = URL_SAFE_NO_PAD.encode(header.to_string());
+        let payload = URL_SAFE_NO_PAD.encode(a2a_types::jcs::card_signing_payload(card));
+        let signature = sign(format!("{protected}.{payload}").as_bytes());
+        card["signatures"] = json!([{
+            "protected": protected,
+            "signature": URL_SAFE_NO_PAD.encode(signature),
+        }]);
+    }
+
+    fn es256_key() -> (p256::ecdsa::SigningKey, Jwk) {
+        let signing = p256::ecdsa::SigningKey::from_slice(&[7; 32]).unwrap();
+        let point = signing.verifying_key().to_encoded_point(false);
+        let jwk = Jwk {
+            kty: "EC".to_string(),
+            kid: Some("ec-1".to_string()),
+            crv: Some("P-256".to_string()),
+            x: Some(URL_SAFE_NO_PAD.encode(point.x().unwrap())),
+            y: Some(URL_SAFE_NO_PAD.encode(point.y().unwrap())),
+            ..Default::default()
+        };
+        (signing, jwk)
+    }
+
+    fn ed25519_key() -> (ed25519_dalek::SigningKey, Jwk) {
+        let signing = ed25519_dalek::SigningKey::from_bytes(&[9; 32]);
+        let jwk = Jwk {
+            kty: "OKP".to_string(),
+            kid: Some("ed-1".to_string()),
+            crv: Some("Ed25519".to_string()),
+            x: Some(URL_SAFE_NO_PAD.encode(signing.verifying_key().as_bytes())),
+            ..Default::default()
+        };
+        (signing, jwk)
+    }
+
+    #[test]
+    fn test_verify_es256() {
+        let (signing, jwk) = es256_key();
+        let mut card = card();
+        sign(&mut card, json!({"alg": "ES256", "kid": "ec-1"}), |m| {
+            let signature: p256::ecdsa::Signature = signing.sign(m);
+            signature.to_bytes().to_vec()
+        });
+
+        let verified = verify_card(&card, &[jwk]).unwrap();
+        assert_eq!(verified.kid.as_deref(), Some("ec-1"));
+    }
+
+    #[test]
+    fn test_verify_eddsa() {
+        let (signing, jwk) = ed25519_key();
+        let mut card = card();
+        sign(&mut card, json!({"alg": "EdDSA", "kid": "ed-1"}), |m| {
+            signing.sign(m).to_bytes().to_vec()
+        });
+
+        assert!(verify_card(&card, &[jwk]).is_ok());
+    }
+
+    #[test]
+    fn test_tampered_card_is_invalid() {
+        let (signing, jwk) = ed25519_key();
+        let mut card = card();
+        sign(&mut card, json!({"alg": "EdDSA", "kid": "ed-1"}), |m| {
+            signing.sign(m).to_bytes().to_vec()
+        });
+        card["name"] = json!("impostor");
+
+        assert!(matches!(
+            verify_card(&card, &[jwk]),
+            Err(SignatureError::Invalid)
+        ));
+    }
+
+    #[test]
+    fn test_unknown_key_and_unsigned() {
+        let (signing, _) = ed25519_key();
+        let (_, other) = es256_key();
+        let mut card = card();
+        assert!(matches!(
+            verify_card(&card, std::slice::from_ref(&other)),
+            Err(SignatureError::Unsigned)
+        ));
+
+        sign(&mut card, json!({"alg": "EdDSA", "kid": "ed-1"}), |m| {
+            signing.sign(m).to_bytes().to_vec()
+        });
+        assert!(matches!(
+            verify_card(&card, &[other]),
+            Err(SignatureError::UnknownKey { .. })
+        ));
+    }
+
+    #[test]
+    fn test_jku_restricts_jwks_urls() {
+        let verifier = CardVerifier::new()
+            .jwks_url("https://keys.example.com/a.json")
+            .jwks_url("https://keys.example.com/b.json");
+        let mut card = card();
+        sign(
+            &mut card,
+            json!({"alg": "EdDSA", "jku": "https://keys.example.com/b.json"}),
+            |_| vec![0; 64],
+        );
+
+        assert_eq!(
+            verifier.referenced_jwks_urls(&card),
+            vec!["https://keys.example.com/b.json"]
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_identifies_trust() {
+        let a = CardVerifier::new()
+            .jwks_url("https://keys.example.com/a.json")
+            .jwks_url("https://keys.example.com/b.json");
+        let b = CardVerifier::new()
+            .jwks_url("https://keys.example.com/b.json")
+            .jwks_url("https://keys.example.com/a.json");
+        assert_eq!(a.fingerprint(), b.fingerprint());
+
+        let (_, key) = ed25519_key();
+        assert_ne!(a.fingerprint(), a.clone().trust_key(key).fingerprint());
+        assert_ne!(
+            a.fingerprint(),
+            a.clone().allow_unsigned(true).fingerprint()
+        );
+    }
+}
@@ -0,0 +1,413 @@
+// crates/a2a-client/src/stream.rs
+//! Decoding of streaming responses into `StreamResponse` events.
+//!
+//! JSON-RPC and REST deliver events as Server-Sent Events; gRPC delivers
+//! length-prefixed protobuf messages followed by a status in the trailers.
+//! [`EventStream`] hides the difference.
+
+use crate::error::{Error, GrpcStatusCode, ProtocolError, Result};
+use crate::grpc::{self, FrameDecoder};
+use crate::jsonrpc;
+use crate::sse::SseParser;
+use a2a_transport::{BodyFrame, StreamingHttpResponse};
+use a2a_types::StreamResponse;
+use a2a_types::version::{Dialect, JsonRpcMethod};
+use bytes::Bytes;
+use futures_core::Stream;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+/// Wire format of a response stream.
+pub(crate) enum Decoder {
+    /// SSE events carrying JSON-RPC responses to a method in a dialect.
+    JsonRpc(SseParser, JsonRpcMethod, Dialect),
+    /// SSE events carrying `StreamResponse` JSON.
+    Rest(SseParser),
+    /// gRPC length-prefixed `StreamResponse` messages, and whether the
+    /// status has been received.
+    Grpc(FrameDecoder, bool),
+    /// Body of an SSE response with a non-200 status, collected for the
+    /// error it describes.
+    Failed {
+        status: u16,
+        body: Vec<u8>,
+        jsonrpc: Option<(JsonRpcMethod, Dialect)>,
+    },
+}
+
+impl Decoder {
+    pub(crate) fn json_rpc(method: JsonRpcMethod, dialect: Dialect) -> Self {
+        Self::JsonRpc(SseParser::new(), method, dialect)
+    }
+
+    pub(crate) fn rest() -> Self {
+        Self::Rest(SseParser::new())
+    }
+
+    pub(crate) fn grpc() -> Self {
+        Self::Grpc(FrameDecoder::new(), false)
+    }
+
+    /// Check the response status and headers.
+    ///
+    /// Returns `true` if they end the stream: an error status, or a gRPC
+    /// response with no messages that carries its status in the headers
+    /// (Trailers-Only).
+    fn start(
+        &mut self,
+        status: u16,
+        headers: &[(String, String)],
+        out: &mut VecDeque<Result<StreamResponse>>,
+    ) -> bool {
+        match self {
+            Self::Grpc(..) if status != 200 => {
+                out.push_back(Err(grpc::grpc_error(
+                    GrpcStatusCode::Unknown,
+                    format!("HTTP status {status}"),
+                )));
+                true
+            }
+            Self::Grpc(..) => match grpc::status(headers) {
+                Some(status) => {
+                    out.extend(status_error(status).map(Err));
+                    true
+                }
+                None => false,
+            },
+            Self::JsonRpc(_, method, dialect) if status != 200 => {
+                *self = Self::Failed {
+                    status,
+                    body: Vec::new(),
+                    jsonrpc: Some((*method, *dialect)),
+                };
+                false
+            }
+            Self::Rest(_) if status != 200 => {
+                *self = Self::Failed {
+                    status,
+                    body: Vec::new(),
+                    jsonrpc: None,
+                };
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn decode(&mut self, chunk: &Bytes, out: &mut VecDeque<Result<StreamResponse>>) {
+        match self {
+            Self::JsonRpc(parser, method, dialect) => {
+                out.extend(parser.feed(chunk).into_iter().map(|event| {
+                    jsonrpc::decode_response(event.data.as_bytes(), *method, *dialect)
+                }));
+            }
+            Self::Rest(parser) => {
+                out.extend(
+                    parser
+                        .feed(chunk)
+                        .into_iter()
+                        .map(|event| Ok(serde_json::from_str(&event.data)?)),
+                );
+            }
+            Self::Grpc(decoder, _) => match decoder.feed(chunk) {
+                Ok(payloads) => {
+                    out.extend(payloads.iter().map(|payload| grpc::decode_message(payload)))
+                }
+                Err(e) => out.push_back(Err(grpc::frame_error(e))),
+            },
+            Self::Failed { body, .. } => body.extend_from_slice(chunk),
+        }
+    }
+
+    fn trailers(
+        &mut self,
+        trailers: &[(String, String)],
+        out: &mut VecDeque<Result<StreamResponse>>,
+    ) {
+        if let Self::Grpc(_, received) = self
+            && let Some(status) = grpc::status(trailers)
+        {
+            *received = true;
+            out.extend(status_error(status).map(Err));
+        }
+    }
+
+    fn finish(&self) -> Option<Error> {
+        match self {
+            Self::Grpc(decoder, _) if decoder.has_partial_frame() => Some(Error::Transport(
+                "stream ended inside a gRPC message".to_string(),
+            )),
+            Self::Grpc(_, false) => Some(Error::Transport(
+                "gRPC stream ended without a status".to_string(),
+            )),
+            Self::Failed {
+                status,
+                body,
+                jsonrpc: Some((method, dialect)),
+            } => Some(
+                match jsonrpc::decode_response::<StreamResponse>(body, *method, *dialect) {
+                    Err(e @ Error::Agent { .. }) => e,
+                    _ => Error::Transport(format!("HTTP status {status}")),
+                },
+            ),
+            Self::Failed {
+                status,
+                body,
+                jsonrpc: None,
+            } => Some(Error::Agent {
+                message: format!("REST error {status}"),
+                source: ProtocolError::Rest {
+                    status: *status,
+                    body: serde_json::from_slice(body).ok(),
+                },
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// The error for a non-OK gRPC status.
+fn status_error((code, message): (GrpcStatusCode, String)) -> Option<Error> {
+    (code != GrpcStatusCode::Ok).then(|| grpc::grpc_error(code, message))
+}
+
+/// Stream of events from a streaming operation.
+pub struct EventStream<S> {
+    inner: Pin<Box<S>>,
+    decoder: Decoder,
+    pending: VecDeque<Result<StreamResponse>>,
+    done: bool,
+}
+
+impl<S> EventStream<S> {
+    pub(crate) fn new(response: StreamingHttpResponse<S>, mut decoder: Decoder) -> Self {
+        let mut pending = VecDeque::new();
+        let done = decoder.start(response.status, &response.headers, &mut pending);
+        Self {
+            inner: Box::pin(response.body),
+            decoder,
+            pending,
+            done,
+        }
+    }
+}
+
+impl<S, E> Stream for EventStream<S>
+where
+    S: Stream<Item = std::result::Result<BodyFrame, E>>,
+    E: std::fmt::Display,
+{
+    type Item = Result<StreamResponse>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.pending.pop_front() {
+                return Poll::Ready(Some(item));
+            }
+            if this.done {
+                return Poll::Ready(None);
+            }
+            match ready!(this.inner.as_mut().poll_next(cx)) {
+                Some(Ok(BodyFrame::Data(chunk))) => this.decoder.decode(&chunk, &mut this.pending),
+                Some(Ok(BodyFrame::Trailers(trailers))) => {
+                    this.decoder.trailers(&trailers, &mut this.pending)
+                }
+                Some(Err(e)) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(Error::Transport(e.to_string()))));
+                }
+                None => {
+                    this.done = true;
+                    if let Some(e) = this.decoder.finish() {
+                        this.pending.push_back(Err(e));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use a2a_types::Task;
+    use a2a_types::stream_response::Payload;
+
+    struct Frames(VecDeque<BodyFrame>);
+
+    impl Stream for Frames {
+        type Item = std::result::Result<BodyFrame, std::io::Error>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.0.pop_front().map(Ok))
+        }
+    }
+
+    fn headers(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn collect(chunks: Vec<Vec<u8>>, decoder: Decoder) -> Vec<Result<StreamResponse>> {
+        let frames = chunks
+            .into_iter()
+            .map(|chunk| BodyFrame::Data(Bytes::from(chunk)))
+            .collect();
+        collect_response(200, Vec::new(), frames, decoder)
+    }
+
+    fn collect_grpc(
+        chunks: Vec<Vec<u8>>,
+        trailers: &[(&str, &str)],
+    ) -> Vec<Result<StreamResponse>> {
+        let mut frames: VecDeque<BodyFrame> = chunks
+            .into_iter()
+            .map(|chunk| BodyFrame::Data(Bytes::from(chunk)))
+            .collect();
+        frames.push_back(BodyFrame::Trailers(headers(trailers)));
+        collect_response(200, Vec::new(), frames, Decoder::grpc())
+    }
+
+    fn collect_response(
+        status: u16,
+        headers: Vec<(String, String)>,
+        frames: VecDeque<BodyFrame>,
+        decoder: Decoder,
+    ) -> Vec<Result<StreamResponse>> {
+        let response = StreamingHttpResponse {
+            status,
+            headers,
+            body: Frames(frames),
+        };
+        let mut stream = EventStream::new(response, decoder);
+        let waker = std::task::Waker::noop();
+        let mut cx = Context::from_waker(waker);
+        let mut items = Vec::new();
+        while let Poll::Ready(Some(item)) = Pin::new(&mut stream).poll_next(&mut cx) {
+            items.push(item);
+        }
+        items
+    }
+
+    fn task_event() -> StreamResponse {
+        StreamResponse {
+            payload: Some(Payload::Task(Task {
+                id: "task-1".to_string(),
+                ..Default::default()
+            })),
+        }
+    }
+
+    #[test]
+    fn test_jsonrpc_events() {
+        let body = concat!(
+            "data: {\"jsonrpc\":\"2.0\",\"id\":\"1\",\"result\":{\"task\":{\"id\":\"task-1\"}}}\n\n",
+            "data: {\"jsonrpc\":\"2.0\",\"id\":\"1\",\"error\":{\"code\":-32001,\"message\":\"gone\"}}\n\n",
+        );
+        let decoder = Decoder::json_rpc(JsonRpcMethod::SendStreamingMessage, Dialect::V1);
+        let items = collect(vec![body.as_bytes().to_vec()], decoder);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].as_ref().unwrap(), &task_event());
+        assert!(matches!(items[1], Err(Error::Agent { .. })));
+    }
+
+    #[test]
+    fn test_legacy_jsonrpc_events() {
+        let body = "data: {\"jsonrpc\":\"2.0\",\"id\":\"1\",\"result\":{\"kind\":\"task\",\"id\":\"task-1\"}}\n\n";
+        let decoder = Decoder::json_rpc(JsonRpcMethod::SubscribeToTask, Dialect::Legacy);
+        let items = collect(vec![body.as_bytes().to_vec()], decoder);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].as_ref().unwrap(), &task_event());
+    }
+
+    #[test]
+    fn test_rest_events() {
+        let body = "data: {\"task\":{\"id\":\"task-1\"}}\n\n";
+        let items = collect(vec![body.as_bytes().to_vec()], Decoder::rest());
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].as_ref().unwrap(), &task_event());
+    }
+
+    #[test]
+    fn test_rest_error_status() {
+        let frames = VecDeque::from([BodyFrame::Data(Bytes::from_static(b"{\"error\":\"gone\"}"))]);
+        let items = collect_response(404, Vec::new(), frames, Decoder::rest());
+        assert_eq!(items.len(), 1);
+        assert!(matches!(
+            items[0],
+            Err(Error::Agent {
+                source: ProtocolError::Rest { status: 404, .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_grpc_frames_across_chunks() {
+        let frame = grpc::encode_frame(&task_event());
+        let (head, tail) = frame.split_at(7);
+        let items = collect_grpc(vec![head.to_vec(), tail.to_vec()], &[("grpc-status", "0")]);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].as_ref().unwrap(), &task_event());
+    }
+
+    #[test]
+    fn test_grpc_truncated_frame() {
+        let frame = grpc::encode_frame(&task_event());
+        let items = collect(vec![frame[..frame.len() - 1].to_vec()], Decoder::grpc());
+        assert!(matches!(items[0], Err(Error::Transport(_))));
+    }
+
+    #[test]
+    fn test_grpc_error_status_in_trailers() {
+        let frame = grpc::encode_frame(&task_event());
+        let trailers = [("grpc-status", "13"), ("grpc-message", "agent%20failed")];
+        let items = collect_grpc(vec![frame], &trailers);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].as_ref().unwrap(), &task_event());
+        match &items[1] {
+            Err(Error::Agent {
+                source: ProtocolError::Grpc { code, message },
+                ..
+            }) => {
+                assert_eq!(*code, GrpcStatusCode::Internal);
+                assert_eq!(message, "agent failed");
+            }
+            other => panic!("unexpected item: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_grpc_stream_without_status() {
+        let frame = grpc::encode_frame(&task_event());
+        let items = collect(vec![frame], Decoder::grpc());
+        assert_eq!(items.len(), 2);
+        assert!(items[0].is_ok());
+        assert!(matches!(items[1], Err(Error::Transport(_))));
+    }
+
+    #[test]
+    fn test_grpc_trailers_only_error() {
+        let items = collect_response(
+            200,
+            headers(&[("grpc-status", "5"), ("grpc-message", "no%20task")]),
+            VecDeque::new(),
+            Decoder::grpc(),
+        );
+        assert_eq!(items.len(), 1);
+        assert!(matches!(
+            items[0],
+            Err(Error::Agent {
+                source: ProtocolError::Grpc {
+                    code: GrpcStatusCode::NotFound,
+                    ..
+                },
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+// crates/a2a-server/src/agent_card.rs
+//! Agent card endpoint (`/.well-known/agent-card.json`).
+
+use crate::error::Result;
+use crate::signing::CardSigner;
+use a2a_transport::{HttpRequest, HttpResponse, Method};
+use a2a_types::AgentCard;
+use bytes::Bytes;
+use sha2::{Digest, Sha256};
+
+/// Well-known path of the agent card.
+pub const AGENT_CARD_PATH: &str = "/.well-known/agent-card.json";
+
+/// Serves an agent card, signed when a signer is configured.
+///
+/// The card is serialized (and signed) once, and served with an `ETag` so
+/// clients can revalidate with `If-None-Match`.
+pub struct AgentCardEndpoint {
+    body: Bytes,
+    etag: String,
+    cache_control: Option<String>,
+}
+
+impl AgentCardEndpoint {
+    /// Serve a card as-is.
+    pub fn new(card: &AgentCard) -> Result<Self> {
+        let body = Bytes::from(serde_json::to_vec(card)?);
+        let digest = Sha256::digest(&body);
+        let etag = format!(
+            "\"{}\"",
+            digest[..16]
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect::<String>()
+        );
+        Ok(Self {
+            body,
+            etag,
+            cache_control: None,
+        })
+    }
+
+    /// Serve a card signed with `signer`.
+    pub fn signed(card: &AgentCard, signer: &CardSigner) -> Result<Self> {
+        let mut card = card.clone();
+        signer.sign(&mut card)?;
+        Self::new(&card)
+    }
+
+    /// Set the `Cache-Control` header of responses.
+    pub fn with_cache_control(mut self, value: impl Into<String>) -> Self {
+        self.cache_control = Some(value.into());
+        self
+    }
+
+    /// Whether a request targets the agent card.
+    pub fn matches(request: &HttpRequest) -> bool {
+        request.method == Method::Get
+            && request
+                .url
+                .split('?')
+                .next()
+                .is_some_and(|path| path.ends_with(AGENT_CARD_PATH))
+    }
+
+    /// Handle an agent card request.
+    pub fn handle(&self, request: &HttpRequest) -> HttpResponse {
+        let revalidated = request.headers.iter().any(|(name, value)| {
+            name.eq_ignore_ascii_case("If-None-Match")
+                && value.split(',').any(|tag| tag.trim() == self.etag)
+        });
+
+        let response = if revalidated {
+            HttpResponse::ok(Bytes::new()).with_status(304)
+        } else {
+            HttpResponse::ok(self.body.clone()).with_header("Content-Type", "application/json")
+        };
+        let response = response.with_header("ETag", self.etag.clone());
+        match &self.cache_control {
+            Some(value) => response.with_header("Cache-Control", value.clone()),
+            None => response,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::SigningKey;
+    use a2a_client::signature::{Jwk, verify_card};
+
+    fn card() -> AgentCard {
+        AgentCard {
+            name: "agent".to_string(),
+            description: "test".to_string(),
+            version: "1.0".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_serves_signed_card() {
+        let signer = CardSigner::new(SigningKey::EdDsa(ed25519_dalek::SigningKey::from_bytes(
+            &[3; 32],
+        )))
+        .with_kid("key-1");
+        let endpoint = AgentCardEndpoint::signed(&card(), &signer).unwrap();
+
+        let request = HttpRequest::get(format!("http://agent.test{AGENT_CARD_PATH}"));
+        assert!(AgentCardEndpoint::matches(&request));
+        let response = endpoint.handle(&request);
+        assert_eq!(response.status, 200);
+
+        let served: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+        let jwk: Jwk = serde_json::from_value(signer.public_jwk()).unwrap();
+        assert!(verify_card(&served, &[jwk]).is_ok());
+    }
+
+    #[test]
+    fn test_etag_revalidation() {
+        let endpoint = AgentCardEndpoint::new(&card())
+            .unwrap()
+            .with_cache_control("max-age=300");
+        let request = HttpRequest::get(AGENT_CARD_PATH);
+        let etag = endpoint
+            .handle(&request)
+            .header("ETag")
+            .unwrap()
+            .to_string();
+
+        let response = endpoint.handle(&request.with_header("If-None-Match", etag));
+        assert_eq!(response.status, 304);
+        assert!(response.body.is_empty());
+        assert_eq!(response.header("Cache-Control"), Some("max-age=300"));
+    }
+}
@@ -23,6 +23,9 @@ pub enum Error {
 
     #[error("Invalid params: {0}")]
     InvalidParams(String),
+
+    #[error("Signing error: {0}")]
+    Signing(String),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,1195 @@
+// crates/a2a-server/src/grpc.rs
+//! gRPC binding for the `a2a.v1.A2AService` service.
+//!
+//! Requests and responses are the prost types from `a2a-types`, framed per
+//! the gRPC over HTTP/2 wire format (see [`a2a_types::grpc`]). The handler
+//! and store keep working on JSON values; messages are converted through
+//! their canonical JSON form.
+//!
+//! [`GrpcService::handle_stream`] streams responses, ending the body with
+//! the `grpc-status` and `grpc-message` trailers; the `HttpServer`
+//! implementation must speak HTTP/2 to send them. [`GrpcService::handle`]
+//! serves the unary methods as complete responses with the status set on
+//! `HttpResponse::headers`, to be sent as trailers whenever the body is
+//! non-empty.
+//!
+//! Events of `SendStreamingMessage` streams and tasks returned by
+//! `SendMessage` and `CancelTask` are relayed to `SubscribeToTask` streams
+//! of the same service.
+//!
+//! Every method of the service is served. Tasks are listed from the
+//! [`TaskStore`], newest first, with page tokens holding the offset of the
+//! next page. Push notification configs are kept in a [`PushConfigStore`]
+//! and only recorded: the service doesn't deliver notifications.
+//!
+//! Extensions requested in the `X-A2A-Extensions` metadata are activated
+//! against the service's [`ExtensionRegistry`], passed to the handler in
+//! [`RequestContext::extensions`] and echoed in the response metadata.
+
+use crate::error::{Error, Result};
+use crate::handler::{AgentHandler, RequestContext};
+use crate::store::{InMemoryPushConfigStore, PushConfigStore, TaskFilter, TaskStore};
+use crate::subscribers::{self, Subscribers, Subscription};
+use a2a_transport::{BodyFrame, HttpRequest, HttpResponse, StreamingHttpResponse};
+use a2a_types::error::ConversionError;
+use a2a_types::extension::{self, ExtensionRegistry};
+pub use a2a_types::grpc::SERVICE;
+use a2a_types::grpc::{CONTENT_TYPE, StatusCode, decode_frame, encode_frame, percent_encode};
+use a2a_types::{
+    AgentCard, CancelTaskRequest, DeleteTaskPushNotificationConfigRequest,
+    GetExtendedAgentCardRequest, GetTaskPushNotificationConfigRequest, GetTaskRequest,
+    ListTaskPushNotificationConfigRequest, ListTaskPushNotificationConfigResponse,
+    ListTasksRequest, ListTasksResponse, PushConfigName, SendMessageRequest, SendMessageResponse,
+    SetTaskPushNotificationConfigRequest, StreamResponse, SubscribeToTaskRequest, Task, TaskName,
+    TaskPushNotificationConfig, TaskState, send_message_response, stream_response,
+};
+use bytes::Bytes;
+use futures_core::Stream;
+use prost::Message;
+use std::future::{Future, poll_fn};
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+/// A non-OK gRPC status.
+#[derive(Debug)]
+struct Status {
+    code: StatusCode,
+    message: String,
+}
+
+impl Status {
+    fn new(code: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<Error> for Status {
+    fn from(error: Error) -> Self {
+        let code = match error {
+            Error::MethodNotFound(_) => StatusCode::Unimplemented,
+            Error::InvalidParams(_) => StatusCode::InvalidArgument,
+            _ => StatusCode::Internal,
+        };
+        Self::new(code, error.to_string())
+    }
+}
+
+/// Default and maximum page size of list methods.
+const DEFAULT_PAGE_SIZE: usize = 50;
+const MAX_PAGE_SIZE: usize = 100;
+
+/// Serves `A2AService` over gRPC with an agent handler and task store.
+///
+/// The push notification config methods need a [`PushConfigStore`] and
+/// `GetExtendedAgentCard` needs an extended card; until they are configured
+/// those methods fail with `UNIMPLEMENTED`.
+pub struct GrpcService<H, S, P = InMemoryPushConfigStore> {
+    handler: H,
+    store: S,
+    push_configs: Option<P>,
+    extended_card: Option<AgentCard>,
+    extensions: ExtensionRegistry,
+    subscribers: Subscribers,
+}
+
+impl<H: AgentHandler, S: TaskStore> GrpcService<H, S> {
+    pub fn new(handler: H, store: S) -> Self {
+        Self {
+            handler,
+            store,
+            push_configs: None,
+            extended_card: None,
+            extensions: ExtensionRegistry::new(),
+            subscribers: Subscribers::default(),
+        }
+    }
+}
+
+impl<H: AgentHandler, S: TaskStore, P: PushConfigStore> GrpcService<H, S, P> {
+    /// Store push notification configs in `store`.
+    ///
+    /// The agent card should then declare the `pushNotifications`
+    /// capability.
+    pub fn with_push_config_store<Q: PushConfigStore>(self, store: Q) -> GrpcService<H, S, Q> {
+        GrpcService {
+            handler: self.handler,
+            store: self.store,
+            push_configs: Some(store),
+            extended_card: self.extended_card,
+            extensions: self.extensions,
+            subscribers: self.subscribers,
+        }
+    }
+
+    /// Serve `card` from `GetExtendedAgentCard`.
+    ///
+    /// The service doesn't authenticate callers; serve it behind whatever
+    /// authentication the extended card calls for.
+    pub fn with_extended_card(mut self, card: AgentCard) -> Self {
+        self.extended_card = Some(card);
+        self
+    }
+
+    /// Set the extensions the agent supports, usually
+    /// [`ExtensionRegistry::from_card`] of its card.
+    ///
+    /// Requests that don't request a required extension fail with
+    /// `FAILED_PRECONDITION`.
+    pub fn with_extensions(mut self, extensions: ExtensionRegistry) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Whether a request targets this service.
+    pub fn matches(request: &HttpRequest) -> bool {
+        method_name(&request.url).is_some()
+    }
+
+    /// Handle a gRPC request, buffering the response.
+    ///
+    /// The server-streaming methods, `SendStreamingMessage` and
+    /// `SubscribeToTask`, fail with `UNIMPLEMENTED`: serve them with
+    /// [`handle_stream`](Self::handle_stream).
+    pub async fn handle(&self, request: HttpRequest) -> HttpResponse {
+        let (method, activated) = match self.prepare(&request) {
+            Ok(prepared) => prepared,
+            Err(status) => return status_response(status),
+        };
+        let body = request.body.as_deref().unwrap_or_default();
+
+        let response = match self.call_unary(method, body, &activated).await {
+            Ok(frame) => HttpResponse::ok(frame)
+                .with_header("Content-Type", CONTENT_TYPE)
+                .with_header("grpc-status", StatusCode::Ok.to_string()),
+            Err(status) => status_response(status),
+        };
+        if activated.is_empty() {
+            response
+        } else {
+            response.with_header(extension::HEADER, extension::format_header(&activated))
+        }
+    }
+
+    /// Handle a gRPC request, streaming the response.
+    ///
+    /// Serves every method. Messages are sent as they are produced and the
+    /// body ends with the `grpc-status` trailers; calls failing before any
+    /// message is produced get a trailers-only response.
+    pub async fn handle_stream(&self, request: HttpRequest) -> StreamingHttpResponse<GrpcBody<'_>> {
+        let (method, activated) = match self.prepare(&request) {
+            Ok(prepared) => prepared,
+            Err(status) => return status_stream_response(status),
+        };
+        let body = request.body.as_deref().unwrap_or_default();
+
+        let messages = match method {
+            "SendStreamingMessage" => self.send_streaming_message(body, &activated).await,
+            "SubscribeToTask" => self.subscribe_to_task(body).await,
+            method => self
+                .call_unary(method, body, &activated)
+                .await
+                .map(|frame| Box::pin(Frames::new(vec![Ok(frame)])) as Messages<'_>),
+        };
+        let mut response = match messages {
+            Ok(messages) => StreamingHttpResponse {
+                status: 200,
+                headers: vec![("Content-Type".to_string(), CONTENT_TYPE.to_string())],
+                body: Box::pin(Framed {
+                    messages: Some(messages),
+                }) as GrpcBody<'_>,
+            },
+            Err(status) => status_stream_response(status),
+        };
+        if !activated.is_empty() {
+            response.headers.push((
+                extension::HEADER.to_string(),
+                extension::format_header(&activated),
+            ));
+        }
+        response
+    }
+
+    /// Find the method a request calls and activate its extensions.
+    fn prepare<'r>(
+        &self,
+        request: &'r HttpRequest,
+    ) -> std::result::Result<(&'r str, Vec<String>), Status> {
+        let method = method_name(&request.url)
+            .ok_or_else(|| Status::new(StatusCode::Unimplemented, "unknown service"))?;
+        let requested = request
+            .headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(extension::HEADER))
+            .map(|(_, value)| extension::parse_header(value))
+            .unwrap_or_default();
+        let activated = self
+            .extensions
+            .activate(&requested)
+            .map_err(|e| Status::new(StatusCode::FailedPrecondition, e.to_string()))?;
+        Ok((method, activated))
+    }
+
+    /// Call a method answering with a single message.
+    async fn call_unary(
+        &self,
+        method: &str,
+        body: &[u8],
+        extensions: &[String],
+    ) -> std::result::Result<Vec<u8>, Status> {
+        match method {
+            "SendMessage" => self.send_message(body, extensions).await,
+            "GetTask" => self.get_task(body).await,
+            "ListTasks" => self.list_tasks(body).await,
+            "CancelTask" => self.cancel_task(body).await,
+            "SetTaskPushNotificationConfig" => self.set_push_config(body).await,
+            "GetTaskPushNotificationConfig" => self.get_push_config(body).await,
+            "ListTaskPushNotificationConfig" => self.list_push_configs(body).await,
+            "DeleteTaskPushNotificationConfig" => self.delete_push_config(body).await,
+            "GetExtendedAgentCard" => self.get_extended_card(body),
+            "SendStreamingMessage" | "SubscribeToTask" => Err(Status::new(
+                StatusCode::Unimplemented,
+                format!("{method} streams its response; serve it with handle_stream"),
+            )),
+            other => Err(Error::MethodNotFound(other.to_string()).into()),
+        }
+    }
+
+    async fn send_message(
+        &self,
+        body: &[u8],
+        extensions: &[String],
+    ) -> std::result::Result<Vec<u8>, Status> {
+        let request: SendMessageRequest = decode_request(body)?;
+        let (message, context) = message_json(&request, extensions)?;
+
+        let response = self
+            .handler
+            .handle_message(message, context)
+            .await
+            .map_err(|e| Error::Handler(e.to_string()))?;
+        let response: SendMessageResponse =
+            serde_json::from_value(response).map_err(Error::from)?;
+
+        if let Some(send_message_response::Payload::Task(task)) = &response.payload {
+            self.save_task(task).await?;
+            self.publish_task(task);
+        }
+        Ok(encode_frame(&response))
+    }
+
+    async fn send_streaming_message(
+        &self,
+        body: &[u8],
+        extensions: &[String],
+    ) -> std::result::Result<Messages<'_>, Status> {
+        let request: SendMessageRequest = decode_request(body)?;
+        let (message, context) = message_json(&request, extensions)?;
+
+        let events = self
+            .handler
+            .handle_message_stream(message, context)
+            .await
+            .map_err(|e| Error::Handler(e.to_string()))?;
+
+        Ok(Box::pin(unfold(
+            events,
+            move |mut events: H::EventStream| async move {
+                let event = poll_fn(|cx| Pin::new(&mut events).poll_next(cx)).await?;
+                Some((self.stream_event(event).await, events))
+            },
+        )))
+    }
+
+    /// Save and relay an event of a streamed message, then frame it.
+    async fn stream_event(&self, event: serde_json::Value) -> std::result::Result<Vec<u8>, Status> {
+        let event: StreamResponse = serde_json::from_value(event).map_err(Error::from)?;
+        if let Some(stream_response::Payload::Task(task)) = &event.payload {
+            self.save_task(task).await?;
+        }
+        self.subscribers.publish(&event);
+        Ok(encode_frame(&event))
+    }
+
+    async fn get_task(&self, body: &[u8]) -> std::result::Result<Vec<u8>, Status> {
+        let request: GetTaskRequest = decode_request(body)?;
+        let mut task = self.load_task(&request.name).await?;
+
+        if let Some(len) = request.history_length {
+            trim_history(&mut task, len);
+        }
+        Ok(encode_frame(&task))
+    }
+
+    async fn list_tasks(&self, body: &[u8]) -> std::result::Result<Vec<u8>, Status> {
+        let request: ListTasksRequest = decode_request(body)?;
+        let filter = TaskFilter {
+            context_id: Some(request.context_id.clone()).filter(|id| !id.is_empty()),
+            status: (request.status != TaskState::Unspecified as i32)
+                .then(|| TaskState::try_from(request.status))
+                .transpose()
+                .map_err(|e| Status::new(StatusCode::InvalidArgument, e.to_string()))?
+                .map(|state| state.as_str_name().to_string()),
+        };
+        let tasks = self
+            .store
+            .list(filter)
+            .await
+            .map_err(|e| Error::Store(e.to_string()))?;
+
+        let mut tasks = tasks
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<std::result::Result<Vec<Task>, _>>()
+            .map_err(Error::from)?;
+        tasks.retain(|task| updated_at(task) >= request.last_updated_after);
+        // Most recently updated first
+        tasks.sort_by(|a, b| updated_at(b).cmp(&updated_at(a)).then(a.id.cmp(&b.id)));
+
+        let total_size = tasks.len();
+        let page = paginate(tasks, request.page_size, &request.page_token)?;
+
+        let mut tasks = page.items;
+        for task in &mut tasks {
+            if let Some(len) = request.history_length {
+                trim_history(task, len);
+            }
+            if !request.include_artifacts.unwrap_or(false) {
+                task.artifacts.clear();
+            }
+        }
+        Ok(encode_frame(&ListTasksResponse {
+            tasks,
+            next_page_token: page.next_page_token,
+            page_size: page.page_size as i32,
+            total_size: total_size as i32,
+        }))
+    }
+
+    async fn cancel_task(&self, body: &[u8]) -> std::result::Result<Vec<u8>, Status> {
+        let request: CancelTaskRequest = decode_request(body)?;
+        let name = task_name(&request.name)?;
+        self.load_task(&request.name).await?;
+
+        let task = self
+            .handler
+            .handle_cancel(name.task_id().as_str())
+            .await
+            .map_err(|e| Error::Handler(e.to_string()))?;
+        let task: Task = serde_json::from_value(task).map_err(Error::from)?;
+
+        self.save_task(&task).await?;
+        self.publish_task(&task);
+        Ok(encode_frame(&task))
+    }
+
+    /// Sends the current task snapshot, then the task's events until it
+    /// reaches a terminal state.
+    ///
+    /// Only events of requests served by this service are relayed. A task
+    /// already in a terminal state can't be subscribed to.
+    async fn subscribe_to_task(&self, body: &[u8]) -> std::result::Result<Messages<'_>, Status> {
+        let request: SubscribeToTaskRequest = decode_request(body)?;
+        let name = task_name(&request.name)?;
+        // Subscribe first so that no event is missed after the snapshot
+        let subscription = self.subscribers.subscribe(name.task_id().as_str());
+        let task = self.load_task(&request.name).await?;
+
+        let state = task.status.as_ref().map_or(0, |status| status.state);
+        if TaskState::try_from(state).is_ok_and(subscribers::is_terminal) {
+            return Err(Status::new(
+                StatusCode::Unimplemented,
+                format!("task {} is in a terminal state", task.id),
+            ));
+        }
+        let snapshot = StreamResponse {
+            payload: Some(stream_response::Payload::Task(task)),
+        };
+        Ok(Box::pin(unfold(
+            (Some(snapshot), subscription),
+            |(snapshot, mut subscription): (Option<StreamResponse>, Subscription)| async move {
+                let event = match snapshot {
+                    Some(snapshot) => snapshot,
+                    None => poll_fn(|cx| Pin::new(&mut subscription).poll_next(cx)).await?,
+                };
+                Some((Ok(encode_frame(&event)), (None, subscription)))
+            },
+        )))
+    }
+
+    async fn set_push_config(&self, body: &[u8]) -> std::result::Result<Vec<u8>, Status> {
+        let request: SetTaskPushNotificationConfigRequest = decode_request(body)?;
+        let push_configs = self.push_configs()?;
+        let task = task_name(&request.parent)?;
+        let name = task.push_config(&request.config_id);
+        name.validate()
+            .map_err(|e| Status::new(StatusCode::InvalidArgument, e.to_string()))?;
+        let mut config = request
+            .config
+            .ok_or_else(|| Status::new(StatusCode::InvalidArgument, "missing config"))?;
+        self.load_task(&request.parent).await?;
+
+        config.name = name.resource_name();
+        if let Some(push_config) = &mut config.push_notification_config {
+            push_config.id = request.config_id.clone();
+        }
+        push_configs
+            .set(
+                task.task_id().as_str(),
+                &request.config_id,
+                &serde_json::to_value(&config).map_err(Error::from)?,
+            )
+            .await
+            .map_err(|e| Error::Store(e.to_string()))?;
+        Ok(encode_frame(&config))
+    }
+
+    async fn get_push_config(&self, body: &[u8]) -> std::result::Result<Vec<u8>, Status> {
+        let request: GetTaskPushNotificationConfigRequest = decode_request(body)?;
+        let push_configs = self.push_configs()?;
+        let name = push_config_name(&request.name)?;
+
+        let config = push_configs
+            .get(name.task().task_id().as_str(), name.config_id())
+            .await
+            .map_err(|e| Error::Store(e.to_string()))?
+            .ok_or_else(|| {
+                Status::new(
+                    StatusCode::NotFound,
+                    format!("push notification config not found: {}", request.name),
+                )
+            })?;
+        let config: TaskPushNotificationConfig =
+            serde_json::from_value(config).map_err(Error::from)?;
+        Ok(encode_frame(&config))
+    }
+
+    async fn list_push_configs(&self, body: &[u8]) -> std::result::Result<Vec<u8>, Status> {
+        let request: ListTaskPushNotificationConfigRequest = decode_request(body)?;
+        let push_configs = self.push_configs()?;
+        let task = task_name(&request.parent)?;
+
+        let configs = push_configs
+            .list(task.task_id().as_str())
+            .await
+            .map_err(|e| Error::Store(e.to_string()))?
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<std::result::Result<Vec<TaskPushNotificationConfig>, _>>()
+            .map_err(Error::from)?;
+        // Unlike in ListTasks, the page size isn't optional: 0 means unset
+        let page_size = Some(request.page_size).filter(|&size| size != 0);
+        let page = paginate(configs, page_size, &request.page_token)?;
+        Ok(encode_frame(&ListTaskPushNotificationConfigResponse {
+            configs: page.items,
+            next_page_token: page.next_page_token,
+        }))
+    }
+
+    async fn delete_push_config(&self, body: &[u8]) -> std::result::Result<Vec<u8>, Status> {
+        let request: DeleteTaskPushNotificationConfigRequest = decode_request(body)?;
+        let push_configs = self.push_configs()?;
+        let name = push_config_name(&request.name)?;
+
+        push_configs
+            .delete(name.task().task_id().as_str(), name.config_id())
+            .await
+            .map_err(|e| Error::Store(e.to_string()))?;
+        Ok(encode_frame(&()))
+    }
+
+    fn get_extended_card(&self, body: &[u8]) -> std::result::Result<Vec<u8>, Status> {
+        let _: GetExtendedAgentCardRequest = decode_request(body)?;
+        let card = self.extended_card.as_ref().ok_or_else(|| {
+            Status::new(
+                StatusCode::Unimplemented,
+                "extended agent card is not configured",
+            )
+        })?;
+        Ok(encode_frame(card))
+    }
+
+    fn push_configs(&self) -> std::result::Result<&P, Status> {
+        self.push_configs.as_ref().ok_or_else(|| {
+            Status::new(
+                StatusCode::Unimplemented,
+                "push notifications are not supported",
+            )
+        })
+    }
+
+    async fn load_task(&self, name: &str) -> std::result::Result<Task, Status> {
+        let name = task_name(name)?;
+        let task_id = name.task_id().as_str();
+        let task = self
+            .store
+            .get(task_id)
+            .await
+            .map_err(|e| Error::Store(e.to_string()))?
+            .ok_or_else(|| {
+                Status::new(StatusCode::NotFound, format!("task not found: {task_id}"))
+            })?;
+        Ok(serde_json::from_value(task).map_err(Error::from)?)
+    }
+
+    /// Relay a task snapshot to its subscribers.
+    fn publish_task(&self, task: &Task) {
+        self.subscribers.publish(&StreamResponse {
+            payload: Some(stream_response::Payload::Task(task.clone())),
+        });
+    }
+
+    async fn save_task(&self, task: &Task) -> Result<()> {
+        self.store
+            .save(&serde_json::to_value(task)?)
+            .await
+            .map_err(|e| Error::Store(e.to_string()))
+    }
+}
+
+/// Extract the method from a `/a2a.v1.A2AService/{method}` URL.
+fn method_name(url: &str) -> Option<&str> {
+    let path = url.split('?').next().unwrap_or(url);
+    let (service, method) = path.rsplit_once('/')?;
+    service
+        .strip_suffix(SERVICE)
+        .filter(|prefix| prefix.is_empty() || prefix.ends_with('/'))?;
+    Some(method)
+}
+
+/// Parse a `tasks/{id}` resource name.
+fn task_name(name: &str) -> std::result::Result<TaskName, Status> {
+    name.parse()
+        .map_err(|e: ConversionError| Status::new(StatusCode::InvalidArgument, e.to_string()))
+}
+
+/// Parse a `tasks/{id}/pushNotificationConfigs/{config_id}` resource name.
+fn push_config_name(name: &str) -> std::result::Result<PushConfigName, Status> {
+    name.parse()
+        .map_err(|e: ConversionError| Status::new(StatusCode::InvalidArgument, e.to_string()))
+}
+
+/// Keep the last `len` messages of a task's history.
+fn trim_history(task: &mut Task, len: i32) {
+    let keep = usize::try_from(len).unwrap_or(0);
+    let skip = task.history.len().saturating_sub(keep);
+    task.history.drain(..skip);
+}
+
+/// When a task was last updated, in milliseconds since the epoch.
+fn updated_at(task: &Task) -> i64 {
+    task.status
+        .as_ref()
+        .and_then(|status| status.timestamp.as_ref())
+        .map_or(0, |ts| ts.seconds * 1000 + i64::from(ts.nanos) / 1_000_000)
+}
+
+/// A page of a listing.
+struct Page<T> {
+    items: Vec<T>,
+    page_size: usize,
+    next_page_token: String,
+}
+
+/// Take the page of `items` starting at `page_token`, an offset from a
+/// previous page.
+///
+/// The page size defaults to [`DEFAULT_PAGE_SIZE`] if unset, and must be
+/// between 1 and [`MAX_PAGE_SIZE`].
+fn paginate<T>(
+    mut items: Vec<T>,
+    page_size: Option<i32>,
+    page_token: &str,
+) -> std::result::Result<Page<T>, Status> {
+    let page_size = match page_size {
+        None => DEFAULT_PAGE_SIZE,
+        Some(size @ 1..) if size as usize <= MAX_PAGE_SIZE => size as usize,
+        Some(size) => {
+            return Err(Status::new(
+                StatusCode::InvalidArgument,
+                format!("page size must be between 1 and {MAX_PAGE_SIZE}, got {size}"),
+            ));
+        }
+    };
+    let start = match page_token {
+        "" => 0,
+        token => token
+            .parse::<usize>()
+            .ok()
+            .filter(|&start| start <= items.len())
+            .ok_or_else(|| Status::new(StatusCode::InvalidArgument, "invalid page token"))?,
+    };
+    let end = items.len().min(start.saturating_add(page_size));
+    let next_page_token = if end < items.len() {
+        end.to_string()
+    } else {
+        String::new()
+    };
+    items.truncate(end);
+    items.drain(..start);
+    Ok(Page {
+        items,
+        page_size,
+        next_page_token,
+    })
+}
+
+/// Convert the message of a send request to the handler's JSON form.
+fn message_json(
+    request: &SendMessageRequest,
+    extensions: &[String],
+) -> std::result::Result<(serde_json::Value, RequestContext), Status> {
+    let message = request
+        .request
+        .as_ref()
+        .ok_or_else(|| Status::new(StatusCode::InvalidArgument, "missing message"))?;
+    let context = RequestContext {
+        task_id: Some(message.task_id.clone()).filter(|id| !id.is_empty()),
+        context_id: Some(message.context_id.clone()).filter(|id| !id.is_empty()),
+        extensions: extensions.to_vec(),
+    };
+    let json = serde_json::to_value(message).map_err(Error::from)?;
+    Ok((json, context))
+}
+
+/// Decode a request body holding a single gRPC frame.
+fn decode_request<M: Message + Default>(body: &[u8]) -> std::result::Result<M, Status> {
+    let payload = decode_frame(body).map_err(|e| Status::new(e.status_code(), e.to_string()))?;
+    M::decode(payload).map_err(|e| Status::new(StatusCode::InvalidArgument, e.to_string()))
+}
+
+/// Build a trailers-only response carrying a non-OK status.
+fn status_response(status: Status) -> HttpResponse {
+    let mut response = HttpResponse::ok(Vec::new());
+    response.headers = status_headers(&status);
+    response.with_header("Content-Type", CONTENT_TYPE)
+}
+
+/// Build a streamed trailers-only response carrying a non-OK status.
+fn status_stream_response<'a>(status: Status) -> StreamingHttpResponse<GrpcBody<'a>> {
+    let mut headers = status_headers(&status);
+    headers.push(("Content-Type".to_string(), CONTENT_TYPE.to_string()));
+    StreamingHttpResponse {
+        status: 200,
+        headers,
+        body: Box::pin(Framed { messages: None }),
+    }
+}
+
+/// The `grpc-status` and `grpc-message` headers or trailers of a status.
+fn status_headers(status: &Status) -> Vec<(String, String)> {
+    let mut headers = vec![("grpc-status".to_string(), status.code.to_string())];
+    if !status.message.is_empty() {
+        headers.push(("grpc-message".to_string(), percent_encode(&status.message)));
+    }
+    headers
+}
+
+/// Stream of response messages, ending early with a non-OK status.
+type Messages<'a> = Pin<Box<dyn Stream<Item = std::result::Result<Vec<u8>, Status>> + Send + 'a>>;
+
+/// Body of a streamed gRPC response: the messages, then the status
+/// trailers.
+pub type GrpcBody<'a> = Pin<Box<dyn Stream<Item = BodyFrame> + Send + 'a>>;
+
+/// Messages already produced.
+struct Frames(std::vec::IntoIter<std::result::Result<Vec<u8>, Status>>);
+
+impl Frames {
+    fn new(frames: Vec<std::result::Result<Vec<u8>, Status>>) -> Self {
+        Self(frames.into_iter())
+    }
+}
+
+impl Stream for Frames {
+    type Item = std::result::Result<Vec<u8>, Status>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.0.next())
+    }
+}
+
+/// Frames the messages of a response, followed by its status trailers.
+struct Framed<'a> {
+    messages: Option<Messages<'a>>,
+}
+
+impl Stream for Framed<'_> {
+    type Item = BodyFrame;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<BodyFrame>> {
+        let Some(messages) = self.messages.as_mut() else {
+            return Poll::Ready(None);
+        };
+        let status = match ready!(messages.as_mut().poll_next(cx)) {
+            Some(Ok(frame)) => return Poll::Ready(Some(BodyFrame::Data(Bytes::from(frame)))),
+            Some(Err(status)) => status,
+            None => Status::new(StatusCode::Ok, ""),
+        };
+        self.messages = None;
+        Poll::Ready(Some(BodyFrame::Trailers(status_headers(&status))))
+    }
+}
+
+/// Stream produced by running `step` on a state until it returns `None`.
+struct Unfold<S, F, Fut> {
+    state: Option<S>,
+    step: F,
+    pending: Option<Pin<Box<Fut>>>,
+}
+
+fn unfold<S, F, Fut>(state: S, step: F) -> Unfold<S, F, Fut> {
+    Unfold {
+        state: Some(state),
+        step,
+        pending: None,
+    }
+}
+
+impl<T, S, F, Fut> Stream for Unfold<S, F, Fut>
+where
+    S: Unpin,
+    F: FnMut(S) -> Fut + Unpin,
+    Fut: Future<Output = Option<(T, S)>>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = self.get_mut();
+        if this.pending.is_none() {
+            let Some(state) = this.state.take() else {
+                return Poll::Ready(None);
+            };
+            this.pending = Some(Box::pin((this.step)(state)));
+        }
+        let next = ready!(
+            this.pending
+                .as_mut()
+                .expect("step started")
+                .as_mut()
+                .poll(cx)
+        );
+        this.pending = None;
+        Poll::Ready(next.map(|(item, state)| {
+            this.state = Some(state);
+            item
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::InMemoryTaskStore;
+    use a2a_types::grpc::PREFIX_LEN;
+    use serde_json::{Value, json};
+    use std::task::{Context, Poll};
+
+    struct Events(Vec<Value>);
+
+    impl Stream for Events {
+        type Item = Value;
+
+        fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Value>> {
+            Poll::Ready((!self.0.is_empty()).then(|| self.0.remove(0)))
+        }
+    }
+
+    struct EchoAgent;
+
+    impl AgentHandler for EchoAgent {
+        type Error = std::convert::Infallible;
+        type EventStream = Events;
+
+        async fn handle_message(
+            &self,
+            _message: Value,
+            context: RequestContext,
+        ) -> std::result::Result<Value, Self::Error> {
+            Ok(json!({"task": {
+                "id": context.task_id.unwrap_or_else(|| "task-1".to_string()),
+                "status": {"state": "TASK_STATE_COMPLETED"},
+            }}))
+        }
+
+        async fn handle_message_stream(
+            &self,
+            _message: Value,
+            _context: RequestContext,
+        ) -> std::result::Result<Events, Self::Error> {
+            Ok(Events(vec![
+                json!({"task": {"id": "task-2", "status": {"state": "TASK_STATE_WORKING"}}}),
+                json!({"statusUpdate": {"taskId": "task-2", "status": {"state": "TASK_STATE_COMPLETED"}}}),
+            ]))
+        }
+
+        async fn handle_cancel(&self, task_id: &str) -> std::result::Result<Value, Self::Error> {
+            Ok(json!({"id": task_id, "status": {"state": "TASK_STATE_CANCELLED"}}))
+        }
+    }
+
+    fn service() -> GrpcService<EchoAgent, InMemoryTaskStore> {
+        GrpcService::new(EchoAgent, InMemoryTaskStore::new())
+    }
+
+    fn call(method: &str, message: &impl Message) -> HttpRequest {
+        HttpRequest::post(
+            format!("http://agent.test/{SERVICE}/{method}"),
+            encode_frame(message),
+        )
+    }
+
+    fn frames(body: &[u8]) -> Vec<&[u8]> {
+        let mut frames = Vec::new();
+        let mut rest = body;
+        while !rest.is_empty() {
+            let len = u32::from_be_bytes([rest[1], rest[2], rest[3], rest[4]]) as usize;
+            frames.push(&rest[PREFIX_LEN..PREFIX_LEN + len]);
+            rest = &rest[PREFIX_LEN + len..];
+        }
+        frames
+    }
+
+    fn send_request() -> SendMessageRequest {
+        SendMessageRequest {
+            request: Some(a2a_types::Message {
+                message_id: "msg-1".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_method_name() {
+        assert_eq!(
+            method_name("http://agent.test/a2a.v1.A2AService/GetTask"),
+            Some("GetTask")
+        );
+        assert_eq!(method_name("/a2a.v1.A2AService/GetTask"), Some("GetTask"));
+        assert_eq!(method_name("http://agent.test/v1/tasks/1"), None);
+        assert_eq!(
+            method_name("http://agent.test/xa2a.v1.A2AService/GetTask"),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_message_then_get_task() {
+        let service = service();
+
+        let response = service.handle(call("SendMessage", &send_request())).await;
+        assert_eq!(response.header("grpc-status"), Some("0"));
+        let sent = SendMessageResponse::decode(frames(&response.body)[0]).unwrap();
+        assert!(matches!(
+            sent.payload,
+            Some(send_message_response::Payload::Task(_))
+        ));
+
+        let get = GetTaskRequest {
+            name: "tasks/task-1".to_string(),
+            ..Default::default()
+        };
+        let response = service.handle(call("GetTask", &get)).await;
+        assert_eq!(response.header("grpc-status"), Some("0"));
+        let task = Task::decode(frames(&response.body)[0]).unwrap();
+        assert_eq!(task.id, "task-1");
+    }
+
+    /// Collect the messages and trailers of a streamed response.
+    async fn collect(mut body: GrpcBody<'_>) -> (Vec<Vec<u8>>, Vec<(String, String)>) {
+        let (mut messages, mut trailers) = (Vec::new(), Vec::new());
+        while let Some(frame) = poll_fn(|cx| body.as_mut().poll_next(cx)).await {
+            match frame {
+                BodyFrame::Data(data) => messages.push(decode_frame(&data).unwrap().to_vec()),
+                BodyFrame::Trailers(headers) => trailers = headers,
+            }
+        }
+        (messages, trailers)
+    }
+
+    fn grpc_status(headers: &[(String, String)]) -> Option<&str> {
+        headers
+            .iter()
+            .find(|(name, _)| name == "grpc-status")
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[tokio::test]
+    async fn test_streaming_message_frames_every_event() {
+        let service = service();
+        let response = service
+            .handle_stream(call("SendStreamingMessage", &send_request()))
+            .await;
+        assert_eq!(response.header("grpc-status"), None);
+        let (messages, trailers) = collect(response.body).await;
+        assert_eq!(messages.len(), 2);
+        assert_eq!(grpc_status(&trailers), Some("0"));
+
+        let response = service
+            .handle(call("SendStreamingMessage", &send_request()))
+            .await;
+        assert_eq!(response.header("grpc-status"), Some("12"));
+    }
+
+    #[tokio::test]
+    async fn test_unary_method_streamed() {
+        let get = GetTaskRequest {
+            name: "tasks/missing".to_string(),
+            ..Default::default()
+        };
+        let service = service();
+        let response = service.handle_stream(call("GetTask", &get)).await;
+        assert_eq!(response.header("grpc-status"), Some("5"));
+        assert_eq!(collect(response.body).await, (vec![], vec![]));
+
+        service.handle(call("SendMessage", &send_request())).await;
+        let get = GetTaskRequest {
+            name: "tasks/task-1".to_string(),
+            ..Default::default()
+        };
+        let response = service.handle_stream(call("GetTask", &get)).await;
+        let (messages, trailers) = collect(response.body).await;
+        assert_eq!(Task::decode(&messages[0][..]).unwrap().id, "task-1");
+        assert_eq!(grpc_status(&trailers), Some("0"));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_relays_events_until_terminal() {
+        let service = service();
+        service
+            .store
+            .save(&json!({"id": "task-3", "status": {"state": "TASK_STATE_WORKING"}}))
+            .await
+            .unwrap();
+
+        let subscribe = SubscribeToTaskRequest {
+            name: "tasks/task-3".to_string(),
+            ..Default::default()
+        };
+        let mut body = service
+            .handle_stream(call("SubscribeToTask", &subscribe))
+            .await
+            .body;
+        let Some(BodyFrame::Data(snapshot)) = poll_fn(|cx| body.as_mut().poll_next(cx)).await
+        else {
+            panic!("expected the task snapshot");
+        };
+        let snapshot = StreamResponse::decode(decode_frame(&snapshot).unwrap()).unwrap();
+        assert!(matches!(
+            snapshot.payload,
+            Some(stream_response::Payload::Task(task)) if task.id == "task-3"
+        ));
+
+        let cancel = CancelTaskRequest {
+            name: "tasks/task-3".to_string(),
+            ..Default::default()
+        };
+        let response = service.handle(call("CancelTask", &cancel)).await;
+        assert_eq!(response.header("grpc-status"), Some("0"));
+        let (messages, trailers) = collect(body).await;
+        assert_eq!(messages.len(), 1);
+        assert_eq!(grpc_status(&trailers), Some("0"));
+
+        // The task is now canceled
+        let response = service
+            .handle_stream(call("SubscribeToTask", &subscribe))
+            .await;
+        assert_eq!(response.header("grpc-status"), Some("12"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_task_is_not_found() {
+        let get = GetTaskRequest {
+            name: "tasks/missing".to_string(),
+            ..Default::default()
+        };
+        let response = service().handle(call("GetTask", &get)).await;
+        assert_eq!(response.header("grpc-status"), Some("5"));
+        assert!(response.body.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_extension_activation() {
+        const TRACING: &str = "https://example.com/ext/tracing/v1";
+        let service = GrpcService::new(EchoAgent, InMemoryTaskStore::new()).with_extensions(
+            ExtensionRegistry::new().declare(a2a_types::AgentExtension {
+                uri: TRACING.to_string(),
+                required: true,
+                ..Default::default()
+            }),
+        );
+
+        let response = service.handle(call("SendMessage", &send_request())).await;
+        assert_eq!(response.header("grpc-status"), Some("9"));
+
+        let request = call("SendMessage", &send_request()).with_header(
+            "x-a2a-extensions",
+            format!("{TRACING}, https://other.example.com"),
+        );
+        let response = service.handle(request).await;
+        assert_eq!(response.header("grpc-status"), Some("0"));
+        assert_eq!(response.header(extension::HEADER), Some(TRACING));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_name_and_unknown_method() {
+        let service = service();
+
+        let cancel = CancelTaskRequest {
+            name: "task-1".to_string(),
+            ..Default::default()
+        };
+        let response = service.handle(call("CancelTask", &cancel)).await;
+        assert_eq!(response.header("grpc-status"), Some("3"));
+
+        let response = service.handle(call("ListAgents", &cancel)).await;
+        assert_eq!(response.header("grpc-status"), Some("12"));
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks_pages() {
+        let service = service();
+        for (id, context_id) in [
+            ("task-1", "ctx-1"),
+            ("task-2", "ctx-1"),
+            ("task-3", "ctx-2"),
+        ] {
+            let mut request = send_request();
+            let message = request.request.as_mut().unwrap();
+            message.task_id = id.to_string();
+            message.context_id = context_id.to_string();
+            service.handle(call("SendMessage", &request)).await;
+        }
+
+        let list = ListTasksRequest {
+            page_size: Some(2),
+            ..Default::default()
+        };
+        let response = service.handle(call("ListTasks", &list)).await;
+        assert_eq!(response.header("grpc-status"), Some("0"));
+        let page = ListTasksResponse::decode(frames(&response.body)[0]).unwrap();
+        assert_eq!(page.tasks.len(), 2);
+        assert_eq!(page.total_size, 3);
+        assert!(!page.next_page_token.is_empty());
+
+        let list = ListTasksRequest {
+            page_size: Some(2),
+            page_token: page.next_page_token,
+            ..Default::default()
+        };
+        let response = service.handle(call("ListTasks", &list)).await;
+        let page = ListTasksResponse::decode(frames(&response.body)[0]).unwrap();
+        assert_eq!(page.tasks.len(), 1);
+        assert!(page.next_page_token.is_empty());
+
+        let list = ListTasksRequest {
+            status: TaskState::Completed as i32,
+            page_size: Some(101),
+            ..Default::default()
+        };
+        let response = service.handle(call("ListTasks", &list)).await;
+        assert_eq!(response.header("grpc-status"), Some("3"));
+    }
+
+    #[tokio::test]
+    async fn test_push_config_crud() {
+        let set = SetTaskPushNotificationConfigRequest {
+            parent: "tasks/task-1".to_string(),
+            config_id: "config-1".to_string(),
+            config: Some(TaskPushNotificationConfig {
+                push_notification_config: Some(a2a_types::PushNotificationConfig {
+                    url: "https://client.example.com/notify".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let response = service()
+            .handle(call("SetTaskPushNotificationConfig", &set))
+            .await;
+        assert_eq!(response.header("grpc-status"), Some("12"));
+
+        let service = service().with_push_config_store(InMemoryPushConfigStore::new());
+        let response = service
+            .handle(call("SetTaskPushNotificationConfig", &set))
+            .await;
+        assert_eq!(response.header("grpc-status"), Some("5"));
+
+        service.handle(call("SendMessage", &send_request())).await;
+        let response = service
+            .handle(call("SetTaskPushNotificationConfig", &set))
+            .await;
+        assert_eq!(response.header("grpc-status"), Some("0"));
+        let created = TaskPushNotificationConfig::decode(frames(&response.body)[0]).unwrap();
+        assert_eq!(
+            created.name,
+            "tasks/task-1/pushNotificationConfigs/config-1"
+        );
+
+        let get = GetTaskPushNotificationConfigRequest {
+            name: created.name.clone(),
+            ..Default::default()
+        };
+        let response = service
+            .handle(call("GetTaskPushNotificationConfig", &get))
+            .await;
+        let config = TaskPushNotificationConfig::decode(frames(&response.body)[0]).unwrap();
+        assert_eq!(config, created);
+
+        let list = ListTaskPushNotificationConfigRequest {
+            parent: "tasks/task-1".to_string(),
+            ..Default::default()
+        };
+        let response = service
+            .handle(call("ListTaskPushNotificationConfig", &list))
+            .await;
+        let configs =
+            ListTaskPushNotificationConfigResponse::decode(frames(&response.body)[0]).unwrap();
+        assert_eq!(configs.configs, [created]);
+
+        let list = ListTaskPushNotificationConfigRequest {
+            parent: "tasks/task-1".to_string(),
+            page_size: 101,
+            ..Default::default()
+        };
+        let response = service
+            .handle(call("ListTaskPushNotificationConfig", &list))
+            .await;
+        assert_eq!(response.header("grpc-status"), Some("3"));
+
+        let delete = DeleteTaskPushNotificationConfigRequest {
+            name: get.name.clone(),
+            ..Default::default()
+        };
+        let response = service
+            .handle(call("DeleteTaskPushNotificationConfig", &delete))
+            .await;
+        assert_eq!(response.header("grpc-status"), Some("0"));
+        let response = service
+            .handle(call("GetTaskPushNotificationConfig", &get))
+            .await;
+        assert_eq!(response.header("grpc-status"), Some("5"));
+    }
+
+    #[tokio::test]
+    async fn test_extended_agent_card() {
+        let request = GetExtendedAgentCardRequest::default();
+        let response = service()
+            .handle(call("GetExtendedAgentCard", &request))
+            .await;
+        assert_eq!(response.header("grpc-status"), Some("12"));
+
+        let card = AgentCard {
+            name: "extended".to_string(),
+            ..Default::default()
+        };
+        let service = service().with_extended_card(card.clone());
+        let response = service.handle(call("GetExtendedAgentCard", &request)).await;
+        assert_eq!(response.header("grpc-status"), Some("0"));
+        assert_eq!(AgentCard::decode(frames(&response.body)[0]).unwrap(), card);
+    }
+}
@@ -7,6 +7,8 @@ use std::future::Future;
 pub struct RequestContext {
     pub task_id: Option<String>,
     pub context_id: Option<String>,
+    /// URIs of the extensions activated for the request.
+    pub extensions: Vec<String>,
 }
 
 /// User-implemented handler for agent logic.
@@ -0,0 +1,43 @@
+// crates/a2a-server/src/interfaces.rs
+//! Agent card interface advertisement.
+
+use a2a_types::{AgentInterface, Binding};
+
+/// Build the `supportedInterfaces` entries for an agent served at `base_url`.
+///
+/// `bindings` lists the bindings the agent actually serves, in preference
+/// order; this crate serves only gRPC (see [`GrpcService`](crate::GrpcService)).
+/// All bindings share the base URL: JSON-RPC is served at its root, REST
+/// under `/v1/...` and gRPC under `/a2a.v1.A2AService/...`.
+pub fn agent_interfaces(base_url: &str, bindings: &[Binding]) -> Vec<AgentInterface> {
+    bindings
+        .iter()
+        .map(|binding| AgentInterface {
+            url: base_url.to_string(),
+            protocol_binding: binding.to_string(),
+            ..Default::default()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interfaces_follow_bindings() {
+        let interfaces = agent_interfaces(
+            "https://agent.example.com",
+            &[Binding::JsonRpc, Binding::Rest, Binding::Grpc],
+        );
+        let bindings: Vec<_> = interfaces
+            .iter()
+            .map(|i| i.protocol_binding.as_str())
+            .collect();
+        assert_eq!(bindings, ["JSONRPC", "HTTP+JSON", "GRPC"]);
+
+        let interfaces = agent_interfaces("https://agent.example.com", &[Binding::Grpc]);
+        assert_eq!(interfaces.len(), 1);
+        assert_eq!(interfaces[0].protocol_binding, "GRPC");
+    }
+}
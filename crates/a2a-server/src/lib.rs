@@ -1,10 +1,21 @@
 // crates/a2a-server/src/lib.rs
 //! A2A protocol server.
 
+pub mod agent_card;
 pub mod error;
+pub mod grpc;
 pub mod handler;
+pub mod interfaces;
+pub mod signing;
 pub mod store;
+mod subscribers;
 
+pub use agent_card::AgentCardEndpoint;
 pub use error::{Error, Result};
+pub use grpc::{GrpcBody, GrpcService};
 pub use handler::{AgentHandler, RequestContext};
-pub use store::{InMemoryTaskStore, TaskFilter, TaskStore};
+pub use interfaces::agent_interfaces;
+pub use signing::{CardSigner, SigningKey};
+pub use store::{
+    InMemoryPushConfigStore, InMemoryTaskStore, PushConfigStore, TaskFilter, TaskStore,
+};
@@ -1,5 +1,5 @@
 // crates/a2a-server/src/store.rs
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::future::Future;
 use std::sync::RwLock;
 
@@ -71,7 +71,11 @@ impl TaskStore for InMemoryTaskStore {
             .values()
             .filter(|task| {
                 if let Some(ref ctx) = filter.context_id
-                    && task.get("context_id").and_then(|v| v.as_str()) != Some(ctx)
+                    && task
+                        .get("contextId")
+                        .or_else(|| task.get("context_id"))
+                        .and_then(|v| v.as_str())
+                        != Some(ctx)
                 {
                     return false;
                 }
@@ -98,6 +102,96 @@ impl TaskStore for InMemoryTaskStore {
     }
 }
 
+/// Push notification config storage trait.
+///
+/// Configs are `TaskPushNotificationConfig` JSON values, keyed by task ID and
+/// config ID.
+pub trait PushConfigStore: Send + Sync {
+    /// Error type for this store.
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Create or replace a config.
+    fn set(
+        &self,
+        task_id: &str,
+        config_id: &str,
+        config: &serde_json::Value,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+
+    /// Get a config by ID.
+    fn get(
+        &self,
+        task_id: &str,
+        config_id: &str,
+    ) -> impl Future<Output = Result<Option<serde_json::Value>, Self::Error>> + Send;
+
+    /// List the configs of a task, ordered by config ID.
+    fn list(
+        &self,
+        task_id: &str,
+    ) -> impl Future<Output = Result<Vec<serde_json::Value>, Self::Error>> + Send;
+
+    /// Delete a config.
+    fn delete(
+        &self,
+        task_id: &str,
+        config_id: &str,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// In-memory push notification config store.
+#[derive(Debug, Default)]
+pub struct InMemoryPushConfigStore {
+    configs: RwLock<BTreeMap<(String, String), serde_json::Value>>,
+}
+
+impl InMemoryPushConfigStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl PushConfigStore for InMemoryPushConfigStore {
+    type Error = std::convert::Infallible;
+
+    async fn set(
+        &self,
+        task_id: &str,
+        config_id: &str,
+        config: &serde_json::Value,
+    ) -> Result<(), Self::Error> {
+        let mut configs = self.configs.write().unwrap();
+        configs.insert((task_id.to_string(), config_id.to_string()), config.clone());
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        task_id: &str,
+        config_id: &str,
+    ) -> Result<Option<serde_json::Value>, Self::Error> {
+        let configs = self.configs.read().unwrap();
+        Ok(configs
+            .get(&(task_id.to_string(), config_id.to_string()))
+            .cloned())
+    }
+
+    async fn list(&self, task_id: &str) -> Result<Vec<serde_json::Value>, Self::Error> {
+        let configs = self.configs.read().unwrap();
+        Ok(configs
+            .iter()
+            .filter(|((task, _), _)| task == task_id)
+            .map(|(_, config)| config.clone())
+            .collect())
+    }
+
+    async fn delete(&self, task_id: &str, config_id: &str) -> Result<(), Self::Error> {
+        let mut configs = self.configs.write().unwrap();
+        configs.remove(&(task_id.to_string(), config_id.to_string()));
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -130,4 +224,42 @@ mod tests {
         let deleted = store.get("task-1").await.unwrap();
         assert!(deleted.is_none());
     }
+
+    #[tokio::test]
+    async fn test_list_filters_by_context() {
+        let store = InMemoryTaskStore::new();
+        store
+            .save(&json!({"id": "task-1", "contextId": "ctx-1"}))
+            .await
+            .unwrap();
+        store
+            .save(&json!({"id": "task-2", "contextId": "ctx-2"}))
+            .await
+            .unwrap();
+
+        let filter = TaskFilter {
+            context_id: Some("ctx-2".to_string()),
+            ..Default::default()
+        };
+        let tasks = store.list(filter).await.unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0]["id"], "task-2");
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_push_config_store() {
+        let store = InMemoryPushConfigStore::new();
+        let config = json!({"name": "tasks/task-1/pushNotificationConfigs/c2"});
+
+        store.set("task-1", "c2", &config).await.unwrap();
+        store.set("task-1", "c1", &json!({})).await.unwrap();
+        store.set("task-2", "c1", &json!({})).await.unwrap();
+
+        assert_eq!(store.get("task-1", "c2").await.unwrap(), Some(config));
+        assert_eq!(store.list("task-1").await.unwrap().len(), 2);
+
+        store.delete("task-1", "c2").await.unwrap();
+        assert!(store.get("task-1", "c2").await.unwrap().is_none());
+        assert_eq!(store.list("task-1").await.unwrap(), [json!({})]);
+    }
 }
@@ -0,0 +1,179 @@
+// crates/a2a-server/src/subscribers.rs
+//! Fan-out of task events to `SubscribeToTask` streams.
+//!
+//! Events are relayed as they are produced, so a subscriber only sees the
+//! events of requests served by the same service after it subscribed. A
+//! subscription ends once its task reaches a terminal state.
+
+use a2a_types::{StreamResponse, TaskState, stream_response::Payload};
+use futures_core::Stream;
+use std::collections::{HashMap, VecDeque};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// Events queued for one subscriber.
+#[derive(Default)]
+struct Queue {
+    events: VecDeque<StreamResponse>,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+impl Queue {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Subscribers of every task, by task ID.
+#[derive(Default)]
+pub(crate) struct Subscribers {
+    tasks: Mutex<HashMap<String, Vec<Arc<Mutex<Queue>>>>>,
+}
+
+impl Subscribers {
+    /// Subscribe to the events of a task.
+    pub(crate) fn subscribe(&self, task_id: &str) -> Subscription {
+        let queue = Arc::new(Mutex::new(Queue::default()));
+        self.tasks
+            .lock()
+            .unwrap()
+            .entry(task_id.to_string())
+            .or_default()
+            .push(queue.clone());
+        Subscription { queue }
+    }
+
+    /// Relay an event to the subscribers of its task, ending their
+    /// subscriptions if the task reached a terminal state.
+    pub(crate) fn publish(&self, event: &StreamResponse) {
+        let Some((task_id, state)) = task_of(event) else {
+            return;
+        };
+        let mut tasks = self.tasks.lock().unwrap();
+        let Some(queues) = tasks.get_mut(task_id) else {
+            return;
+        };
+        let terminal = state.is_some_and(is_terminal);
+        // Dropped subscriptions hold no other reference to their queue
+        queues.retain(|queue| Arc::strong_count(queue) > 1);
+        for queue in queues.iter() {
+            let mut queue = queue.lock().unwrap();
+            queue.events.push_back(event.clone());
+            queue.closed |= terminal;
+            queue.wake();
+        }
+        if terminal || queues.is_empty() {
+            tasks.remove(task_id);
+        }
+    }
+}
+
+/// Stream of the events of a subscribed task.
+pub(crate) struct Subscription {
+    queue: Arc<Mutex<Queue>>,
+}
+
+impl Stream for Subscription {
+    type Item = StreamResponse;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<StreamResponse>> {
+        let mut queue = self.queue.lock().unwrap();
+        if let Some(event) = queue.events.pop_front() {
+            return Poll::Ready(Some(event));
+        }
+        if queue.closed {
+            return Poll::Ready(None);
+        }
+        queue.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+/// The task an event belongs to, with the state it reports if any.
+fn task_of(event: &StreamResponse) -> Option<(&str, Option<TaskState>)> {
+    let state = |status: &Option<a2a_types::TaskStatus>| {
+        status
+            .as_ref()
+            .and_then(|s| TaskState::try_from(s.state).ok())
+    };
+    let (task_id, state) = match event.payload.as_ref()? {
+        Payload::Task(task) => (task.id.as_str(), state(&task.status)),
+        Payload::StatusUpdate(update) => (update.task_id.as_str(), state(&update.status)),
+        Payload::ArtifactUpdate(update) => (update.task_id.as_str(), None),
+        Payload::Msg(message) => (message.task_id.as_str(), None),
+    };
+    (!task_id.is_empty()).then_some((task_id, state))
+}
+
+/// Whether a task in `state` will not change anymore.
+pub(crate) fn is_terminal(state: TaskState) -> bool {
+    matches!(
+        state,
+        TaskState::Completed | TaskState::Failed | TaskState::Cancelled | TaskState::Rejected
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use a2a_types::{Task, TaskStatus, TaskStatusUpdateEvent};
+
+    fn status_update(task_id: &str, state: TaskState) -> StreamResponse {
+        StreamResponse {
+            payload: Some(Payload::StatusUpdate(TaskStatusUpdateEvent {
+                task_id: task_id.to_string(),
+                status: Some(TaskStatus {
+                    state: state as i32,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })),
+        }
+    }
+
+    fn poll(subscription: &mut Subscription) -> Poll<Option<StreamResponse>> {
+        let mut cx = Context::from_waker(Waker::noop());
+        Pin::new(subscription).poll_next(&mut cx)
+    }
+
+    #[test]
+    fn test_events_relayed_until_terminal() {
+        let subscribers = Subscribers::default();
+        let mut subscription = subscribers.subscribe("task-1");
+        assert!(poll(&mut subscription).is_pending());
+
+        let working = status_update("task-1", TaskState::Working);
+        subscribers.publish(&working);
+        subscribers.publish(&status_update("task-2", TaskState::Working));
+        assert_eq!(poll(&mut subscription), Poll::Ready(Some(working)));
+        assert!(poll(&mut subscription).is_pending());
+
+        let completed = StreamResponse {
+            payload: Some(Payload::Task(Task {
+                id: "task-1".to_string(),
+                status: Some(TaskStatus {
+                    state: TaskState::Completed as i32,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })),
+        };
+        subscribers.publish(&completed);
+        assert_eq!(poll(&mut subscription), Poll::Ready(Some(completed)));
+        assert_eq!(poll(&mut subscription), Poll::Ready(None));
+        assert!(subscribers.tasks.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_dropped_subscriptions_are_released() {
+        let subscribers = Subscribers::default();
+        drop(subscribers.subscribe("task-1"));
+
+        subscribers.publish(&status_update("task-1", TaskState::Working));
+        assert!(subscribers.tasks.lock().unwrap().is_empty());
+    }
+}
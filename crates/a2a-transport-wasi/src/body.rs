@@ -0,0 +1,235 @@
+// crates/a2a-transport-wasi/src/body.rs
+//! Streaming reader for incoming request and response bodies.
+
+use crate::client::{HeaderPairs, from_error_code, to_header_pairs};
+use crate::error::WasiError;
+use crate::reactor;
+use a2a_transport::BodyFrame;
+use bytes::Bytes;
+use futures_core::Stream;
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use wasi::http::types::{FutureTrailers, IncomingBody};
+use wasi::io::poll::Pollable;
+use wasi::io::streams::InputStream;
+
+/// Default maximum size of a buffered body (1 MiB).
+///
+/// Applies to request bodies read by
+/// [`from_incoming_request`](crate::server::from_incoming_request) and to
+/// response bodies read by [`WasiHttpClient`](crate::WasiHttpClient).
+pub const DEFAULT_MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// Streaming reader for a WASI `incoming-body`, of a request or a response.
+///
+/// Yields the body in chunks as the host receives it. With a maximum size,
+/// the stream fails with [`WasiError::BodyTooLarge`] as soon as the body
+/// grows past it, without reading the rest.
+///
+/// Once the body has been read to the end, its trailers are available
+/// through [`trailers`](Self::trailers); [`into_frames`](Self::into_frames)
+/// streams both.
+///
+/// While no data is available, the stream waits on the body through the
+/// [reactor](crate::reactor), so it must be polled under
+/// [`block_on`](crate::reactor::block_on).
+pub struct IncomingBodyStream {
+    // Child resources are dropped before their parents: the pollables before
+    // the stream and the trailers, the stream before the body
+    readable: Option<Pollable>,
+    trailers_ready: Option<Pollable>,
+    registration: Option<usize>,
+    stream: Option<InputStream>,
+    body: Option<IncomingBody>,
+    trailers: Option<FutureTrailers>,
+    max_size: Option<usize>,
+    received: usize,
+}
+
+impl IncomingBodyStream {
+    /// Stream `body`, with no size limit.
+    ///
+    /// Fails with [`WasiError::BodyAlreadyConsumed`] if the body's stream
+    /// was already taken.
+    pub fn new(body: IncomingBody) -> Result<Self, WasiError> {
+        let stream = body.stream().map_err(|()| WasiError::BodyAlreadyConsumed)?;
+        Ok(Self {
+            readable: None,
+            trailers_ready: None,
+            registration: None,
+            stream: Some(stream),
+            body: Some(body),
+            trailers: None,
+            max_size: None,
+            received: 0,
+        })
+    }
+
+    /// Fail once the body exceeds `max_size` bytes.
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Read the rest of the body into memory.
+    ///
+    /// The trailers remain available through [`trailers`](Self::trailers).
+    pub async fn read_to_end(&mut self) -> Result<Vec<u8>, WasiError> {
+        let mut data = Vec::new();
+        while let Some(chunk) = poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await {
+            data.extend_from_slice(&chunk?);
+        }
+        Ok(data)
+    }
+
+    /// Wait for the trailers once the body has been read to the end.
+    ///
+    /// Returns no trailers if the body has none, or if it was not read to
+    /// the end.
+    pub async fn trailers(&mut self) -> Result<HeaderPairs, WasiError> {
+        poll_fn(|cx| self.poll_trailers(cx)).await
+    }
+
+    /// Stream the body's data followed by its trailers, if it has any.
+    pub fn into_frames(self) -> IncomingBodyFrames {
+        IncomingBodyFrames {
+            body: self,
+            data_done: false,
+            done: false,
+        }
+    }
+
+    fn poll_trailers(&mut self, cx: &mut Context<'_>) -> Poll<Result<HeaderPairs, WasiError>> {
+        loop {
+            let Some(trailers) = &self.trailers else {
+                return Poll::Ready(Ok(Vec::new()));
+            };
+            let result = match trailers.get() {
+                Some(Ok(Ok(Some(fields)))) => to_header_pairs(&fields),
+                Some(Ok(Ok(None))) => Ok(Vec::new()),
+                Some(Ok(Err(code))) => Err(from_error_code(code)),
+                Some(Err(())) => Err(WasiError::InternalError(
+                    "trailers already retrieved".into(),
+                )),
+                None => {
+                    let ready = self
+                        .trailers_ready
+                        .get_or_insert_with(|| trailers.subscribe());
+                    if ready.ready() {
+                        continue;
+                    }
+                    reactor::register(&mut self.registration, ready, cx.waker());
+                    return Poll::Pending;
+                }
+            };
+            reactor::unregister(&mut self.registration);
+            self.trailers_ready = None;
+            self.trailers = None;
+            return Poll::Ready(result);
+        }
+    }
+
+    /// Release the body once it has been read, keeping its trailers.
+    fn finish(&mut self) {
+        reactor::unregister(&mut self.registration);
+        self.readable = None;
+        self.stream = None;
+        if let Some(body) = self.body.take() {
+            self.trailers = Some(IncomingBody::finish(body));
+        }
+    }
+}
+
+impl Stream for IncomingBodyStream {
+    type Item = Result<Bytes, WasiError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            let Some(stream) = &this.stream else {
+                return Poll::Ready(None);
+            };
+
+            match stream.read(4096) {
+                Ok(chunk) if chunk.is_empty() => {
+                    // No data available yet: wait until the body is readable
+                    let readable = this.readable.get_or_insert_with(|| stream.subscribe());
+                    if readable.ready() {
+                        continue;
+                    }
+                    reactor::register(&mut this.registration, readable, cx.waker());
+                    return Poll::Pending;
+                }
+                Ok(chunk) => {
+                    reactor::unregister(&mut this.registration);
+                    this.received += chunk.len();
+                    if let Some(limit) = this.max_size.filter(|&limit| this.received > limit) {
+                        // Stop reading: the rest of the body is never buffered
+                        this.finish();
+                        return Poll::Ready(Some(Err(WasiError::BodyTooLarge { limit })));
+                    }
+                    return Poll::Ready(Some(Ok(Bytes::from(chunk))));
+                }
+                Err(wasi::io::streams::StreamError::Closed) => {
+                    this.finish();
+                    return Poll::Ready(None);
+                }
+                Err(e) => {
+                    this.finish();
+                    return Poll::Ready(Some(Err(WasiError::StreamError(format!("{e:?}")))));
+                }
+            }
+        }
+    }
+}
+
+impl Drop for IncomingBodyStream {
+    fn drop(&mut self) {
+        reactor::unregister(&mut self.registration);
+    }
+}
+
+// SAFETY: IncomingBodyStream is single-threaded (WASM is single-threaded)
+unsafe impl Send for IncomingBodyStream {}
+
+/// Stream of a body's data followed by its trailers, as [`BodyFrame`]s.
+///
+/// Created by [`IncomingBodyStream::into_frames`]. No trailers frame is
+/// yielded if the body has none.
+pub struct IncomingBodyFrames {
+    body: IncomingBodyStream,
+    data_done: bool,
+    done: bool,
+}
+
+impl Stream for IncomingBodyFrames {
+    type Item = Result<BodyFrame, WasiError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        if this.done {
+            return Poll::Ready(None);
+        }
+        if !this.data_done {
+            match Pin::new(&mut this.body).poll_next(cx) {
+                Poll::Ready(Some(Ok(chunk))) => {
+                    return Poll::Ready(Some(Ok(BodyFrame::Data(chunk))));
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Poll::Ready(None) => this.data_done = true,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        let result = std::task::ready!(this.body.poll_trailers(cx));
+        this.done = true;
+        match result {
+            Ok(trailers) if trailers.is_empty() => Poll::Ready(None),
+            Ok(trailers) => Poll::Ready(Some(Ok(BodyFrame::Trailers(trailers)))),
+            Err(e) => Poll::Ready(Some(Err(e))),
+        }
+    }
+}
@@ -1,26 +1,42 @@
 // crates/a2a-transport-wasi/src/client.rs
 //! WASI HTTP client implementation.
 
+use crate::body::{DEFAULT_MAX_BODY_SIZE, IncomingBodyStream};
 use crate::error::WasiError;
 use crate::poll::PollableExt;
-use a2a_transport::{HttpClient, HttpRequest, HttpResponse, Method};
+use a2a_transport::{
+    BodyFrame, HttpClient, HttpRequest, HttpResponse, Method, StreamingHttpResponse,
+};
 use bytes::Bytes;
 use futures_core::Stream;
 use std::future::Future;
-use std::pin::Pin;
-use std::task::{Context, Poll};
 use wasi::http::outgoing_handler;
 use wasi::http::types::{
     ErrorCode, Fields, IncomingBody, IncomingResponse, OutgoingBody, OutgoingRequest, Scheme,
 };
 
 /// WASI HTTP client using wasi:http/outgoing-handler.
-pub struct WasiHttpClient;
+///
+/// Buffered responses ([`HttpClient::request`]) fail with
+/// [`WasiError::BodyTooLarge`] if their body exceeds the maximum body size,
+/// [`DEFAULT_MAX_BODY_SIZE`] unless configured otherwise. Streamed responses
+/// aren't limited, since they are never held in memory at once.
+pub struct WasiHttpClient {
+    max_body_size: usize,
+}
 
 impl WasiHttpClient {
     /// Create a new WASI HTTP client.
     pub fn new() -> Self {
-        Self
+        Self {
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+        }
+    }
+
+    /// Set the maximum size of buffered response bodies, in bytes.
+    pub fn with_max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = max_body_size;
+        self
     }
 }
 
@@ -41,7 +57,7 @@ fn to_wasi_method(method: &Method) -> wasi::http::types::Method {
 }
 
 /// Convert wasi:http ErrorCode to WasiError.
-fn from_error_code(code: ErrorCode) -> WasiError {
+pub(crate) fn from_error_code(code: ErrorCode) -> WasiError {
     match code {
         ErrorCode::DnsTimeout | ErrorCode::DnsError(_) => WasiError::DnsError(format!("{code:?}")),
         ErrorCode::ConnectionTimeout => WasiError::ConnectionTimeout,
@@ -139,53 +155,52 @@ fn write_body(body: &OutgoingBody, data: &[u8]) -> Result<(), WasiError> {
     Ok(())
 }
 
-/// Read all data from an IncomingBody.
-fn read_body(body: IncomingBody) -> Result<Vec<u8>, WasiError> {
-    let stream = body
-        .stream()
-        .map_err(|()| WasiError::HttpResponseBodyError("failed to get read stream".into()))?;
-
-    let mut data = Vec::new();
-    loop {
-        // Use blocking_read to wait for data. Non-blocking read() returns empty
-        // when no data is immediately available, which we'd wrongly interpret as EOF.
-        match stream.blocking_read(4096) {
-            Ok(chunk) => {
-                if chunk.is_empty() {
-                    // blocking_read returns empty only when stream is truly done
-                    break;
-                }
-                data.extend_from_slice(&chunk);
-            }
-            Err(wasi::io::streams::StreamError::Closed) => break,
-            Err(e) => {
-                return Err(WasiError::HttpResponseBodyError(format!("{e:?}")));
-            }
-        }
-    }
-    drop(stream);
-    IncomingBody::finish(body);
-    Ok(data)
+/// Header name/value pairs.
+pub(crate) type HeaderPairs = Vec<(String, String)>;
+
+/// Read all data from an IncomingBody, up to `max_size` bytes, followed by
+/// its trailers.
+async fn read_body(
+    body: IncomingBody,
+    max_size: usize,
+) -> Result<(Vec<u8>, HeaderPairs), WasiError> {
+    let mut body = IncomingBodyStream::new(body)?.with_max_size(max_size);
+    let data = body.read_to_end().await?;
+
+    // Trailers carry the status of some protocols (e.g. gRPC's `grpc-status`).
+    let trailers = body.trailers().await?;
+    Ok((data, trailers))
 }
 
-/// Convert IncomingResponse to HttpResponse.
-fn to_http_response(response: IncomingResponse) -> Result<HttpResponse, WasiError> {
+/// Convert WASI fields to header name/value pairs.
+pub(crate) fn to_header_pairs(fields: &Fields) -> Result<HeaderPairs, WasiError> {
+    fields
+        .entries()
+        .into_iter()
+        .map(|(name, value)| {
+            let value_str = String::from_utf8(value)
+                .map_err(|_| WasiError::HttpProtocolError("invalid header value".into()))?;
+            Ok((name, value_str))
+        })
+        .collect()
+}
+
+/// Convert IncomingResponse to HttpResponse, reading up to `max_body_size`
+/// bytes of body.
+async fn to_http_response(
+    response: IncomingResponse,
+    max_body_size: usize,
+) -> Result<HttpResponse, WasiError> {
     let status = response.status();
     let wasi_headers = response.headers();
-    let entries = wasi_headers.entries();
-
-    let mut headers = Vec::with_capacity(entries.len());
-    for (name, value) in entries {
-        let value_str = String::from_utf8(value)
-            .map_err(|_| WasiError::HttpProtocolError("invalid header value".into()))?;
-        headers.push((name, value_str));
-    }
+    let mut headers = to_header_pairs(&wasi_headers)?;
     drop(wasi_headers);
 
     let body = response
         .consume()
         .map_err(|()| WasiError::BodyAlreadyConsumed)?;
-    let body_data = read_body(body)?;
+    let (body_data, trailers) = read_body(body, max_body_size).await?;
+    headers.extend(trailers);
 
     Ok(HttpResponse {
         status,
@@ -202,6 +217,7 @@ impl HttpClient for WasiHttpClient {
         &self,
         request: HttpRequest,
     ) -> impl Future<Output = Result<HttpResponse, Self::Error>> + Send {
+        let max_body_size = self.max_body_size;
         async move {
             // Build and send request
             let outgoing = build_outgoing_request(&request)?;
@@ -229,7 +245,7 @@ impl HttpClient for WasiHttpClient {
                 .map_err(|()| WasiError::InternalError("response get failed".into()))?
                 .map_err(from_error_code)?;
 
-            to_http_response(response)
+            to_http_response(response, max_body_size).await
         }
     }
 
@@ -237,7 +253,10 @@ impl HttpClient for WasiHttpClient {
         &self,
         request: HttpRequest,
     ) -> impl Future<
-        Output = Result<impl Stream<Item = Result<Bytes, Self::Error>> + Send, Self::Error>,
+        Output = Result<
+            StreamingHttpResponse<impl Stream<Item = Result<BodyFrame, Self::Error>> + Send>,
+            Self::Error,
+        >,
     > + Send {
         async move {
             // Build and send request
@@ -262,61 +281,28 @@ impl HttpClient for WasiHttpClient {
                 .map_err(|()| WasiError::InternalError("response get failed".into()))?
                 .map_err(from_error_code)?;
 
+            let status = response.status();
+            let wasi_headers = response.headers();
+            let headers = to_header_pairs(&wasi_headers)?;
+            drop(wasi_headers);
+
             let incoming_body = response
                 .consume()
                 .map_err(|()| WasiError::BodyAlreadyConsumed)?;
 
-            Ok(WasiBodyStream::new(incoming_body))
+            Ok(StreamingHttpResponse {
+                status,
+                headers,
+                body: IncomingBodyStream::new(incoming_body)?.into_frames(),
+            })
         }
     }
-}
 
-/// Streaming body reader for WASI HTTP responses.
-pub struct WasiBodyStream {
-    body: Option<IncomingBody>,
-    stream: Option<wasi::io::streams::InputStream>,
-}
-
-impl WasiBodyStream {
-    fn new(body: IncomingBody) -> Self {
-        let stream = body.stream().ok();
-        Self {
-            body: Some(body),
-            stream,
-        }
-    }
-}
-
-impl Stream for WasiBodyStream {
-    type Item = Result<Bytes, WasiError>;
-
-    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        let stream = match &self.stream {
-            Some(s) => s,
-            None => return Poll::Ready(None),
-        };
-
-        match stream.read(4096) {
-            Ok(chunk) if chunk.is_empty() => {
-                // Clean up
-                self.stream = None;
-                if let Some(body) = self.body.take() {
-                    IncomingBody::finish(body);
-                }
-                Poll::Ready(None)
-            }
-            Ok(chunk) => Poll::Ready(Some(Ok(Bytes::from(chunk)))),
-            Err(wasi::io::streams::StreamError::Closed) => {
-                self.stream = None;
-                if let Some(body) = self.body.take() {
-                    IncomingBody::finish(body);
-                }
-                Poll::Ready(None)
-            }
-            Err(e) => Poll::Ready(Some(Err(WasiError::StreamError(format!("{e:?}"))))),
-        }
+    fn is_connect_error(error: &WasiError) -> bool {
+        error.is_connect()
     }
 }
 
-// SAFETY: WasiBodyStream is single-threaded (WASM is single-threaded)
-unsafe impl Send for WasiBodyStream {}
+/// Streaming body reader for WASI HTTP responses.
+#[deprecated(note = "renamed to `IncomingBodyStream`")]
+pub type WasiBodyStream = IncomingBodyStream;
@@ -58,9 +58,30 @@ pub enum WasiError {
     #[error("Invalid request: {0}")]
     InvalidRequest(String),
 
+    #[error("Body exceeds the maximum size of {limit} bytes")]
+    BodyTooLarge { limit: usize },
+
     #[error("Body already consumed")]
     BodyAlreadyConsumed,
 
     #[error("Stream error: {0}")]
     StreamError(String),
 }
+
+impl WasiError {
+    /// Whether the request failed before it was sent: no connection could
+    /// be established, or the host refused to send it.
+    pub fn is_connect(&self) -> bool {
+        matches!(
+            self,
+            Self::DnsError(_)
+                | Self::ConnectionTimeout
+                | Self::ConnectionRefused
+                | Self::TlsProtocolError(_)
+                | Self::TlsCertificateError(_)
+                | Self::TlsAlertReceived { .. }
+                | Self::HttpRequestDenied(_)
+                | Self::InvalidRequest(_)
+        )
+    }
+}
@@ -48,18 +48,35 @@
 //! export_incoming_handler!(MyHandler);
 //! ```
 //!
+//! To stream the response body, for server-sent events say, write the
+//! handler as an `async fn` returning a [`StreamingResponse`] and export it
+//! with `export_incoming_handler!(async MyHandler)`. Chunks are written as
+//! the body stream yields them, waiting whenever the host applies
+//! backpressure.
+//!
 //! # Async Model
 //!
 //! This crate uses WASIP2's poll-based async model. The `WasiPollFuture`
-//! type bridges WASI pollables to Rust's `Future` trait, allowing
-//! integration with async runtimes that support single-threaded execution.
+//! type bridges WASI pollables to Rust's `Future` trait by registering them
+//! with a small reactor. Run futures with [`block_on`], which polls every
+//! pending pollable at once, and start concurrent tasks with [`spawn`], so
+//! several requests can wait on the host together.
 
+pub mod body;
 pub mod client;
 pub mod error;
 pub mod poll;
+pub mod reactor;
 pub mod server;
 
-pub use client::{WasiBodyStream, WasiHttpClient};
+pub use body::{DEFAULT_MAX_BODY_SIZE, IncomingBodyFrames, IncomingBodyStream};
+#[allow(deprecated)]
+pub use client::WasiBodyStream;
+pub use client::WasiHttpClient;
 pub use error::WasiError;
 pub use poll::{PollableExt, WasiPollFuture};
-pub use server::{from_incoming_request, send_response};
+pub use reactor::{JoinHandle, block_on, spawn};
+pub use server::{
+    StreamingResponse, from_incoming_request, from_incoming_request_with_limit, send_response,
+    send_streaming_response,
+};
@@ -1,36 +1,51 @@
 // crates/a2a-transport-wasi/src/poll.rs
 //! Poll-to-async bridge for WASI pollables.
 
+use crate::reactor;
 use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
-use wasi::io::poll::{Pollable, poll};
+use wasi::io::poll::Pollable;
 
 /// A future that wraps a WASI `Pollable` and yields when it becomes ready.
 ///
 /// This allows WASI poll-based async to integrate with Rust's `Future` trait.
+/// While the pollable isn't ready, it is registered with the
+/// [reactor](crate::reactor) along with the task's waker, so the future must
+/// run under [`block_on`](crate::reactor::block_on).
 pub struct WasiPollFuture<'a> {
     pollable: &'a Pollable,
+    registration: Option<usize>,
 }
 
 impl<'a> WasiPollFuture<'a> {
     /// Create a new poll future from a WASI pollable.
     pub fn new(pollable: &'a Pollable) -> Self {
-        Self { pollable }
+        Self {
+            pollable,
+            registration: None,
+        }
     }
 }
 
 impl Future for WasiPollFuture<'_> {
     type Output = ();
 
-    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
-        // Check if already ready without blocking
-        if self.pollable.ready() {
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        if this.pollable.ready() {
+            reactor::unregister(&mut this.registration);
             return Poll::Ready(());
         }
-        // Block on the pollable - in WASM, this yields to the host
-        poll(&[self.pollable]);
-        Poll::Ready(())
+        // The reactor wakes us once the host reports the pollable ready
+        reactor::register(&mut this.registration, this.pollable, cx.waker());
+        Poll::Pending
+    }
+}
+
+impl Drop for WasiPollFuture<'_> {
+    fn drop(&mut self) {
+        reactor::unregister(&mut self.registration);
     }
 }
 
@@ -0,0 +1,376 @@
+// crates/a2a-transport-wasi/src/reactor.rs
+//! Single-threaded executor and reactor for WASI pollables.
+//!
+//! Components can only wait for I/O through `wasi:io/poll`. Futures waiting
+//! on a pollable ([`WasiPollFuture`](crate::poll::WasiPollFuture),
+//! [`WasiBodyStream`](crate::client::WasiBodyStream)) register it here with
+//! their waker and return `Pending`. When no task can make progress,
+//! [`block_on`] passes every registered pollable to a single `poll` call and
+//! wakes the tasks whose pollables are ready, so tasks started with [`spawn`]
+//! (several outgoing requests, say) wait on the host together instead of one
+//! after the other.
+//!
+//! ```ignore
+//! use a2a_transport::HttpClient;
+//! use a2a_transport_wasi::{WasiHttpClient, block_on, spawn};
+//!
+//! let responses = block_on(async {
+//!     let handles: Vec<_> = requests
+//!         .into_iter()
+//!         .map(|request| spawn(async move { WasiHttpClient::new().request(request).await }))
+//!         .collect();
+//!     let mut responses = Vec::new();
+//!     for handle in handles {
+//!         responses.push(handle.await);
+//!     }
+//!     responses
+//! });
+//! ```
+//!
+//! Everything here is per thread: futures registered on a thread are woken
+//! by the `block_on` running on that thread.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::future::Future;
+use std::mem::ManuallyDrop;
+use std::pin::{Pin, pin};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use wasi::io::poll::{Pollable, poll};
+
+thread_local! {
+    static REACTOR: RefCell<Reactor> = RefCell::new(Reactor::default());
+    static EXECUTOR: RefCell<Executor> = RefCell::new(Executor::default());
+}
+
+/// Pollables that futures are waiting on.
+#[derive(Default)]
+struct Reactor {
+    next_key: usize,
+    /// Registration key to the pollable's handle and the waiting task's waker
+    waiting: BTreeMap<usize, (u32, Waker)>,
+}
+
+/// Wake the task behind `waker` once `pollable` is ready.
+///
+/// `key` identifies the registration: it is assigned on the first call and
+/// reused by later calls, which replace the waker. The caller must
+/// [`unregister`] before dropping `pollable`.
+pub(crate) fn register(key: &mut Option<usize>, pollable: &Pollable, waker: &Waker) {
+    REACTOR.with(|reactor| {
+        let mut reactor = reactor.borrow_mut();
+        let key = *key.get_or_insert_with(|| {
+            reactor.next_key += 1;
+            reactor.next_key
+        });
+        reactor
+            .waiting
+            .insert(key, (pollable.handle(), waker.clone()));
+    });
+}
+
+/// Remove a registration made by [`register`], if any.
+pub(crate) fn unregister(key: &mut Option<usize>) {
+    if let Some(key) = key.take() {
+        REACTOR.with(|reactor| reactor.borrow_mut().waiting.remove(&key));
+    }
+}
+
+/// Block until at least one registered pollable is ready, and wake the tasks
+/// waiting on the ready ones.
+///
+/// Returns `false` without blocking if nothing is registered.
+fn wait() -> bool {
+    let waiting: Vec<(usize, u32)> = REACTOR.with(|reactor| {
+        reactor
+            .borrow()
+            .waiting
+            .iter()
+            .map(|(&key, &(handle, _))| (key, handle))
+            .collect()
+    });
+    if waiting.is_empty() {
+        return false;
+    }
+
+    // The pollables are owned by the registered futures, which unregister
+    // before dropping them: borrow their handles without taking ownership.
+    let pollables: Vec<ManuallyDrop<Pollable>> = waiting
+        .iter()
+        .map(|&(_, handle)| ManuallyDrop::new(unsafe { Pollable::from_handle(handle) }))
+        .collect();
+    let refs: Vec<&Pollable> = pollables.iter().map(|pollable| &**pollable).collect();
+    let ready = poll(&refs);
+
+    let wakers: Vec<Waker> = REACTOR.with(|reactor| {
+        let mut reactor = reactor.borrow_mut();
+        ready
+            .iter()
+            .filter_map(|&index| reactor.waiting.remove(&waiting[index as usize].0))
+            .map(|(_, waker)| waker)
+            .collect()
+    });
+    for waker in wakers {
+        waker.wake();
+    }
+    true
+}
+
+/// Spawned tasks and the queue of tasks to poll.
+#[derive(Default)]
+struct Executor {
+    next_id: usize,
+    /// Spawned tasks, taken out while they are polled
+    tasks: HashMap<usize, Pin<Box<dyn Future<Output = ()>>>>,
+    /// Woken tasks, including `block_on` futures
+    ready: VecDeque<usize>,
+    /// IDs of the futures of running `block_on` calls, innermost last
+    blocking: Vec<usize>,
+}
+
+fn next_id() -> usize {
+    EXECUTOR.with(|executor| {
+        let mut executor = executor.borrow_mut();
+        executor.next_id += 1;
+        executor.next_id
+    })
+}
+
+fn schedule(id: usize) {
+    EXECUTOR.with(|executor| {
+        let mut executor = executor.borrow_mut();
+        if !executor.ready.contains(&id) {
+            executor.ready.push_back(id);
+        }
+    });
+}
+
+fn next_ready() -> Option<usize> {
+    EXECUTOR.with(|executor| executor.borrow_mut().ready.pop_front())
+}
+
+/// Waker scheduling a task by ID.
+struct TaskWaker(usize);
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        schedule(self.0);
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        schedule(self.0);
+    }
+}
+
+/// Poll spawned task `id`, if it is still running.
+fn run_task(id: usize) {
+    let Some(mut task) = EXECUTOR.with(|executor| executor.borrow_mut().tasks.remove(&id)) else {
+        return;
+    };
+    let waker = Waker::from(Arc::new(TaskWaker(id)));
+    if task
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker))
+        .is_pending()
+    {
+        EXECUTOR.with(|executor| executor.borrow_mut().tasks.insert(id, task));
+    }
+}
+
+/// Whether `id` is the future of an enclosing `block_on` call.
+fn is_blocking(id: usize) -> bool {
+    EXECUTOR.with(|executor| executor.borrow().blocking.contains(&id))
+}
+
+/// Run a future to completion, along with any spawned tasks.
+///
+/// Whenever neither the future nor a spawned task can make progress, this
+/// blocks the component in `wasi:io/poll` on every pollable they wait on.
+///
+/// # Panics
+///
+/// Panics if the future is pending with nothing left to wake it: no ready
+/// task and no registered pollable.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let id = next_id();
+    let waker = Waker::from(Arc::new(TaskWaker(id)));
+    let mut cx = Context::from_waker(&waker);
+    EXECUTOR.with(|executor| executor.borrow_mut().blocking.push(id));
+    // Wake-ups for the futures of enclosing `block_on` calls
+    let mut outer = Vec::new();
+
+    let output = 'run: loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            break output;
+        }
+        loop {
+            match next_ready() {
+                Some(ready) if ready == id => continue 'run,
+                Some(ready) if is_blocking(ready) => outer.push(ready),
+                Some(ready) => run_task(ready),
+                None => {
+                    if !wait() {
+                        panic!("block_on: future is pending with nothing to wait for");
+                    }
+                }
+            }
+        }
+    };
+
+    EXECUTOR.with(|executor| executor.borrow_mut().blocking.pop());
+    for id in outer {
+        schedule(id);
+    }
+    output
+}
+
+/// Start a task that runs concurrently with the current `block_on` future.
+///
+/// Tasks run while a `block_on` call is running on the same thread. Await
+/// the returned handle for the task's output; dropping the handle leaves the
+/// task running.
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + 'static,
+    F::Output: 'static,
+{
+    let state = Rc::new(RefCell::new(JoinState {
+        output: None,
+        waker: None,
+    }));
+    let task_state = state.clone();
+    let task = async move {
+        let output = future.await;
+        let waker = {
+            let mut state = task_state.borrow_mut();
+            state.output = Some(output);
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    };
+
+    let id = next_id();
+    EXECUTOR.with(|executor| {
+        let mut executor = executor.borrow_mut();
+        executor.tasks.insert(id, Box::pin(task));
+        executor.ready.push_back(id);
+    });
+    JoinHandle { state }
+}
+
+struct JoinState<T> {
+    output: Option<T>,
+    waker: Option<Waker>,
+}
+
+/// Handle to a task started with [`spawn`], resolving to its output.
+pub struct JoinHandle<T> {
+    state: Rc<RefCell<JoinState<T>>>,
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.state.borrow_mut();
+        match state.output.take() {
+            Some(output) => Poll::Ready(output),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// Returns `Pending` once, waking itself, then `Ready`.
+    struct YieldNow(bool);
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                return Poll::Ready(());
+            }
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn test_block_on() {
+        assert_eq!(block_on(async { 42 }), 42);
+        assert_eq!(
+            block_on(async {
+                YieldNow(false).await;
+                "done"
+            }),
+            "done"
+        );
+    }
+
+    #[test]
+    fn test_spawned_tasks_interleave() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let task = |name: &'static str| {
+            let log = log.clone();
+            spawn(async move {
+                for step in 0..2 {
+                    log.borrow_mut().push(format!("{name}{step}"));
+                    YieldNow(false).await;
+                }
+                name
+            })
+        };
+
+        let names = block_on(async {
+            let (a, b) = (task("a"), task("b"));
+            [a.await, b.await]
+        });
+
+        assert_eq!(names, ["a", "b"]);
+        assert_eq!(*log.borrow(), ["a0", "b0", "a1", "b1"]);
+    }
+
+    #[test]
+    fn test_detached_task_runs() {
+        let ran = Rc::new(Cell::new(false));
+        let flag = ran.clone();
+        drop(spawn(async move { flag.set(true) }));
+
+        block_on(YieldNow(false));
+
+        assert!(ran.get());
+    }
+
+    #[test]
+    fn test_nested_block_on() {
+        let output = block_on(async {
+            let handle = spawn(async { 1 });
+            let inner = block_on(async {
+                YieldNow(false).await;
+                2
+            });
+            handle.await + inner
+        });
+        assert_eq!(output, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "nothing to wait for")]
+    fn test_block_on_pending_forever() {
+        block_on(std::future::pending::<()>());
+    }
+}
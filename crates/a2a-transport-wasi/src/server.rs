@@ -1,12 +1,20 @@
 // crates/a2a-transport-wasi/src/server.rs
 //! WASI HTTP server implementation via incoming-handler export.
 
+use crate::body::{DEFAULT_MAX_BODY_SIZE, IncomingBodyStream};
 use crate::error::WasiError;
+use crate::poll::PollableExt;
+use crate::reactor::block_on;
 use a2a_transport::{HttpRequest, HttpResponse, Method};
 use bytes::Bytes;
+use futures_core::Stream;
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::task::{Context, Poll};
 use wasi::http::types::{
     Fields, IncomingBody, IncomingRequest, OutgoingBody, OutgoingResponse, ResponseOutparam,
 };
+use wasi::io::streams::OutputStream;
 
 /// Convert wasi:http Method to a2a-transport Method.
 fn from_wasi_method(method: wasi::http::types::Method) -> Method {
@@ -19,28 +27,29 @@ fn from_wasi_method(method: wasi::http::types::Method) -> Method {
     }
 }
 
-/// Read the incoming request body.
-fn read_incoming_body(body: IncomingBody) -> Result<Vec<u8>, WasiError> {
-    let stream = body
-        .stream()
-        .map_err(|()| WasiError::HttpRequestBodyError("failed to get stream".into()))?;
-
-    let mut data = Vec::new();
-    loop {
-        match stream.read(4096) {
-            Ok(chunk) if chunk.is_empty() => break,
-            Ok(chunk) => data.extend_from_slice(&chunk),
-            Err(wasi::io::streams::StreamError::Closed) => break,
-            Err(e) => return Err(WasiError::HttpRequestBodyError(format!("{e:?}"))),
-        }
-    }
-    drop(stream);
-    IncomingBody::finish(body);
-    Ok(data)
+/// Read the incoming request body, up to `max_size` bytes.
+fn read_incoming_body(body: IncomingBody, max_size: usize) -> Result<Vec<u8>, WasiError> {
+    let mut body = IncomingBodyStream::new(body)?.with_max_size(max_size);
+    block_on(body.read_to_end())
 }
 
 /// Convert IncomingRequest to HttpRequest.
+///
+/// Bodies larger than [`DEFAULT_MAX_BODY_SIZE`] are rejected with
+/// [`WasiError::BodyTooLarge`]; see [`from_incoming_request_with_limit`].
 pub fn from_incoming_request(request: IncomingRequest) -> Result<HttpRequest, WasiError> {
+    from_incoming_request_with_limit(request, DEFAULT_MAX_BODY_SIZE)
+}
+
+/// Convert IncomingRequest to HttpRequest, reading at most `max_body_size`
+/// bytes of body.
+///
+/// Requests whose body is larger, by their `content-length` or once read
+/// that far, fail with [`WasiError::BodyTooLarge`].
+pub fn from_incoming_request_with_limit(
+    request: IncomingRequest,
+    max_body_size: usize,
+) -> Result<HttpRequest, WasiError> {
     let method = from_wasi_method(request.method());
 
     // Build URL from scheme, authority, path
@@ -68,11 +77,22 @@ pub fn from_incoming_request(request: IncomingRequest) -> Result<HttpRequest, Wa
     }
     drop(wasi_headers);
 
+    // Reject declared oversized bodies without reading them
+    let content_length = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, value)| value.trim().parse::<usize>().ok());
+    if content_length.is_some_and(|length| length > max_body_size) {
+        return Err(WasiError::BodyTooLarge {
+            limit: max_body_size,
+        });
+    }
+
     // Read body
     let body = request
         .consume()
         .map_err(|()| WasiError::BodyAlreadyConsumed)?;
-    let body_data = read_incoming_body(body)?;
+    let body_data = read_incoming_body(body, max_body_size)?;
 
     // Convert body to Option<Bytes> (None if empty, Some otherwise)
     let body = if body_data.is_empty() {
@@ -89,26 +109,36 @@ pub fn from_incoming_request(request: IncomingRequest) -> Result<HttpRequest, Wa
     })
 }
 
-/// Send an HttpResponse via ResponseOutparam.
-pub fn send_response(response: HttpResponse, outparam: ResponseOutparam) -> Result<(), WasiError> {
+/// Build an OutgoingResponse with the given status and headers, returning it
+/// with its body handle.
+fn build_outgoing_response(
+    status: u16,
+    headers: &[(String, String)],
+) -> Result<(OutgoingResponse, OutgoingBody), WasiError> {
     // Build headers
-    let headers = Fields::new();
-    for (name, value) in &response.headers {
-        headers
+    let fields = Fields::new();
+    for (name, value) in headers {
+        fields
             .append(name, value.as_bytes())
             .map_err(|_| WasiError::InvalidRequest(format!("invalid header: {name}")))?;
     }
 
     // Create response with status
-    let outgoing = OutgoingResponse::new(headers);
+    let outgoing = OutgoingResponse::new(fields);
     outgoing
-        .set_status_code(response.status)
+        .set_status_code(status)
         .map_err(|()| WasiError::InvalidRequest("invalid status code".into()))?;
 
     // Get body handle
     let body = outgoing
         .body()
         .map_err(|()| WasiError::HttpResponseBodyError("failed to get body".into()))?;
+    Ok((outgoing, body))
+}
+
+/// Send an HttpResponse via ResponseOutparam.
+pub fn send_response(response: HttpResponse, outparam: ResponseOutparam) -> Result<(), WasiError> {
+    let (outgoing, body) = build_outgoing_response(response.status, &response.headers)?;
 
     // Send response headers
     ResponseOutparam::set(outparam, Ok(outgoing));
@@ -135,11 +165,143 @@ pub fn send_response(response: HttpResponse, outparam: ResponseOutparam) -> Resu
     Ok(())
 }
 
+/// Response whose body is written as a stream yields it.
+///
+/// Unlike [`HttpResponse`], the body doesn't have to be known up front, so
+/// handlers can serve server-sent events or other long-lived responses.
+pub struct StreamingResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers
+    pub headers: Vec<(String, String)>,
+    /// Body chunks, each written to the client as soon as it is yielded
+    pub body: Pin<Box<dyn Stream<Item = Bytes>>>,
+}
+
+impl StreamingResponse {
+    /// Create a streaming response from a stream of body chunks.
+    pub fn new(
+        status: u16,
+        headers: Vec<(String, String)>,
+        body: impl Stream<Item = Bytes> + 'static,
+    ) -> Self {
+        Self {
+            status,
+            headers,
+            body: Box::pin(body),
+        }
+    }
+}
+
+impl From<HttpResponse> for StreamingResponse {
+    fn from(response: HttpResponse) -> Self {
+        Self::new(response.status, response.headers, Once(Some(response.body)))
+    }
+}
+
+/// Stream yielding a single, non-empty body.
+struct Once(Option<Bytes>);
+
+impl Stream for Once {
+    type Item = Bytes;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Bytes>> {
+        Poll::Ready(self.0.take().filter(|body| !body.is_empty()))
+    }
+}
+
+/// Send a StreamingResponse via ResponseOutparam.
+///
+/// The headers are sent right away; each body chunk is then written and
+/// flushed as the stream yields it. While the host's buffer is full, this
+/// waits on the output stream's pollable instead of blocking, so other tasks
+/// keep running. Must run under [`block_on`](crate::reactor::block_on).
+pub async fn send_streaming_response(
+    response: StreamingResponse,
+    outparam: ResponseOutparam,
+) -> Result<(), WasiError> {
+    let StreamingResponse {
+        status,
+        headers,
+        mut body,
+    } = response;
+    let (outgoing, outgoing_body) = build_outgoing_response(status, &headers)?;
+
+    // Send response headers
+    ResponseOutparam::set(outparam, Ok(outgoing));
+
+    // Write body. On error the body is dropped unfinished, which tells the
+    // host the response is incomplete.
+    let stream = outgoing_body
+        .write()
+        .map_err(|()| WasiError::HttpResponseBodyError("failed to get write stream".into()))?;
+    while let Some(chunk) = poll_fn(|cx| body.as_mut().poll_next(cx)).await {
+        write_and_flush(&stream, &chunk).await?;
+    }
+    drop(stream);
+
+    // Finish body
+    OutgoingBody::finish(outgoing_body, None)
+        .map_err(|e| WasiError::HttpResponseBodyError(format!("{e:?}")))?;
+
+    Ok(())
+}
+
+/// Write `data` to `stream` and wait for it to be flushed, as far as the
+/// stream's write budget allows at a time.
+async fn write_and_flush(stream: &OutputStream, mut data: &[u8]) -> Result<(), WasiError> {
+    let to_error = |e| WasiError::HttpResponseBodyError(format!("{e:?}"));
+    let writable = stream.subscribe();
+    while !data.is_empty() {
+        let budget = stream.check_write().map_err(to_error)?;
+        if budget == 0 {
+            writable.wait().await;
+            continue;
+        }
+        let len = data
+            .len()
+            .min(usize::try_from(budget).unwrap_or(usize::MAX));
+        stream.write(&data[..len]).map_err(to_error)?;
+        data = &data[len..];
+    }
+
+    // check_write reports no budget until the flush completes
+    stream.flush().map_err(to_error)?;
+    while stream.check_write().map_err(to_error)? == 0 {
+        writable.wait().await;
+    }
+    Ok(())
+}
+
+/// Response sent when an incoming request can't be converted: 413 for
+/// oversized bodies, 500 otherwise.
+#[doc(hidden)]
+pub fn parse_error_response(error: WasiError) -> HttpResponse {
+    let status = match error {
+        WasiError::BodyTooLarge { .. } => 413,
+        _ => 500,
+    };
+    HttpResponse {
+        status,
+        headers: vec![("content-type".into(), "text/plain".into())],
+        body: Bytes::from(format!("Request parse error: {error}")),
+    }
+}
+
 /// Helper macro to implement the wasi:http/incoming-handler export.
 ///
 /// This macro generates the necessary export implementation for your WASM component.
 /// The handler function receives an `HttpRequest` and must return an `HttpResponse`.
 ///
+/// Request bodies larger than [`DEFAULT_MAX_BODY_SIZE`] are answered with 413
+/// without calling the handler; pass `max_body_size = <bytes>` after the
+/// handler to change the limit.
+///
+/// With `async`, the handler is an `async fn` returning a [`StreamingResponse`],
+/// run under [`block_on`](crate::reactor::block_on). Its body is written to the
+/// client chunk by chunk, so it can stream server-sent events; buffered
+/// responses convert with `HttpResponse::into`.
+///
 /// # Example
 ///
 /// ```ignore
@@ -158,11 +320,35 @@ pub fn send_response(response: HttpResponse, outparam: ResponseOutparam) -> Resu
 ///     }
 /// }
 ///
-/// export_incoming_handler!(MyHandler);
+/// export_incoming_handler!(MyHandler, max_body_size = 8 * 1024 * 1024);
+/// ```
+///
+/// Streaming events from an async handler:
+///
+/// ```ignore
+/// use a2a_transport::HttpRequest;
+/// use a2a_transport_wasi::export_incoming_handler;
+/// use a2a_transport_wasi::server::StreamingResponse;
+///
+/// struct MyStreamingHandler;
+///
+/// impl MyStreamingHandler {
+///     async fn handle(request: HttpRequest) -> StreamingResponse {
+///         // Any `Stream<Item = Bytes>`, such as SSE-encoded agent events
+///         let events = event_stream(request);
+///         StreamingResponse::new(
+///             200,
+///             vec![("content-type".into(), "text/event-stream".into())],
+///             events,
+///         )
+///     }
+/// }
+///
+/// export_incoming_handler!(async MyStreamingHandler);
 /// ```
 #[macro_export]
 macro_rules! export_incoming_handler {
-    ($handler:ty) => {
+    (async $handler:ident $(, max_body_size = $max_body_size:expr)?) => {
         // Export the handler using wit-bindgen generated code
         ::wasi::http::proxy::export!($handler);
 
@@ -171,16 +357,54 @@ macro_rules! export_incoming_handler {
                 request: ::wasi::http::types::IncomingRequest,
                 response_out: ::wasi::http::types::ResponseOutparam,
             ) {
+                #[allow(unused_mut, unused_assignments)]
+                let mut max_body_size = $crate::body::DEFAULT_MAX_BODY_SIZE;
+                $(max_body_size = $max_body_size;)?
+
                 // Convert incoming request to our type
-                let http_request = match $crate::server::from_incoming_request(request) {
+                let http_request = match $crate::server::from_incoming_request_with_limit(
+                    request,
+                    max_body_size,
+                ) {
+                    Ok(req) => req,
+                    Err(e) => {
+                        let error_response = $crate::server::parse_error_response(e);
+                        let _ = $crate::server::send_response(error_response, response_out);
+                        return;
+                    }
+                };
+
+                // Run the handler and stream its response
+                $crate::reactor::block_on(async move {
+                    let response = <$handler>::handle(http_request).await;
+                    // The response has been started; errors mean the client went away
+                    let _ = $crate::server::send_streaming_response(response, response_out).await;
+                });
+            }
+        }
+    };
+    ($handler:ident $(, max_body_size = $max_body_size:expr)?) => {
+        // Export the handler using wit-bindgen generated code
+        ::wasi::http::proxy::export!($handler);
+
+        impl ::wasi::exports::http::incoming_handler::Guest for $handler {
+            fn handle(
+                request: ::wasi::http::types::IncomingRequest,
+                response_out: ::wasi::http::types::ResponseOutparam,
+            ) {
+                #[allow(unused_mut, unused_assignments)]
+                let mut max_body_size = $crate::body::DEFAULT_MAX_BODY_SIZE;
+                $(max_body_size = $max_body_size;)?
+
+                // Convert incoming request to our type
+                let http_request = match $crate::server::from_incoming_request_with_limit(
+                    request,
+                    max_body_size,
+                ) {
                     Ok(req) => req,
                     Err(e) => {
                         // Send error response
-                        let error_response = ::a2a_transport::HttpResponse {
-                            status: 500,
-                            headers: vec![("content-type".into(), "text/plain".into())],
-                            body: ::bytes::Bytes::from(format!("Request parse error: {e}")),
-                        };
+                        let error_response = $crate::server::parse_error_response(e);
                         let _ = $crate::server::send_response(error_response, response_out);
                         return;
                     }
@@ -199,3 +423,51 @@ macro_rules! export_incoming_handler {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(mut body: Pin<Box<dyn Stream<Item = Bytes>>>) -> Vec<Bytes> {
+        block_on(async {
+            let mut chunks = Vec::new();
+            while let Some(chunk) = poll_fn(|cx| body.as_mut().poll_next(cx)).await {
+                chunks.push(chunk);
+            }
+            chunks
+        })
+    }
+
+    #[test]
+    fn test_streaming_response_from_http_response() {
+        let response = StreamingResponse::from(HttpResponse {
+            status: 201,
+            headers: vec![("content-type".into(), "text/plain".into())],
+            body: Bytes::from("created"),
+        });
+
+        assert_eq!(response.status, 201);
+        assert_eq!(response.headers[0].1, "text/plain");
+        assert_eq!(collect(response.body), [Bytes::from("created")]);
+    }
+
+    #[test]
+    fn test_streaming_response_from_empty_http_response() {
+        let response = StreamingResponse::from(HttpResponse {
+            status: 204,
+            headers: vec![],
+            body: Bytes::new(),
+        });
+
+        assert!(collect(response.body).is_empty());
+    }
+
+    #[test]
+    fn test_parse_error_response_status() {
+        let too_large = parse_error_response(WasiError::BodyTooLarge { limit: 1024 });
+        assert_eq!(too_large.status, 413);
+
+        let other = parse_error_response(WasiError::BodyAlreadyConsumed);
+        assert_eq!(other.status, 500);
+    }
+}
@@ -5,9 +5,8 @@ pub mod error;
 pub mod types;
 
 pub use error::{Error, Result};
-pub use types::{HttpRequest, HttpResponse, Method};
+pub use types::{BodyFrame, HttpRequest, HttpResponse, Method, StreamingHttpResponse};
 
-use bytes::Bytes;
 use futures_core::Stream;
 use std::future::Future;
 
@@ -23,15 +22,30 @@ pub trait HttpClient: Send + Sync {
     ) -> impl Future<Output = std::result::Result<HttpResponse, Self::Error>> + Send;
 
     /// Send a request and receive a streaming response.
+    ///
+    /// The body yields the response data as it arrives, then the trailers
+    /// if there are any.
     fn request_stream(
         &self,
         request: HttpRequest,
     ) -> impl Future<
         Output = std::result::Result<
-            impl Stream<Item = std::result::Result<Bytes, Self::Error>> + Send,
+            StreamingHttpResponse<
+                impl Stream<Item = std::result::Result<BodyFrame, Self::Error>> + Send,
+            >,
             Self::Error,
         >,
     > + Send;
+
+    /// Whether `error` happened before the request was sent, such as a
+    /// failed DNS lookup or a refused connection.
+    ///
+    /// Such requests never reached the server, so they are safe to resend.
+    /// Defaults to `false`.
+    fn is_connect_error(error: &Self::Error) -> bool {
+        let _ = error;
+        false
+    }
 }
 
 /// HTTP server trait for handling incoming requests.
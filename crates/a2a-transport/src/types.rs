@@ -47,6 +47,15 @@ impl HttpRequest {
         }
     }
 
+    pub fn delete(url: impl Into<String>) -> Self {
+        Self {
+            method: Method::Delete,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
     pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
         self.headers.push((name.into(), value.into()));
         self
@@ -87,6 +96,33 @@ impl HttpResponse {
     }
 }
 
+/// Response whose body is streamed as [`BodyFrame`]s.
+#[derive(Debug)]
+pub struct StreamingHttpResponse<S> {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: S,
+}
+
+impl<S> StreamingHttpResponse<S> {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// A frame of a streamed body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BodyFrame {
+    /// A chunk of body data.
+    Data(Bytes),
+    /// Trailers, following the last chunk of data. Not sent if the
+    /// response has none.
+    Trailers(Vec<(String, String)>),
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,6 +165,18 @@ mod tests {
         assert_eq!(resp.header("missing"), None);
     }
 
+    #[test]
+    fn test_streaming_response_header_lookup() {
+        let resp = StreamingHttpResponse {
+            status: 200,
+            headers: vec![("grpc-status".to_string(), "5".to_string())],
+            body: (),
+        };
+
+        assert_eq!(resp.header("Grpc-Status"), Some("5"));
+        assert_eq!(resp.header("grpc-message"), None);
+    }
+
     #[test]
     fn test_method_as_str() {
         assert_eq!(Method::Get.as_str(), "GET");
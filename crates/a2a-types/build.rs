@@ -1,3 +1,6 @@
+#[path = "build/schema.rs"]
+mod schema;
+
 use protox::prost::Message;
 use std::fs;
 use std::path::PathBuf;
@@ -57,5 +60,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         fs::rename(&serde_generated, &serde_target)?;
     }
 
+    // Generate the JSON Schema of the pbjson encoding. The pool is compiled
+    // separately: prost-types drops the `field_behavior` option extensions.
+    let pool = protox::Compiler::new(include_dirs)?
+        .include_source_info(true)
+        .include_imports(true)
+        .open_files(proto_files)?
+        .descriptor_pool();
+    let schema = serde_json::to_string_pretty(&schema::generate(&pool))?;
+    fs::write(out_dir.join("v1.schema.json"), schema + "\n")?;
+    fs::write(out_dir.join("v1.fields.rs"), schema::field_table(&pool))?;
+
     Ok(())
 }
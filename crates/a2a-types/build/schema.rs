@@ -0,0 +1,267 @@
+//! JSON Schema generation from the compiled proto descriptors.
+//!
+//! The schema describes the proto3 JSON mapping pbjson serializes:
+//! lowerCamelCase field names, enum values as strings, 64-bit integers as
+//! decimal strings, bytes as base64 and well-known types per their JSON
+//! mapping.
+
+use protox::prost_reflect::prost_types::FileDescriptorProto;
+use protox::prost_reflect::{
+    DescriptorPool, EnumDescriptor, ExtensionDescriptor, FieldDescriptor, Kind, MessageDescriptor,
+};
+use serde_json::{Map, Value, json};
+
+const PACKAGE: &str = "a2a.v1";
+
+/// `google.api.FieldBehavior.REQUIRED`.
+const REQUIRED: i32 = 2;
+
+/// Build a JSON Schema (draft 2020-12) with every type of [`PACKAGE`] under
+/// `$defs`, keyed by name without the package.
+pub fn generate(pool: &DescriptorPool) -> Value {
+    let field_behavior = pool.get_extension_by_name("google.api.field_behavior");
+
+    let mut defs = Map::new();
+    for message in pool.all_messages() {
+        if message.package_name() == PACKAGE && !message.is_map_entry() {
+            defs.insert(
+                def_name(message.full_name()),
+                message_schema(&message, field_behavior.as_ref()),
+            );
+        }
+    }
+    for enum_ in pool.all_enums() {
+        if enum_.package_name() == PACKAGE {
+            defs.insert(def_name(enum_.full_name()), enum_schema(&enum_));
+        }
+    }
+    defs.extend(well_known_types());
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "A2A protocol types",
+        "description": "JSON encoding of the a2a.v1 protobuf types. Generated by a2a-types' build script; do not edit.",
+        "$defs": defs,
+    })
+}
+
+fn def_name(full_name: &str) -> String {
+    full_name
+        .strip_prefix(PACKAGE)
+        .and_then(|name| name.strip_prefix('.'))
+        .unwrap_or(full_name)
+        .to_string()
+}
+
+fn reference(full_name: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{}", def_name(full_name)) })
+}
+
+/// Schemas of the well-known types used by the A2A protos.
+fn well_known_types() -> Map<String, Value> {
+    let value = reference("google.protobuf.Value");
+    let Value::Object(defs) = json!({
+        "google.protobuf.Struct": {
+            "type": "object",
+            "additionalProperties": value,
+        },
+        "google.protobuf.Value": {
+            "description": "Any JSON value.",
+        },
+        "google.protobuf.ListValue": {
+            "type": "array",
+            "items": value,
+        },
+        "google.protobuf.Timestamp": {
+            "type": "string",
+            "format": "date-time",
+        },
+    }) else {
+        unreachable!()
+    };
+    defs
+}
+
+fn message_schema(
+    message: &MessageDescriptor,
+    field_behavior: Option<&ExtensionDescriptor>,
+) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for field in message.fields() {
+        let mut schema = field_schema(&field);
+        if let Some(description) = description(message.parent_file_descriptor_proto(), field.path())
+        {
+            schema["description"] = description.into();
+        }
+        properties.insert(field.json_name().to_string(), schema);
+        if is_required(&field, field_behavior) {
+            required.push(field.json_name().to_string());
+        }
+    }
+
+    // Exactly one member of each oneof must be set.
+    let mut oneofs: Vec<Value> = message
+        .oneofs()
+        .filter(|oneof| !oneof.is_synthetic())
+        .map(|oneof| {
+            let members: Vec<Value> = oneof
+                .fields()
+                .map(|field| json!({ "required": [field.json_name()] }))
+                .collect();
+            json!({ "oneOf": members })
+        })
+        .collect();
+
+    let mut schema = Map::new();
+    schema.insert("type".into(), "object".into());
+    if let Some(description) = description(message.parent_file_descriptor_proto(), message.path()) {
+        schema.insert("description".into(), description.into());
+    }
+    schema.insert("properties".into(), properties.into());
+    if !required.is_empty() {
+        schema.insert("required".into(), required.into());
+    }
+    match oneofs.len() {
+        0 => {}
+        1 => {
+            schema.insert("oneOf".into(), oneofs.remove(0)["oneOf"].take());
+        }
+        _ => {
+            schema.insert("allOf".into(), oneofs.into());
+        }
+    }
+    schema.into()
+}
+
+fn field_schema(field: &FieldDescriptor) -> Value {
+    if field.is_map() {
+        let Kind::Message(entry) = field.kind() else {
+            unreachable!("map fields have a map entry type")
+        };
+        json!({
+            "type": "object",
+            "additionalProperties": kind_schema(&entry.map_entry_value_field().kind()),
+        })
+    } else if field.is_list() {
+        json!({ "type": "array", "items": kind_schema(&field.kind()) })
+    } else {
+        kind_schema(&field.kind())
+    }
+}
+
+fn kind_schema(kind: &Kind) -> Value {
+    match kind {
+        Kind::Double | Kind::Float => json!({ "type": "number" }),
+        Kind::Int32 | Kind::Sint32 | Kind::Sfixed32 => {
+            json!({ "type": "integer", "format": "int32" })
+        }
+        Kind::Uint32 | Kind::Fixed32 => {
+            json!({ "type": "integer", "format": "uint32", "minimum": 0 })
+        }
+        Kind::Int64 | Kind::Sint64 | Kind::Sfixed64 => {
+            json!({ "type": "string", "format": "int64", "pattern": "^-?[0-9]+$" })
+        }
+        Kind::Uint64 | Kind::Fixed64 => {
+            json!({ "type": "string", "format": "uint64", "pattern": "^[0-9]+$" })
+        }
+        Kind::Bool => json!({ "type": "boolean" }),
+        Kind::String => json!({ "type": "string" }),
+        Kind::Bytes => json!({ "type": "string", "contentEncoding": "base64" }),
+        Kind::Message(message) => reference(message.full_name()),
+        Kind::Enum(enum_) => reference(enum_.full_name()),
+    }
+}
+
+fn enum_schema(enum_: &EnumDescriptor) -> Value {
+    let values: Vec<String> = enum_
+        .values()
+        .map(|value| value.name().to_string())
+        .collect();
+    let mut schema = json!({ "type": "string", "enum": values });
+    if let Some(description) = description(enum_.parent_file_descriptor_proto(), enum_.path()) {
+        schema["description"] = description.into();
+    }
+    schema
+}
+
+/// Generate the Rust table of the fields of every [`PACKAGE`] message, with
+/// their `REQUIRED` annotations, for `card::require_fields`.
+pub fn field_table(pool: &DescriptorPool) -> String {
+    let field_behavior = pool.get_extension_by_name("google.api.field_behavior");
+
+    let mut out = String::from(
+        "// Generated by a2a-types' build script from the proto descriptors; do not edit.\n\n\
+         use crate::card::{Field, Shape};\n\n\
+         /// The fields of a message, by name without the package.\n\
+         pub(crate) fn message_fields(message: &str) -> Option<&'static [Field]> {\n\
+         \x20   match message {\n",
+    );
+    for message in pool.all_messages() {
+        if message.package_name() != PACKAGE || message.is_map_entry() {
+            continue;
+        }
+        out += &format!("        {:?} => Some(&[\n", def_name(message.full_name()));
+        for field in message.fields() {
+            let (shape, kind) = if field.is_map() {
+                let Kind::Message(entry) = field.kind() else {
+                    unreachable!("map fields have a map entry type")
+                };
+                ("Map", entry.map_entry_value_field().kind())
+            } else if field.is_list() {
+                ("List", field.kind())
+            } else {
+                ("Single", field.kind())
+            };
+            let message = match kind {
+                Kind::Message(message) if message.package_name() == PACKAGE => {
+                    format!("Some({:?})", def_name(message.full_name()))
+                }
+                _ => "None".to_string(),
+            };
+            out += &format!(
+                "            Field {{ name: {:?}, required: {}, shape: Shape::{shape}, message: {message} }},\n",
+                field.json_name(),
+                is_required(&field, field_behavior.as_ref()),
+            );
+        }
+        out += "        ]),\n";
+    }
+    out += "        _ => None,\n    }\n}\n";
+    out
+}
+
+fn is_required(field: &FieldDescriptor, field_behavior: Option<&ExtensionDescriptor>) -> bool {
+    let Some(field_behavior) = field_behavior else {
+        return false;
+    };
+    let options = field.options();
+    options.has_extension(field_behavior)
+        && options
+            .get_extension(field_behavior)
+            .as_list()
+            .is_some_and(|behaviors| {
+                behaviors
+                    .iter()
+                    .any(|behavior| behavior.as_enum_number() == Some(REQUIRED))
+            })
+}
+
+/// The leading comment of the element at `path`, without the docs snippet
+/// markers (`--8<--`).
+fn description(file: &FileDescriptorProto, path: &[i32]) -> Option<String> {
+    let location = file
+        .source_code_info
+        .as_ref()?
+        .location
+        .iter()
+        .find(|location| location.path == path)?;
+    let lines: Vec<&str> = location
+        .leading_comments()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with("--8<--"))
+        .collect();
+    let description = lines.join("\n").trim().to_string();
+    (!description.is_empty()).then_some(description)
+}
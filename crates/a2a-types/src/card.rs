@@ -0,0 +1,801 @@
+//! Agent card construction and validation.
+//!
+//! [`AgentCardBuilder`] assembles a card with fluent setters, and
+//! [`AgentCard::validate`] checks a card against the proto's
+//! `field_behavior = REQUIRED` annotations and the rules for the deprecated
+//! interface fields. Diagnostic paths use the card's JSON field names, so
+//! they point at the published document.
+//!
+//! Cards published before 1.0 declare their interfaces with `url`,
+//! `preferredTransport` and `additionalInterfaces` instead of
+//! `supportedInterfaces`; [`from_json`] and
+//! [`AgentCard::normalize_interfaces`] bring them into the current shape.
+
+#![allow(deprecated)]
+
+use crate::generated::a2a::fields;
+use crate::{
+    AgentCapabilities, AgentCard, AgentExtension, AgentInterface, AgentProvider, AgentSkill,
+    Binding, ProtocolBinding, Security, SecurityScheme,
+};
+use serde_json::Value;
+
+/// Protocol version set by [`AgentCardBuilder`] unless overridden.
+pub const DEFAULT_PROTOCOL_VERSION: &str = "1.0";
+
+/// How serious a validation finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Valid, but relies on deprecated or discouraged constructs.
+    Warning,
+    /// The card violates the specification.
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A single validation finding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// JSON path of the offending field, e.g. `skills[0].tags`.
+    pub path: String,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn error(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    fn warning(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}: {}", self.severity, self.path, self.message)
+    }
+}
+
+impl AgentCard {
+    /// Start building a card with its identifying fields.
+    pub fn builder(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        version: impl Into<String>,
+    ) -> AgentCardBuilder {
+        AgentCardBuilder::new(name, description, version)
+    }
+
+    /// Check the card against the specification.
+    ///
+    /// Returns every finding; the card is valid when none of them is an
+    /// error (see [`Diagnostic::is_error`]).
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut out = Vec::new();
+
+        // Absent and default values alike are left out of the JSON form.
+        let json = serde_json::to_value(self).expect("agent cards serialize to JSON");
+        require_fields(&mut out, "", "AgentCard", &json);
+
+        for (i, skill) in self.skills.iter().enumerate() {
+            if !skill.id.is_empty() && self.skills[..i].iter().any(|s| s.id == skill.id) {
+                out.push(Diagnostic::error(
+                    format!("skills[{i}].id"),
+                    format!("duplicate skill id `{}`", skill.id),
+                ));
+            }
+        }
+
+        self.validate_interfaces(&mut out);
+        out
+    }
+
+    fn validate_interfaces(&self, out: &mut Vec<Diagnostic>) {
+        for (i, iface) in self.supported_interfaces.iter().enumerate() {
+            validate_interface(out, &format!("supportedInterfaces[{i}]"), iface);
+            if self.supported_interfaces[..i].contains(iface) {
+                out.push(Diagnostic::warning(
+                    format!("supportedInterfaces[{i}]"),
+                    "duplicate interface",
+                ));
+            }
+        }
+
+        if self.supported_interfaces.is_empty() && self.url.is_none() {
+            out.push(Diagnostic::error(
+                "supportedInterfaces",
+                "the card declares no interfaces",
+            ));
+        }
+
+        let declared = |url: &str, binding: Option<&str>| {
+            self.supported_interfaces.iter().any(|iface| {
+                iface.url == url && binding.is_none_or(|b| iface.protocol_binding == b)
+            })
+        };
+        // The deprecated fields only conflict when `supportedInterfaces` is
+        // present; otherwise they are the card's sole interface declaration.
+        let check_conflicts = !self.supported_interfaces.is_empty();
+
+        if let Some(url) = &self.url {
+            out.push(deprecated("url"));
+            if check_conflicts && !declared(url, None) {
+                out.push(Diagnostic::error(
+                    "url",
+                    format!("`{url}` is not listed in supportedInterfaces"),
+                ));
+            }
+        }
+
+        if let Some(transport) = &self.preferred_transport {
+            out.push(deprecated("preferredTransport"));
+            match &self.url {
+                None => out.push(Diagnostic::warning(
+                    "preferredTransport",
+                    "has no effect without `url`",
+                )),
+                Some(url) if check_conflicts && declared(url, None) => {
+                    if !declared(url, Some(transport)) {
+                        out.push(Diagnostic::error(
+                            "preferredTransport",
+                            format!(
+                                "`{transport}` at `{url}` is not listed in supportedInterfaces"
+                            ),
+                        ));
+                    }
+                }
+                Some(_) => {}
+            }
+            if transport.parse::<ProtocolBinding>().is_err() {
+                out.push(unknown_binding("preferredTransport", transport));
+            }
+        }
+
+        for (i, iface) in self.additional_interfaces.iter().enumerate() {
+            let path = format!("additionalInterfaces[{i}]");
+            out.push(deprecated(&path));
+            validate_interface(out, &path, iface);
+            if check_conflicts && !declared(&iface.url, Some(&iface.protocol_binding)) {
+                out.push(Diagnostic::error(
+                    path,
+                    "interface is not listed in supportedInterfaces",
+                ));
+            }
+        }
+    }
+}
+
+fn validate_interface(out: &mut Vec<Diagnostic>, path: &str, iface: &AgentInterface) {
+    if !iface.url.is_empty()
+        && !iface.url.starts_with("https://")
+        && !iface.url.starts_with("http://")
+    {
+        out.push(Diagnostic::error(
+            format!("{path}.url"),
+            "must be an absolute http(s) URL",
+        ));
+    }
+    if !iface.protocol_binding.is_empty()
+        && iface.protocol_binding.parse::<ProtocolBinding>().is_err()
+    {
+        out.push(unknown_binding(
+            &format!("{path}.protocolBinding"),
+            &iface.protocol_binding,
+        ));
+    }
+}
+
+fn deprecated(path: &str) -> Diagnostic {
+    Diagnostic::warning(path, "deprecated; use supportedInterfaces")
+}
+
+fn unknown_binding(path: &str, binding: &str) -> Diagnostic {
+    // Bindings are an open set, so this is not an error.
+    Diagnostic::warning(path, format!("unknown protocol binding `{binding}`"))
+}
+
+/// How a message field holds its values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Shape {
+    Single,
+    List,
+    Map,
+}
+
+/// A message field, as listed in the generated field table.
+#[derive(Debug)]
+pub(crate) struct Field {
+    /// JSON name of the field.
+    pub name: &'static str,
+    /// Whether the field is annotated `field_behavior = REQUIRED`.
+    pub required: bool,
+    pub shape: Shape,
+    /// Message type of the field's values, for `a2a.v1` messages.
+    pub message: Option<&'static str>,
+}
+
+/// Report the `REQUIRED` fields missing from `value`, the JSON form of a
+/// `message`, and from the messages nested in it.
+fn require_fields(out: &mut Vec<Diagnostic>, path: &str, message: &str, value: &Value) {
+    let (Some(fields), Some(object)) = (fields::message_fields(message), value.as_object()) else {
+        return;
+    };
+    for field in fields {
+        let path = match path {
+            "" => field.name.to_string(),
+            path => format!("{path}.{}", field.name),
+        };
+        let Some(value) = object.get(field.name) else {
+            if field.required {
+                out.push(match field.shape {
+                    Shape::Single => Diagnostic::error(path, "required field is missing"),
+                    Shape::List | Shape::Map => {
+                        Diagnostic::error(path, "required field must not be empty")
+                    }
+                });
+            }
+            continue;
+        };
+        let Some(message) = field.message else {
+            continue;
+        };
+        match (field.shape, value) {
+            (Shape::Single, value) => require_fields(out, &path, message, value),
+            (Shape::List, Value::Array(items)) => {
+                for (i, item) in items.iter().enumerate() {
+                    require_fields(out, &format!("{path}[{i}]"), message, item);
+                }
+            }
+            (Shape::Map, Value::Object(entries)) => {
+                for (key, item) in entries {
+                    require_fields(out, &format!("{path}.{key}"), message, item);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Parse an agent card, accepting the pre-1.0 shape.
+///
+/// Legacy interfaces name their binding `transport` rather than
+/// `protocolBinding`; the deprecated interface fields are then folded into
+/// `supportedInterfaces` (see [`AgentCard::normalize_interfaces`]).
+pub fn from_json(mut value: serde_json::Value) -> serde_json::Result<AgentCard> {
+    if let Some(interfaces) = value
+        .get_mut("additionalInterfaces")
+        .and_then(|v| v.as_array_mut())
+    {
+        for iface in interfaces.iter_mut().filter_map(|i| i.as_object_mut()) {
+            if !iface.contains_key("protocolBinding")
+                && let Some(transport) = iface.remove("transport")
+            {
+                iface.insert("protocolBinding".to_string(), transport);
+            }
+        }
+    }
+    let mut card: AgentCard = serde_json::from_value(value)?;
+    card.normalize_interfaces();
+    Ok(card)
+}
+
+impl AgentCard {
+    /// The card's interfaces in preference order.
+    ///
+    /// This is `supportedInterfaces` when present. Otherwise it is derived
+    /// from the deprecated fields: `url` with `preferredTransport` (JSON-RPC
+    /// when unset), followed by `additionalInterfaces`.
+    pub fn interfaces(&self) -> Vec<AgentInterface> {
+        if !self.supported_interfaces.is_empty() {
+            return self.supported_interfaces.clone();
+        }
+
+        let mut interfaces = Vec::new();
+        if let Some(url) = &self.url {
+            interfaces.push(AgentInterface {
+                url: url.clone(),
+                protocol_binding: self
+                    .preferred_transport
+                    .clone()
+                    .unwrap_or_else(|| Binding::JsonRpc.to_string()),
+                tenant: String::new(),
+            });
+        }
+        for iface in &self.additional_interfaces {
+            // Legacy cards commonly repeat the main interface here.
+            if !interfaces.contains(iface) {
+                interfaces.push(iface.clone());
+            }
+        }
+        interfaces
+    }
+
+    /// Fill `supportedInterfaces` from the deprecated fields when it is empty.
+    ///
+    /// The deprecated fields are kept, so the card still serializes for
+    /// legacy clients.
+    pub fn normalize_interfaces(&mut self) {
+        if self.supported_interfaces.is_empty() {
+            self.supported_interfaces = self.interfaces();
+        }
+    }
+}
+
+/// Fluent builder for [`AgentCard`].
+///
+/// Building never fails; call [`AgentCard::validate`] on the result to
+/// check it.
+#[derive(Debug, Clone)]
+pub struct AgentCardBuilder {
+    card: AgentCard,
+}
+
+impl AgentCardBuilder {
+    pub fn new(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Self {
+        Self {
+            card: AgentCard {
+                protocol_version: Some(DEFAULT_PROTOCOL_VERSION.to_string()),
+                name: name.into(),
+                description: description.into(),
+                version: version.into(),
+                capabilities: Some(AgentCapabilities::default()),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn protocol_version(mut self, version: impl Into<String>) -> Self {
+        self.card.protocol_version = Some(version.into());
+        self
+    }
+
+    /// Add an interface. Interfaces are listed in preference order.
+    pub fn interface(self, binding: Binding, url: impl Into<String>) -> Self {
+        self.supported_interface(AgentInterface {
+            url: url.into(),
+            protocol_binding: binding.to_string(),
+            tenant: String::new(),
+        })
+    }
+
+    /// Add an interface with a custom binding or a tenant.
+    pub fn supported_interface(mut self, interface: AgentInterface) -> Self {
+        self.card.supported_interfaces.push(interface);
+        self
+    }
+
+    pub fn provider(mut self, organization: impl Into<String>, url: impl Into<String>) -> Self {
+        self.card.provider = Some(AgentProvider {
+            organization: organization.into(),
+            url: url.into(),
+        });
+        self
+    }
+
+    pub fn documentation_url(mut self, url: impl Into<String>) -> Self {
+        self.card.documentation_url = Some(url.into());
+        self
+    }
+
+    pub fn icon_url(mut self, url: impl Into<String>) -> Self {
+        self.card.icon_url = Some(url.into());
+        self
+    }
+
+    pub fn capabilities(mut self, capabilities: AgentCapabilities) -> Self {
+        self.card.capabilities = Some(capabilities);
+        self
+    }
+
+    pub fn streaming(mut self, enabled: bool) -> Self {
+        self.capabilities_mut().streaming = Some(enabled);
+        self
+    }
+
+    pub fn push_notifications(mut self, enabled: bool) -> Self {
+        self.capabilities_mut().push_notifications = Some(enabled);
+        self
+    }
+
+    pub fn extension(mut self, extension: AgentExtension) -> Self {
+        self.capabilities_mut().extensions.push(extension);
+        self
+    }
+
+    pub fn input_mode(mut self, media_type: impl Into<String>) -> Self {
+        self.card.default_input_modes.push(media_type.into());
+        self
+    }
+
+    pub fn output_mode(mut self, media_type: impl Into<String>) -> Self {
+        self.card.default_output_modes.push(media_type.into());
+        self
+    }
+
+    pub fn skill(mut self, skill: AgentSkill) -> Self {
+        self.card.skills.push(skill);
+        self
+    }
+
+    pub fn security_scheme(mut self, name: impl Into<String>, scheme: SecurityScheme) -> Self {
+        self.card.security_schemes.insert(name.into(), scheme);
+        self
+    }
+
+    pub fn security(mut self, requirement: Security) -> Self {
+        self.card.security.push(requirement);
+        self
+    }
+
+    pub fn supports_extended_agent_card(mut self, supported: bool) -> Self {
+        self.card.supports_extended_agent_card = Some(supported);
+        self
+    }
+
+    pub fn build(self) -> AgentCard {
+        self.card
+    }
+
+    fn capabilities_mut(&mut self) -> &mut AgentCapabilities {
+        self.card.capabilities.get_or_insert_with(Default::default)
+    }
+}
+
+impl AgentSkill {
+    /// Create a skill with its required fields.
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        tags: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            description: description.into(),
+            tags: tags.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_example(mut self, example: impl Into<String>) -> Self {
+        self.examples.push(example.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_card() -> AgentCard {
+        AgentCard::builder("Recipe Agent", "Helps with recipes", "1.0.0")
+            .interface(Binding::JsonRpc, "https://agent.example.com/a2a")
+            .interface(Binding::Rest, "https://agent.example.com/v1")
+            .provider("Example", "https://example.com")
+            .streaming(true)
+            .input_mode("text/plain")
+            .output_mode("text/plain")
+            .skill(AgentSkill::new(
+                "recipes",
+                "Recipes",
+                "Finds recipes",
+                ["cooking"],
+            ))
+            .build()
+    }
+
+    fn paths(diagnostics: &[Diagnostic], severity: Severity) -> Vec<&str> {
+        diagnostics
+            .iter()
+            .filter(|d| d.severity == severity)
+            .map(|d| d.path.as_str())
+            .collect()
+    }
+
+    fn snake_to_camel(name: &str) -> String {
+        let mut out = String::new();
+        let mut upper = false;
+        for c in name.chars() {
+            if c == '_' {
+                upper = true;
+            } else if upper {
+                out.push(c.to_ascii_uppercase());
+                upper = false;
+            } else {
+                out.push(c);
+            }
+        }
+        out
+    }
+
+    /// Fields annotated `REQUIRED` in a message of the proto, as JSON names.
+    fn proto_required_fields(message: &str) -> Vec<String> {
+        let proto = include_str!("../proto/a2a.proto");
+        let start = proto
+            .find(&format!("message {message} {{"))
+            .expect("message in proto");
+        let body = &proto[start..];
+        let body: String = body[..body.find("\n}").unwrap()]
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        // Declarations may span several lines
+        body.split(';')
+            .filter(|decl| decl.contains("field_behavior) = REQUIRED"))
+            .map(|decl| match decl.split_once("json_name = \"") {
+                Some((_, json_name)) => json_name.split('"').next().unwrap().to_string(),
+                None => {
+                    let decl = decl.split('=').next().unwrap();
+                    snake_to_camel(decl.split_whitespace().last().unwrap())
+                }
+            })
+            .collect()
+    }
+
+    /// Names of the messages declared in the proto.
+    fn proto_messages() -> Vec<&'static str> {
+        include_str!("../proto/a2a.proto")
+            .lines()
+            .filter_map(|line| line.strip_prefix("message "))
+            .map(|line| line.trim_end_matches(" {"))
+            .collect()
+    }
+
+    #[test]
+    fn test_field_table_follows_the_proto() {
+        let messages = proto_messages();
+        assert!(messages.contains(&"AgentCard"));
+        for message in messages {
+            let fields = fields::message_fields(message).expect("message in field table");
+            let mut required: Vec<&str> = fields
+                .iter()
+                .filter(|f| f.required)
+                .map(|f| f.name)
+                .collect();
+            let mut expected = proto_required_fields(message);
+            required.sort();
+            expected.sort();
+            assert_eq!(required, expected, "{message}");
+        }
+    }
+
+    #[test]
+    fn test_built_card_is_valid() {
+        let card = valid_card();
+        assert_eq!(card.validate(), vec![]);
+        assert_eq!(card.protocol_version.as_deref(), Some("1.0"));
+        assert_eq!(card.supported_interfaces[1].protocol_binding, "HTTP+JSON");
+        assert_eq!(card.capabilities.unwrap().streaming, Some(true));
+    }
+
+    #[test]
+    fn test_reports_every_required_field_of_the_proto() {
+        // Reach every message nested in a card, with an empty value of it
+        // and the JSON path it sits at.
+        let mut pending = vec![("AgentCard", Vec::<(&Field, String)>::new())];
+        let mut seen = vec!["AgentCard"];
+        while let Some((message, steps)) = pending.pop() {
+            let fields = fields::message_fields(message).unwrap();
+            let required: Vec<&str> = fields
+                .iter()
+                .filter(|f| f.required)
+                .map(|f| f.name)
+                .collect();
+            if !required.is_empty() {
+                let mut card = serde_json::json!({});
+                let mut prefix = String::new();
+                for (field, key) in steps.iter().rev() {
+                    card = match field.shape {
+                        Shape::Single => serde_json::json!({ field.name: card }),
+                        Shape::List => serde_json::json!({ field.name: [card] }),
+                        Shape::Map => serde_json::json!({ field.name: { key: card } }),
+                    };
+                }
+                for (field, key) in &steps {
+                    prefix += field.name;
+                    prefix += match field.shape {
+                        Shape::Single => ".".to_string(),
+                        Shape::List => "[0].".to_string(),
+                        Shape::Map => format!(".{key}."),
+                    }
+                    .as_str();
+                }
+                let card: AgentCard = serde_json::from_value(card).unwrap();
+                let errors = card.validate();
+                let errors = paths(&errors, Severity::Error);
+                for field in required {
+                    let path = format!("{prefix}{field}");
+                    assert!(errors.contains(&path.as_str()), "{path} not reported");
+                }
+            }
+
+            for field in fields {
+                if let Some(nested) = field.message
+                    && !seen.contains(&nested)
+                {
+                    seen.push(nested);
+                    let mut steps = steps.clone();
+                    steps.push((field, "key".to_string()));
+                    pending.push((nested, steps));
+                }
+            }
+        }
+        for message in ["SecurityScheme", "OAuthFlows", "ImplicitOAuthFlow"] {
+            assert!(seen.contains(&message), "{message} not reached");
+        }
+    }
+
+    #[test]
+    fn test_security_scheme_fields_are_required() {
+        let card: AgentCard = serde_json::from_value(serde_json::json!({
+            "securitySchemes": {
+                "key": {"apiKeySecurityScheme": {}},
+                "http": {"httpAuthSecurityScheme": {}},
+                "oauth": {"oauth2SecurityScheme": {"flows": {"authorizationCode": {}}}},
+                "oidc": {"openIdConnectSecurityScheme": {}},
+            },
+        }))
+        .unwrap();
+        let errors = card.validate();
+        let errors = paths(&errors, Severity::Error);
+        for path in [
+            "securitySchemes.key.apiKeySecurityScheme.location",
+            "securitySchemes.key.apiKeySecurityScheme.name",
+            "securitySchemes.http.httpAuthSecurityScheme.scheme",
+            "securitySchemes.oauth.oauth2SecurityScheme.flows.authorizationCode.authorizationUrl",
+            "securitySchemes.oauth.oauth2SecurityScheme.flows.authorizationCode.tokenUrl",
+            "securitySchemes.oauth.oauth2SecurityScheme.flows.authorizationCode.scopes",
+            "securitySchemes.oidc.openIdConnectSecurityScheme.openIdConnectUrl",
+        ] {
+            assert!(errors.contains(&path), "{path} not reported");
+        }
+    }
+
+    #[test]
+    fn test_legacy_only_card_warns() {
+        let mut card = valid_card();
+        card.supported_interfaces.clear();
+        card.url = Some("https://agent.example.com/a2a".to_string());
+        card.preferred_transport = Some("JSONRPC".to_string());
+
+        let diagnostics = card.validate();
+        assert_eq!(paths(&diagnostics, Severity::Error), Vec::<&str>::new());
+        assert_eq!(
+            paths(&diagnostics, Severity::Warning),
+            vec!["url", "preferredTransport"]
+        );
+    }
+
+    #[test]
+    fn test_deprecated_fields_conflicting_with_supported_interfaces() {
+        let mut card = valid_card();
+        card.url = Some("https://agent.example.com/a2a".to_string());
+        card.preferred_transport = Some("GRPC".to_string());
+        card.additional_interfaces = vec![AgentInterface {
+            url: "https://other.example.com".to_string(),
+            protocol_binding: "HTTP+JSON".to_string(),
+            tenant: String::new(),
+        }];
+
+        let diagnostics = card.validate();
+        assert_eq!(
+            paths(&diagnostics, Severity::Error),
+            vec!["preferredTransport", "additionalInterfaces[0]"]
+        );
+
+        card.url = Some("https://elsewhere.example.com".to_string());
+        assert!(paths(&card.validate(), Severity::Error).contains(&"url"));
+    }
+
+    #[test]
+    fn test_interface_and_skill_checks() {
+        let card = AgentCard::builder("a", "b", "1")
+            .interface(Binding::JsonRpc, "agent.example.com")
+            .supported_interface(AgentInterface {
+                url: "https://agent.example.com/ws".to_string(),
+                protocol_binding: "WEBSOCKET".to_string(),
+                tenant: String::new(),
+            })
+            .input_mode("text/plain")
+            .output_mode("text/plain")
+            .skill(AgentSkill::new("s", "S", "d", ["t"]))
+            .skill(AgentSkill::new("s", "S2", "d", ["t"]))
+            .build();
+
+        let diagnostics = card.validate();
+        assert_eq!(
+            paths(&diagnostics, Severity::Error),
+            vec!["skills[1].id", "supportedInterfaces[0].url"]
+        );
+        assert_eq!(
+            paths(&diagnostics, Severity::Warning),
+            vec!["supportedInterfaces[1].protocolBinding"]
+        );
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "error: skills[1].id: duplicate skill id `s`"
+        );
+    }
+
+    #[test]
+    fn test_normalizes_legacy_card() {
+        let card = from_json(serde_json::json!({
+            "protocolVersion": "0.3.0",
+            "name": "legacy",
+            "description": "pre-1.0 agent",
+            "version": "1.0",
+            "url": "https://agent.example.com/a2a",
+            "preferredTransport": "JSONRPC",
+            "additionalInterfaces": [
+                {"url": "https://agent.example.com/a2a", "transport": "JSONRPC"},
+                {"url": "https://agent.example.com/grpc", "transport": "GRPC"}
+            ],
+            "capabilities": {},
+            "defaultInputModes": ["text/plain"],
+            "defaultOutputModes": ["text/plain"],
+            "skills": [{"id": "s", "name": "S", "description": "d", "tags": ["t"]}]
+        }))
+        .unwrap();
+
+        let bindings: Vec<_> = card
+            .supported_interfaces
+            .iter()
+            .map(|i| (i.url.as_str(), i.protocol_binding.as_str()))
+            .collect();
+        assert_eq!(
+            bindings,
+            vec![
+                ("https://agent.example.com/a2a", "JSONRPC"),
+                ("https://agent.example.com/grpc", "GRPC"),
+            ]
+        );
+        // The normalized card no longer conflicts with its deprecated fields.
+        assert!(!card.validate().iter().any(Diagnostic::is_error));
+    }
+
+    #[test]
+    fn test_legacy_url_defaults_to_jsonrpc() {
+        let mut card = AgentCard {
+            url: Some("https://agent.example.com".to_string()),
+            ..Default::default()
+        };
+        card.normalize_interfaces();
+        assert_eq!(card.supported_interfaces[0].protocol_binding, "JSONRPC");
+
+        // Current cards are left alone.
+        let mut current = AgentCard::builder("a", "b", "1")
+            .interface(Binding::Rest, "https://agent.example.com/v1")
+            .build();
+        current.url = Some("https://agent.example.com".to_string());
+        current.normalize_interfaces();
+        assert_eq!(current.supported_interfaces.len(), 1);
+    }
+}
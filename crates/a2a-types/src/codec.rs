@@ -0,0 +1,159 @@
+//! Wire codecs for the HTTP bindings.
+//!
+//! Messages go on the wire as pbjson JSON (`application/json`) or as binary
+//! protobuf (`application/x-protobuf`). Binary protobuf skips the base64
+//! encoding of file bytes and is much cheaper for large artifacts.
+
+use crate::error::Result;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Media type of JSON bodies.
+pub const JSON: &str = "application/json";
+
+/// Media type of binary protobuf bodies.
+pub const PROTOBUF: &str = "application/x-protobuf";
+
+/// Encoding of a request or response body.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Codec {
+    #[default]
+    Json,
+    Protobuf,
+}
+
+impl Codec {
+    pub const fn media_type(self) -> &'static str {
+        match self {
+            Self::Json => JSON,
+            Self::Protobuf => PROTOBUF,
+        }
+    }
+
+    /// The codec of a `Content-Type` header value, ignoring parameters.
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let media_type = content_type.split(';').next().unwrap_or_default().trim();
+        if media_type.eq_ignore_ascii_case(JSON) {
+            Some(Self::Json)
+        } else if media_type.eq_ignore_ascii_case(PROTOBUF)
+            || media_type.eq_ignore_ascii_case("application/protobuf")
+        {
+            Some(Self::Protobuf)
+        } else {
+            None
+        }
+    }
+
+    /// Pick the response codec for an `Accept` header value.
+    ///
+    /// The media range with the highest `q` wins, the first listed on a tie;
+    /// wildcards select JSON. A missing or empty header selects JSON, and
+    /// `None` means neither codec is acceptable.
+    pub fn negotiate(accept: Option<&str>) -> Option<Self> {
+        let Some(accept) = accept.filter(|accept| !accept.trim().is_empty()) else {
+            return Some(Self::Json);
+        };
+
+        let mut best: Option<(Self, f32)> = None;
+        for range in accept.split(',') {
+            let mut params = range.split(';');
+            let media_range = params.next().unwrap_or_default().trim();
+            let codec = match media_range {
+                "*/*" | "application/*" => Self::Json,
+                media_type => match Self::from_content_type(media_type) {
+                    Some(codec) => codec,
+                    None => continue,
+                },
+            };
+            let q = params
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if q > 0.0 && best.is_none_or(|(_, best_q)| q > best_q) {
+                best = Some((codec, q));
+            }
+        }
+        best.map(|(codec, _)| codec)
+    }
+
+    /// The `Accept` header value for a client preferring this codec.
+    ///
+    /// Protobuf clients still accept JSON, so agents without protobuf
+    /// support can answer.
+    pub const fn accept(self) -> &'static str {
+        match self {
+            Self::Json => JSON,
+            Self::Protobuf => "application/x-protobuf, application/json;q=0.5",
+        }
+    }
+
+    pub fn encode<M: prost::Message + Serialize>(self, message: &M) -> Result<Vec<u8>> {
+        match self {
+            Self::Json => Ok(serde_json::to_vec(message)?),
+            Self::Protobuf => Ok(message.encode_to_vec()),
+        }
+    }
+
+    pub fn decode<M: prost::Message + Default + DeserializeOwned>(self, body: &[u8]) -> Result<M> {
+        match self {
+            Self::Json => Ok(serde_json::from_slice(body)?),
+            Self::Protobuf => Ok(M::decode(body)?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Message, Part, SendMessageRequest};
+
+    #[test]
+    fn test_from_content_type() {
+        assert_eq!(
+            Codec::from_content_type("application/json; charset=utf-8"),
+            Some(Codec::Json)
+        );
+        assert_eq!(
+            Codec::from_content_type("Application/X-Protobuf"),
+            Some(Codec::Protobuf)
+        );
+        assert_eq!(Codec::from_content_type("text/plain"), None);
+    }
+
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(Codec::negotiate(None), Some(Codec::Json));
+        assert_eq!(Codec::negotiate(Some("*/*")), Some(Codec::Json));
+        assert_eq!(
+            Codec::negotiate(Some(Codec::Protobuf.accept())),
+            Some(Codec::Protobuf)
+        );
+        assert_eq!(
+            Codec::negotiate(Some("application/x-protobuf;q=0.2, application/json")),
+            Some(Codec::Json)
+        );
+        assert_eq!(
+            Codec::negotiate(Some("text/html, application/x-protobuf")),
+            Some(Codec::Protobuf)
+        );
+        assert_eq!(Codec::negotiate(Some("text/html")), None);
+        assert_eq!(Codec::negotiate(Some("application/json;q=0")), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let request = SendMessageRequest::from(
+            Message::user_text("hello").with_part(Part::file_bytes(vec![0, 1, 2], "a/b")),
+        );
+        for codec in [Codec::Json, Codec::Protobuf] {
+            let body = codec.encode(&request).unwrap();
+            let decoded: SendMessageRequest = codec.decode(&body).unwrap();
+            assert_eq!(decoded, request, "{codec:?}");
+        }
+        assert!(
+            Codec::Protobuf
+                .decode::<SendMessageRequest>(b"\xff")
+                .is_err()
+        );
+    }
+}
@@ -10,6 +10,10 @@ pub enum Error {
     #[error("JSON serialization error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// Protobuf decoding error.
+    #[error("Protobuf decoding error: {0}")]
+    Protobuf(#[from] prost::DecodeError),
+
     /// Invalid message format or content.
     #[error("Invalid message: {0}")]
     InvalidMessage(String),
@@ -0,0 +1,216 @@
+//! Extension negotiation.
+//!
+//! Agents declare the extensions they support in
+//! `capabilities.extensions` of their card, some of them `required`.
+//! Clients request extensions by listing their URIs in the
+//! [`X-A2A-Extensions`](HEADER) header; the agent activates the requested
+//! extensions it declares and echoes the activated set in the same header of
+//! its response.
+
+use crate::{AgentCard, AgentExtension};
+use std::fmt;
+
+/// Header listing requested (on requests) or activated (on responses)
+/// extension URIs, comma-separated.
+pub const HEADER: &str = "X-A2A-Extensions";
+
+/// Parse an `X-A2A-Extensions` header value.
+pub fn parse_header(value: &str) -> Vec<String> {
+    let mut uris: Vec<String> = Vec::new();
+    for uri in value.split(',').map(str::trim) {
+        if !uri.is_empty() && !uris.iter().any(|u| u == uri) {
+            uris.push(uri.to_string());
+        }
+    }
+    uris
+}
+
+/// Format extension URIs as an `X-A2A-Extensions` header value.
+pub fn format_header(uris: &[String]) -> String {
+    uris.join(", ")
+}
+
+/// Failed extension negotiation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtensionError {
+    /// The agent requires extensions the client doesn't support.
+    Unsupported(Vec<String>),
+    /// The client didn't request extensions the agent requires.
+    NotRequested(Vec<String>),
+}
+
+impl fmt::Display for ExtensionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported(uris) => {
+                write!(
+                    f,
+                    "agent requires unsupported extensions: {}",
+                    uris.join(", ")
+                )
+            }
+            Self::NotRequested(uris) => {
+                write!(f, "required extensions not requested: {}", uris.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExtensionError {}
+
+/// The extensions one side of a connection supports.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExtensionRegistry {
+    extensions: Vec<AgentExtension>,
+}
+
+impl ExtensionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The extensions an agent declares on its card.
+    pub fn from_card(card: &AgentCard) -> Self {
+        Self {
+            extensions: card
+                .capabilities
+                .as_ref()
+                .map(|capabilities| capabilities.extensions.clone())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Support the extension with this URI.
+    pub fn register(self, uri: impl Into<String>) -> Self {
+        self.declare(AgentExtension {
+            uri: uri.into(),
+            ..Default::default()
+        })
+    }
+
+    /// Support an extension, replacing any earlier one with the same URI.
+    pub fn declare(mut self, extension: AgentExtension) -> Self {
+        self.extensions.retain(|e| e.uri != extension.uri);
+        self.extensions.push(extension);
+        self
+    }
+
+    pub fn extensions(&self) -> &[AgentExtension] {
+        &self.extensions
+    }
+
+    pub fn supports(&self, uri: &str) -> bool {
+        self.extensions.iter().any(|e| e.uri == uri)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.extensions.is_empty()
+    }
+
+    /// Agent side: the extensions to activate for a request.
+    ///
+    /// Requested extensions that aren't declared are ignored. Fails if a
+    /// required extension wasn't requested.
+    pub fn activate(&self, requested: &[String]) -> Result<Vec<String>, ExtensionError> {
+        let missing: Vec<String> = self
+            .extensions
+            .iter()
+            .filter(|e| e.required && !requested.contains(&e.uri))
+            .map(|e| e.uri.clone())
+            .collect();
+        if !missing.is_empty() {
+            return Err(ExtensionError::NotRequested(missing));
+        }
+        Ok(requested
+            .iter()
+            .filter(|uri| self.supports(uri))
+            .cloned()
+            .collect())
+    }
+
+    /// Client side: the extensions to request from an agent.
+    ///
+    /// These are the supported extensions the card declares. Fails if the
+    /// card requires an extension that isn't supported.
+    pub fn request(&self, card: &AgentCard) -> Result<Vec<String>, ExtensionError> {
+        let declared = Self::from_card(card);
+        let unsupported: Vec<String> = declared
+            .extensions
+            .iter()
+            .filter(|e| e.required && !self.supports(&e.uri))
+            .map(|e| e.uri.clone())
+            .collect();
+        if !unsupported.is_empty() {
+            return Err(ExtensionError::Unsupported(unsupported));
+        }
+        Ok(self
+            .extensions
+            .iter()
+            .filter(|e| declared.supports(&e.uri))
+            .map(|e| e.uri.clone())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AgentCapabilities;
+
+    const TRACING: &str = "https://example.com/ext/tracing/v1";
+    const COST: &str = "https://example.com/ext/cost/v1";
+
+    fn card(extensions: Vec<AgentExtension>) -> AgentCard {
+        AgentCard {
+            capabilities: Some(AgentCapabilities {
+                extensions,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn required(uri: &str) -> AgentExtension {
+        AgentExtension {
+            uri: uri.to_string(),
+            required: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_header_round_trip() {
+        let uris = parse_header(&format!(" {TRACING},{COST}, ,{TRACING}"));
+        assert_eq!(uris, [TRACING, COST]);
+        assert_eq!(format_header(&uris), format!("{TRACING}, {COST}"));
+        assert!(parse_header("").is_empty());
+    }
+
+    #[test]
+    fn test_activate() {
+        let agent = ExtensionRegistry::new().register(TRACING).register(COST);
+        let requested = parse_header(&format!("{COST}, https://unknown.example.com"));
+        assert_eq!(agent.activate(&requested).unwrap(), [COST]);
+
+        let agent = ExtensionRegistry::from_card(&card(vec![required(TRACING)]));
+        assert_eq!(
+            agent.activate(&[COST.to_string()]),
+            Err(ExtensionError::NotRequested(vec![TRACING.to_string()]))
+        );
+        assert_eq!(agent.activate(&[TRACING.to_string()]).unwrap(), [TRACING]);
+    }
+
+    #[test]
+    fn test_request() {
+        let client = ExtensionRegistry::new().register(TRACING).register(COST);
+        let card = card(vec![required(TRACING)]);
+        assert_eq!(client.request(&card).unwrap(), [TRACING]);
+
+        let client = ExtensionRegistry::new().register(COST);
+        assert_eq!(
+            client.request(&card),
+            Err(ExtensionError::Unsupported(vec![TRACING.to_string()]))
+        );
+        assert!(client.request(&AgentCard::default()).unwrap().is_empty());
+    }
+}
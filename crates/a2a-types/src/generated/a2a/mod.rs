@@ -4,3 +4,7 @@ pub use v1::*;
 // pbjson-generated serde implementations (proto3 JSON compliant)
 // This file contains `impl Serialize` and `impl Deserialize` blocks
 include!("v1.serde.rs");
+
+// Fields of every message with their `field_behavior` annotations
+#[path = "v1.fields.rs"]
+pub(crate) mod fields;
@@ -0,0 +1,1162 @@
+// Generated by a2a-types' build script from the proto descriptors; do not edit.
+
+use crate::card::{Field, Shape};
+
+/// The fields of a message, by name without the package.
+pub(crate) fn message_fields(message: &str) -> Option<&'static [Field]> {
+    match message {
+        "SendMessageConfiguration" => Some(&[
+            Field {
+                name: "acceptedOutputModes",
+                required: false,
+                shape: Shape::List,
+                message: None,
+            },
+            Field {
+                name: "pushNotificationConfig",
+                required: false,
+                shape: Shape::Single,
+                message: Some("PushNotificationConfig"),
+            },
+            Field {
+                name: "historyLength",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "blocking",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "Task" => Some(&[
+            Field {
+                name: "id",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "contextId",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "status",
+                required: true,
+                shape: Shape::Single,
+                message: Some("TaskStatus"),
+            },
+            Field {
+                name: "artifacts",
+                required: false,
+                shape: Shape::List,
+                message: Some("Artifact"),
+            },
+            Field {
+                name: "history",
+                required: false,
+                shape: Shape::List,
+                message: Some("Message"),
+            },
+            Field {
+                name: "metadata",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "TaskStatus" => Some(&[
+            Field {
+                name: "state",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "message",
+                required: false,
+                shape: Shape::Single,
+                message: Some("Message"),
+            },
+            Field {
+                name: "timestamp",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "Part" => Some(&[
+            Field {
+                name: "text",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "file",
+                required: false,
+                shape: Shape::Single,
+                message: Some("FilePart"),
+            },
+            Field {
+                name: "data",
+                required: false,
+                shape: Shape::Single,
+                message: Some("DataPart"),
+            },
+            Field {
+                name: "metadata",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "FilePart" => Some(&[
+            Field {
+                name: "fileWithUri",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "fileWithBytes",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "mediaType",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "name",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "DataPart" => Some(&[Field {
+            name: "data",
+            required: true,
+            shape: Shape::Single,
+            message: None,
+        }]),
+        "Message" => Some(&[
+            Field {
+                name: "messageId",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "contextId",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "taskId",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "role",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "parts",
+                required: true,
+                shape: Shape::List,
+                message: Some("Part"),
+            },
+            Field {
+                name: "metadata",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "extensions",
+                required: false,
+                shape: Shape::List,
+                message: None,
+            },
+            Field {
+                name: "referenceTaskIds",
+                required: false,
+                shape: Shape::List,
+                message: None,
+            },
+        ]),
+        "Artifact" => Some(&[
+            Field {
+                name: "artifactId",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "name",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "description",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "parts",
+                required: true,
+                shape: Shape::List,
+                message: Some("Part"),
+            },
+            Field {
+                name: "metadata",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "extensions",
+                required: false,
+                shape: Shape::List,
+                message: None,
+            },
+        ]),
+        "TaskStatusUpdateEvent" => Some(&[
+            Field {
+                name: "taskId",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "contextId",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "status",
+                required: true,
+                shape: Shape::Single,
+                message: Some("TaskStatus"),
+            },
+            Field {
+                name: "final",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "metadata",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "TaskArtifactUpdateEvent" => Some(&[
+            Field {
+                name: "taskId",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "contextId",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "artifact",
+                required: true,
+                shape: Shape::Single,
+                message: Some("Artifact"),
+            },
+            Field {
+                name: "append",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "lastChunk",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "metadata",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "PushNotificationConfig" => Some(&[
+            Field {
+                name: "id",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "url",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "token",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "authentication",
+                required: false,
+                shape: Shape::Single,
+                message: Some("AuthenticationInfo"),
+            },
+        ]),
+        "AuthenticationInfo" => Some(&[
+            Field {
+                name: "schemes",
+                required: true,
+                shape: Shape::List,
+                message: None,
+            },
+            Field {
+                name: "credentials",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "AgentInterface" => Some(&[
+            Field {
+                name: "url",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "protocolBinding",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "tenant",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "AgentCard" => Some(&[
+            Field {
+                name: "name",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "description",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "url",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "provider",
+                required: false,
+                shape: Shape::Single,
+                message: Some("AgentProvider"),
+            },
+            Field {
+                name: "version",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "documentationUrl",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "capabilities",
+                required: true,
+                shape: Shape::Single,
+                message: Some("AgentCapabilities"),
+            },
+            Field {
+                name: "securitySchemes",
+                required: false,
+                shape: Shape::Map,
+                message: Some("SecurityScheme"),
+            },
+            Field {
+                name: "security",
+                required: false,
+                shape: Shape::List,
+                message: Some("Security"),
+            },
+            Field {
+                name: "defaultInputModes",
+                required: true,
+                shape: Shape::List,
+                message: None,
+            },
+            Field {
+                name: "defaultOutputModes",
+                required: true,
+                shape: Shape::List,
+                message: None,
+            },
+            Field {
+                name: "skills",
+                required: true,
+                shape: Shape::List,
+                message: Some("AgentSkill"),
+            },
+            Field {
+                name: "supportsExtendedAgentCard",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "preferredTransport",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "additionalInterfaces",
+                required: false,
+                shape: Shape::List,
+                message: Some("AgentInterface"),
+            },
+            Field {
+                name: "protocolVersion",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "signatures",
+                required: false,
+                shape: Shape::List,
+                message: Some("AgentCardSignature"),
+            },
+            Field {
+                name: "iconUrl",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "supportedInterfaces",
+                required: false,
+                shape: Shape::List,
+                message: Some("AgentInterface"),
+            },
+        ]),
+        "AgentProvider" => Some(&[
+            Field {
+                name: "url",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "organization",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "AgentCapabilities" => Some(&[
+            Field {
+                name: "streaming",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "pushNotifications",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "extensions",
+                required: false,
+                shape: Shape::List,
+                message: Some("AgentExtension"),
+            },
+            Field {
+                name: "stateTransitionHistory",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "AgentExtension" => Some(&[
+            Field {
+                name: "uri",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "description",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "required",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "params",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "AgentSkill" => Some(&[
+            Field {
+                name: "id",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "name",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "description",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "tags",
+                required: true,
+                shape: Shape::List,
+                message: None,
+            },
+            Field {
+                name: "examples",
+                required: false,
+                shape: Shape::List,
+                message: None,
+            },
+            Field {
+                name: "inputModes",
+                required: false,
+                shape: Shape::List,
+                message: None,
+            },
+            Field {
+                name: "outputModes",
+                required: false,
+                shape: Shape::List,
+                message: None,
+            },
+            Field {
+                name: "security",
+                required: false,
+                shape: Shape::List,
+                message: Some("Security"),
+            },
+        ]),
+        "AgentCardSignature" => Some(&[
+            Field {
+                name: "protected",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "signature",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "header",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "TaskPushNotificationConfig" => Some(&[
+            Field {
+                name: "name",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "pushNotificationConfig",
+                required: true,
+                shape: Shape::Single,
+                message: Some("PushNotificationConfig"),
+            },
+        ]),
+        "StringList" => Some(&[Field {
+            name: "list",
+            required: false,
+            shape: Shape::List,
+            message: None,
+        }]),
+        "Security" => Some(&[Field {
+            name: "schemes",
+            required: false,
+            shape: Shape::Map,
+            message: Some("StringList"),
+        }]),
+        "SecurityScheme" => Some(&[
+            Field {
+                name: "apiKeySecurityScheme",
+                required: false,
+                shape: Shape::Single,
+                message: Some("APIKeySecurityScheme"),
+            },
+            Field {
+                name: "httpAuthSecurityScheme",
+                required: false,
+                shape: Shape::Single,
+                message: Some("HTTPAuthSecurityScheme"),
+            },
+            Field {
+                name: "oauth2SecurityScheme",
+                required: false,
+                shape: Shape::Single,
+                message: Some("OAuth2SecurityScheme"),
+            },
+            Field {
+                name: "openIdConnectSecurityScheme",
+                required: false,
+                shape: Shape::Single,
+                message: Some("OpenIdConnectSecurityScheme"),
+            },
+            Field {
+                name: "mtlsSecurityScheme",
+                required: false,
+                shape: Shape::Single,
+                message: Some("MutualTlsSecurityScheme"),
+            },
+        ]),
+        "APIKeySecurityScheme" => Some(&[
+            Field {
+                name: "description",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "location",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "name",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "HTTPAuthSecurityScheme" => Some(&[
+            Field {
+                name: "description",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "scheme",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "bearerFormat",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "OAuth2SecurityScheme" => Some(&[
+            Field {
+                name: "description",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "flows",
+                required: true,
+                shape: Shape::Single,
+                message: Some("OAuthFlows"),
+            },
+            Field {
+                name: "oauth2MetadataUrl",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "OpenIdConnectSecurityScheme" => Some(&[
+            Field {
+                name: "description",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "openIdConnectUrl",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "MutualTlsSecurityScheme" => Some(&[Field {
+            name: "description",
+            required: false,
+            shape: Shape::Single,
+            message: None,
+        }]),
+        "OAuthFlows" => Some(&[
+            Field {
+                name: "authorizationCode",
+                required: false,
+                shape: Shape::Single,
+                message: Some("AuthorizationCodeOAuthFlow"),
+            },
+            Field {
+                name: "clientCredentials",
+                required: false,
+                shape: Shape::Single,
+                message: Some("ClientCredentialsOAuthFlow"),
+            },
+            Field {
+                name: "implicit",
+                required: false,
+                shape: Shape::Single,
+                message: Some("ImplicitOAuthFlow"),
+            },
+            Field {
+                name: "password",
+                required: false,
+                shape: Shape::Single,
+                message: Some("PasswordOAuthFlow"),
+            },
+        ]),
+        "AuthorizationCodeOAuthFlow" => Some(&[
+            Field {
+                name: "authorizationUrl",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "tokenUrl",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "refreshUrl",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "scopes",
+                required: true,
+                shape: Shape::Map,
+                message: None,
+            },
+        ]),
+        "ClientCredentialsOAuthFlow" => Some(&[
+            Field {
+                name: "tokenUrl",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "refreshUrl",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "scopes",
+                required: true,
+                shape: Shape::Map,
+                message: None,
+            },
+        ]),
+        "ImplicitOAuthFlow" => Some(&[
+            Field {
+                name: "authorizationUrl",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "refreshUrl",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "scopes",
+                required: true,
+                shape: Shape::Map,
+                message: None,
+            },
+        ]),
+        "PasswordOAuthFlow" => Some(&[
+            Field {
+                name: "tokenUrl",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "refreshUrl",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "scopes",
+                required: true,
+                shape: Shape::Map,
+                message: None,
+            },
+        ]),
+        "SendMessageRequest" => Some(&[
+            Field {
+                name: "message",
+                required: true,
+                shape: Shape::Single,
+                message: Some("Message"),
+            },
+            Field {
+                name: "configuration",
+                required: false,
+                shape: Shape::Single,
+                message: Some("SendMessageConfiguration"),
+            },
+            Field {
+                name: "metadata",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "tenant",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "GetTaskRequest" => Some(&[
+            Field {
+                name: "name",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "historyLength",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "tenant",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "ListTasksRequest" => Some(&[
+            Field {
+                name: "contextId",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "status",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "pageSize",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "pageToken",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "historyLength",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "lastUpdatedAfter",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "includeArtifacts",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "tenant",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "ListTasksResponse" => Some(&[
+            Field {
+                name: "tasks",
+                required: true,
+                shape: Shape::List,
+                message: Some("Task"),
+            },
+            Field {
+                name: "nextPageToken",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "pageSize",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "totalSize",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "CancelTaskRequest" => Some(&[
+            Field {
+                name: "name",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "tenant",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "GetTaskPushNotificationConfigRequest" => Some(&[
+            Field {
+                name: "name",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "tenant",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "DeleteTaskPushNotificationConfigRequest" => Some(&[
+            Field {
+                name: "name",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "tenant",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "SetTaskPushNotificationConfigRequest" => Some(&[
+            Field {
+                name: "parent",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "configId",
+                required: true,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "config",
+                required: true,
+                shape: Shape::Single,
+                message: Some("TaskPushNotificationConfig"),
+            },
+            Field {
+                name: "tenant",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "SubscribeToTaskRequest" => Some(&[
+            Field {
+                name: "name",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "tenant",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "ListTaskPushNotificationConfigRequest" => Some(&[
+            Field {
+                name: "parent",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "pageSize",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "pageToken",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+            Field {
+                name: "tenant",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        "GetExtendedAgentCardRequest" => Some(&[Field {
+            name: "tenant",
+            required: false,
+            shape: Shape::Single,
+            message: None,
+        }]),
+        "SendMessageResponse" => Some(&[
+            Field {
+                name: "task",
+                required: false,
+                shape: Shape::Single,
+                message: Some("Task"),
+            },
+            Field {
+                name: "message",
+                required: false,
+                shape: Shape::Single,
+                message: Some("Message"),
+            },
+        ]),
+        "StreamResponse" => Some(&[
+            Field {
+                name: "task",
+                required: false,
+                shape: Shape::Single,
+                message: Some("Task"),
+            },
+            Field {
+                name: "message",
+                required: false,
+                shape: Shape::Single,
+                message: Some("Message"),
+            },
+            Field {
+                name: "statusUpdate",
+                required: false,
+                shape: Shape::Single,
+                message: Some("TaskStatusUpdateEvent"),
+            },
+            Field {
+                name: "artifactUpdate",
+                required: false,
+                shape: Shape::Single,
+                message: Some("TaskArtifactUpdateEvent"),
+            },
+        ]),
+        "ListTaskPushNotificationConfigResponse" => Some(&[
+            Field {
+                name: "configs",
+                required: false,
+                shape: Shape::List,
+                message: Some("TaskPushNotificationConfig"),
+            },
+            Field {
+                name: "nextPageToken",
+                required: false,
+                shape: Shape::Single,
+                message: None,
+            },
+        ]),
+        _ => None,
+    }
+}
@@ -0,0 +1,286 @@
+//! gRPC wire format of the `a2a.v1.A2AService` service.
+//!
+//! Messages are framed per the gRPC over HTTP/2 wire format: a 1-byte
+//! compression flag, a 4-byte big-endian length and the protobuf payload.
+//! The status of a call travels in the `grpc-status` and percent-encoded
+//! `grpc-message` trailers. Clients and servers share the framing and the
+//! status codes defined here.
+
+use prost::Message;
+use thiserror::Error;
+
+/// Fully-qualified name of the A2A gRPC service.
+pub const SERVICE: &str = "a2a.v1.A2AService";
+
+/// Length of the gRPC message prefix (compression flag + length).
+pub const PREFIX_LEN: usize = 5;
+
+/// Content type of gRPC requests and responses.
+pub const CONTENT_TYPE: &str = "application/grpc+proto";
+
+/// gRPC status codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusCode {
+    Ok,
+    Cancelled,
+    Unknown,
+    InvalidArgument,
+    DeadlineExceeded,
+    NotFound,
+    AlreadyExists,
+    PermissionDenied,
+    ResourceExhausted,
+    FailedPrecondition,
+    Aborted,
+    OutOfRange,
+    Unimplemented,
+    Internal,
+    Unavailable,
+    DataLoss,
+    Unauthenticated,
+}
+
+impl StatusCode {
+    pub fn from_code(code: i32) -> Self {
+        match code {
+            0 => Self::Ok,
+            1 => Self::Cancelled,
+            3 => Self::InvalidArgument,
+            4 => Self::DeadlineExceeded,
+            5 => Self::NotFound,
+            6 => Self::AlreadyExists,
+            7 => Self::PermissionDenied,
+            8 => Self::ResourceExhausted,
+            9 => Self::FailedPrecondition,
+            10 => Self::Aborted,
+            11 => Self::OutOfRange,
+            12 => Self::Unimplemented,
+            13 => Self::Internal,
+            14 => Self::Unavailable,
+            15 => Self::DataLoss,
+            16 => Self::Unauthenticated,
+            _ => Self::Unknown,
+        }
+    }
+
+    pub fn code(&self) -> i32 {
+        match self {
+            Self::Ok => 0,
+            Self::Cancelled => 1,
+            Self::Unknown => 2,
+            Self::InvalidArgument => 3,
+            Self::DeadlineExceeded => 4,
+            Self::NotFound => 5,
+            Self::AlreadyExists => 6,
+            Self::PermissionDenied => 7,
+            Self::ResourceExhausted => 8,
+            Self::FailedPrecondition => 9,
+            Self::Aborted => 10,
+            Self::OutOfRange => 11,
+            Self::Unimplemented => 12,
+            Self::Internal => 13,
+            Self::Unavailable => 14,
+            Self::DataLoss => 15,
+            Self::Unauthenticated => 16,
+        }
+    }
+}
+
+impl std::fmt::Display for StatusCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// Malformed gRPC framing.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FrameError {
+    #[error("compressed gRPC messages are not supported")]
+    Compressed,
+
+    #[error("truncated gRPC frame")]
+    Truncated,
+
+    #[error("gRPC frame length mismatch")]
+    LengthMismatch,
+}
+
+impl FrameError {
+    /// The status to answer a call with this error.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Compressed => StatusCode::Unimplemented,
+            Self::Truncated | Self::LengthMismatch => StatusCode::InvalidArgument,
+        }
+    }
+}
+
+/// Encode a message as a single uncompressed gRPC frame.
+pub fn encode_frame(message: &impl Message) -> Vec<u8> {
+    let len = message.encoded_len();
+    let mut frame = Vec::with_capacity(PREFIX_LEN + len);
+    frame.push(0);
+    frame.extend_from_slice(&(len as u32).to_be_bytes());
+    message
+        .encode(&mut frame)
+        .expect("Vec<u8> grows to fit the encoded message");
+    frame
+}
+
+/// Read the payload length from a frame prefix.
+fn payload_len(prefix: &[u8]) -> Result<usize, FrameError> {
+    if prefix[0] != 0 {
+        return Err(FrameError::Compressed);
+    }
+    Ok(u32::from_be_bytes([prefix[1], prefix[2], prefix[3], prefix[4]]) as usize)
+}
+
+/// Split a body holding exactly one frame into its payload.
+pub fn decode_frame(body: &[u8]) -> Result<&[u8], FrameError> {
+    let (prefix, payload) = body
+        .split_at_checked(PREFIX_LEN)
+        .ok_or(FrameError::Truncated)?;
+    if payload.len() != payload_len(prefix)? {
+        return Err(FrameError::LengthMismatch);
+    }
+    Ok(payload)
+}
+
+/// Incremental decoder splitting a byte stream into message payloads.
+#[derive(Debug, Default)]
+pub struct FrameDecoder {
+    buffer: Vec<u8>,
+}
+
+impl FrameDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed bytes into the decoder and return any complete message payloads.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<Vec<Vec<u8>>, FrameError> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut payloads = Vec::new();
+        while self.buffer.len() >= PREFIX_LEN {
+            let len = payload_len(&self.buffer)?;
+            if self.buffer.len() < PREFIX_LEN + len {
+                break;
+            }
+            payloads.push(self.buffer[PREFIX_LEN..PREFIX_LEN + len].to_vec());
+            self.buffer.drain(..PREFIX_LEN + len);
+        }
+        Ok(payloads)
+    }
+
+    /// Whether a partial frame is still buffered.
+    pub fn has_partial_frame(&self) -> bool {
+        !self.buffer.is_empty()
+    }
+}
+
+/// Percent-encode a `grpc-message` value.
+pub fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if (0x20..0x7f).contains(&byte) && byte != b'%' {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// Decode a percent-encoded `grpc-message` value.
+pub fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(hex) = bytes.get(i + 1..i + 3)
+            && let Some(byte) = std::str::from_utf8(hex)
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        {
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GetTaskRequest, Task};
+
+    #[test]
+    fn test_frame_round_trip() {
+        let request = GetTaskRequest {
+            name: "tasks/task-1".to_string(),
+            ..Default::default()
+        };
+        let frame = encode_frame(&request);
+        assert_eq!(frame[0], 0);
+        assert_eq!(frame.len(), PREFIX_LEN + request.encoded_len());
+
+        let payload = decode_frame(&frame).unwrap();
+        assert_eq!(GetTaskRequest::decode(payload).unwrap(), request);
+    }
+
+    #[test]
+    fn test_decode_frame_errors() {
+        let frame = encode_frame(&Task::default());
+        assert_eq!(decode_frame(&frame[..3]), Err(FrameError::Truncated));
+        let mut long = encode_frame(&GetTaskRequest {
+            name: "tasks/task-1".to_string(),
+            ..Default::default()
+        });
+        long.push(0);
+        assert_eq!(decode_frame(&long), Err(FrameError::LengthMismatch));
+        assert_eq!(decode_frame(&[1, 0, 0, 0, 0]), Err(FrameError::Compressed));
+    }
+
+    #[test]
+    fn test_decoder_handles_split_frames() {
+        let task = Task {
+            id: "task-1".to_string(),
+            ..Default::default()
+        };
+        let mut bytes = encode_frame(&task);
+        bytes.extend(encode_frame(&task));
+
+        let mut decoder = FrameDecoder::new();
+        assert!(decoder.feed(&bytes[..3]).unwrap().is_empty());
+        assert!(decoder.has_partial_frame());
+        let payloads = decoder.feed(&bytes[3..]).unwrap();
+        assert_eq!(payloads.len(), 2);
+        assert!(!decoder.has_partial_frame());
+    }
+
+    #[test]
+    fn test_decoder_rejects_compressed() {
+        let mut decoder = FrameDecoder::new();
+        assert_eq!(decoder.feed(&[1, 0, 0, 0, 0]), Err(FrameError::Compressed));
+    }
+
+    #[test]
+    fn test_status_code_round_trip() {
+        for code in 0..=16 {
+            assert_eq!(StatusCode::from_code(code).code(), code);
+        }
+        assert_eq!(StatusCode::from_code(99), StatusCode::Unknown);
+    }
+
+    #[test]
+    fn test_percent_encoding() {
+        assert_eq!(percent_encode("task 100% done\n"), "task 100%25 done%0A");
+        assert_eq!(percent_decode("task%20not%20found"), "task not found");
+        assert_eq!(percent_decode(&percent_encode("ünïcode %")), "ünïcode %");
+    }
+}
@@ -0,0 +1,157 @@
+//! JSON Canonicalization Scheme (RFC 8785).
+//!
+//! Agent card signatures are computed over the canonical form of the card
+//! with its `signatures` member removed; see [`card_signing_payload`].
+
+use serde_json::Value;
+use std::fmt::Write;
+
+/// Serialize a JSON value in canonical form.
+///
+/// Object members are sorted by their UTF-16 code units, no insignificant
+/// whitespace is emitted, and numbers use the ECMAScript formatting rules.
+pub fn canonicalize(value: &Value) -> String {
+    let mut out = String::new();
+    write_value(value, &mut out);
+    out
+}
+
+/// Canonical bytes an agent card signature covers.
+///
+/// `card` is the card's JSON object as published; the `signatures` member is
+/// excluded.
+pub fn card_signing_payload(card: &Value) -> Vec<u8> {
+    match card {
+        Value::Object(members) => {
+            let mut members = members.clone();
+            members.remove("signatures");
+            canonicalize(&Value::Object(members)).into_bytes()
+        }
+        other => canonicalize(other).into_bytes(),
+    }
+}
+
+fn write_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => write_number(n, out),
+        Value::String(s) => write_string(s, out),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(members) => {
+            let mut entries: Vec<_> = members.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(key, out);
+                out.push(':');
+                write_value(item, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_number(n: &serde_json::Number, out: &mut String) {
+    if let Some(i) = n.as_i64() {
+        let _ = write!(out, "{i}");
+    } else if let Some(u) = n.as_u64() {
+        let _ = write!(out, "{u}");
+    } else if let Some(f) = n.as_f64() {
+        write_f64(f, out);
+    }
+}
+
+/// Format a float like ECMAScript's `Number.prototype.toString`.
+fn write_f64(f: f64, out: &mut String) {
+    if f == 0.0 {
+        out.push('0');
+        return;
+    }
+    let abs = f.abs();
+    if (1e-6..1e21).contains(&abs) {
+        // Rust's shortest round-trip formatting matches ECMAScript here.
+        let _ = write!(out, "{f}");
+        return;
+    }
+    // Exponent form: Rust prints `1e21` / `1e-7`, ECMAScript `1e+21` / `1e-7`.
+    let formatted = format!("{f:e}");
+    match formatted.split_once('e') {
+        Some((mantissa, exp)) if !exp.starts_with('-') => {
+            let _ = write!(out, "{mantissa}e+{exp}");
+        }
+        _ => out.push_str(&formatted),
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c < '\u{20}' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_sorts_keys_and_strips_whitespace() {
+        let value = json!({"b": [1, {"z": true, "a": null}], "a": "x"});
+        assert_eq!(
+            canonicalize(&value),
+            r#"{"a":"x","b":[1,{"a":null,"z":true}]}"#
+        );
+    }
+
+    #[test]
+    fn test_sorts_by_utf16_code_units() {
+        // U+10000 encodes as a surrogate pair (0xD800...), below U+E000.
+        let value = json!({"\u{e000}": 1, "\u{10000}": 2});
+        assert_eq!(canonicalize(&value), "{\"\u{10000}\":2,\"\u{e000}\":1}");
+    }
+
+    #[test]
+    fn test_escapes_strings() {
+        let value = json!("tab\tquote\"\u{1f}é");
+        assert_eq!(canonicalize(&value), r#""tab\tquote\"\u001fé""#);
+    }
+
+    #[test]
+    fn test_number_formatting() {
+        let value = json!([1.5, 1e21, 1e-7, -0.0, 100.0, 42]);
+        assert_eq!(canonicalize(&value), "[1.5,1e+21,1e-7,0,100,42]");
+    }
+
+    #[test]
+    fn test_card_signing_payload_drops_signatures() {
+        let card = json!({"name": "agent", "signatures": [{"protected": "x"}]});
+        assert_eq!(card_signing_payload(&card), br#"{"name":"agent"}"#);
+    }
+}
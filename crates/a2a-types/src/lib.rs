@@ -1,51 +1,91 @@
 //! A2A Protocol type definitions.
 
+pub mod card;
+pub mod codec;
 pub mod error;
+pub mod extension;
 mod generated;
+pub mod grpc;
+pub mod jcs;
+pub mod message;
+pub mod metadata;
+pub mod name;
+pub mod schema;
+pub mod version;
 
 // Re-export all generated types at the crate root
 pub use generated::a2a::v1::*;
+pub use name::{PushConfigName, TaskName};
 
 use serde::{Deserialize, Serialize};
 
-/// Strongly-typed task identifier.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct TaskId(pub String);
+/// Define a strongly-typed string identifier.
+macro_rules! define_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(pub String);
 
-impl TaskId {
-    pub fn new(id: impl Into<String>) -> Self {
-        Self(id.into())
-    }
+        impl $name {
+            pub fn new(id: impl Into<String>) -> Self {
+                Self(id.into())
+            }
 
-    pub fn as_str(&self) -> &str {
-        &self.0
-    }
-}
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
 
-impl std::fmt::Display for TaskId {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.0.fmt(f)
-    }
-}
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
 
-impl From<String> for TaskId {
-    fn from(s: String) -> Self {
-        Self(s)
-    }
-}
+        impl From<String> for $name {
+            fn from(s: String) -> Self {
+                Self(s)
+            }
+        }
 
-impl From<&str> for TaskId {
-    fn from(s: &str) -> Self {
-        Self(s.to_string())
-    }
+        impl From<&str> for $name {
+            fn from(s: &str) -> Self {
+                Self(s.to_string())
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+    };
 }
 
+define_id!(
+    /// Strongly-typed task identifier.
+    TaskId
+);
+define_id!(
+    /// Strongly-typed context identifier.
+    ContextId
+);
+define_id!(
+    /// Strongly-typed message identifier.
+    MessageId
+);
+define_id!(
+    /// Strongly-typed artifact identifier.
+    ArtifactId
+);
+
 /// Protocol binding type for client/server communication.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Binding {
     JsonRpc,
     Rest,
+    Grpc,
 }
 
 impl std::fmt::Display for Binding {
@@ -53,6 +93,7 @@ impl std::fmt::Display for Binding {
         match self {
             Binding::JsonRpc => write!(f, "JSONRPC"),
             Binding::Rest => write!(f, "HTTP+JSON"),
+            Binding::Grpc => write!(f, "GRPC"),
         }
     }
 }
@@ -73,7 +114,7 @@ impl From<ProtocolBinding> for Option<Binding> {
         match pb {
             ProtocolBinding::JsonRpc => Some(Binding::JsonRpc),
             ProtocolBinding::Rest => Some(Binding::Rest),
-            ProtocolBinding::Grpc => None, // Not supported
+            ProtocolBinding::Grpc => Some(Binding::Grpc),
         }
     }
 }
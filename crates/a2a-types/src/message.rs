@@ -0,0 +1,374 @@
+//! Constructors and accessors for messages, parts and artifacts.
+//!
+//! The generated types mirror the proto, so even a one-line text message
+//! nests several `Option`s. These helpers cover the common cases:
+//!
+//! ```
+//! use a2a_types::{Message, Part, SendMessageRequest};
+//!
+//! let message = Message::user_text("hello").with_context_id("ctx-1");
+//! assert_eq!(message.text(), "hello");
+//!
+//! let request = SendMessageRequest::from(message);
+//! assert!(request.request.is_some());
+//!
+//! let part = Part::file_uri("https://example.com/a.png", "image/png");
+//! assert!(part.as_file().is_some());
+//! ```
+
+use crate::error::{Error, Result};
+use crate::metadata;
+use crate::{
+    Artifact, ArtifactId, ContextId, DataPart, FilePart, Message, MessageId, Part, Role,
+    SendMessageRequest, SendMessageResponse, StreamResponse, Task, TaskArtifactUpdateEvent, TaskId,
+    TaskState, TaskStatusUpdateEvent, file_part, part, send_message_response, stream_response,
+};
+
+/// Generate a random (v4 UUID) identifier.
+fn new_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// Join the text parts of `parts`, one per line.
+fn join_text(parts: &[Part]) -> String {
+    parts
+        .iter()
+        .filter_map(Part::as_text)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Message {
+    /// Create a message with a random message ID.
+    pub fn new(role: Role, parts: impl IntoIterator<Item = Part>) -> Self {
+        Self {
+            message_id: new_id(),
+            role: role.into(),
+            parts: parts.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Create a user message with a single text part.
+    pub fn user_text(text: impl Into<String>) -> Self {
+        Self::new(Role::User, [Part::text(text)])
+    }
+
+    /// Create an agent message with a single text part.
+    pub fn agent_text(text: impl Into<String>) -> Self {
+        Self::new(Role::Agent, [Part::text(text)])
+    }
+
+    pub fn with_message_id(mut self, message_id: impl Into<MessageId>) -> Self {
+        self.message_id = message_id.into().0;
+        self
+    }
+
+    pub fn with_context_id(mut self, context_id: impl Into<ContextId>) -> Self {
+        self.context_id = context_id.into().0;
+        self
+    }
+
+    pub fn with_task_id(mut self, task_id: impl Into<TaskId>) -> Self {
+        self.task_id = task_id.into().0;
+        self
+    }
+
+    pub fn with_part(mut self, part: Part) -> Self {
+        self.parts.push(part);
+        self
+    }
+
+    /// The message's text parts, one per line.
+    pub fn text(&self) -> String {
+        join_text(&self.parts)
+    }
+}
+
+impl Part {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            part: Some(part::Part::Text(text.into())),
+            ..Default::default()
+        }
+    }
+
+    /// A file part referring to `uri`.
+    pub fn file_uri(uri: impl Into<String>, media_type: impl Into<String>) -> Self {
+        Self::file(file_part::File::FileWithUri(uri.into()), media_type.into())
+    }
+
+    /// A file part carrying its content inline.
+    pub fn file_bytes(bytes: impl Into<Vec<u8>>, media_type: impl Into<String>) -> Self {
+        Self::file(
+            file_part::File::FileWithBytes(bytes.into()),
+            media_type.into(),
+        )
+    }
+
+    fn file(file: file_part::File, media_type: String) -> Self {
+        Self {
+            part: Some(part::Part::File(FilePart {
+                media_type,
+                file: Some(file),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+
+    /// A data part holding `value`, which must be a JSON object.
+    pub fn data(value: serde_json::Value) -> Result<Self> {
+        if !value.is_object() {
+            return Err(Error::InvalidPartType(format!(
+                "data part must be a JSON object, got {value}"
+            )));
+        }
+        Ok(Self {
+            part: Some(part::Part::Data(DataPart {
+                data: Some(metadata::json_to_struct(value)?),
+            })),
+            ..Default::default()
+        })
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match &self.part {
+            Some(part::Part::Text(text)) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_file(&self) -> Option<&FilePart> {
+        match &self.part {
+            Some(part::Part::File(file)) => Some(file),
+            _ => None,
+        }
+    }
+
+    /// The content of a data part as a JSON object.
+    pub fn as_data(&self) -> Option<serde_json::Value> {
+        match &self.part {
+            Some(part::Part::Data(DataPart { data })) => Some(
+                data.as_ref()
+                    .map(metadata::struct_to_json)
+                    .unwrap_or_else(|| serde_json::Value::Object(Default::default())),
+            ),
+            _ => None,
+        }
+    }
+}
+
+impl FilePart {
+    pub fn uri(&self) -> Option<&str> {
+        match &self.file {
+            Some(file_part::File::FileWithUri(uri)) => Some(uri),
+            _ => None,
+        }
+    }
+
+    pub fn bytes(&self) -> Option<&[u8]> {
+        match &self.file {
+            Some(file_part::File::FileWithBytes(bytes)) => Some(bytes),
+            _ => None,
+        }
+    }
+}
+
+impl Artifact {
+    /// Create an artifact with a random artifact ID.
+    pub fn new(parts: impl IntoIterator<Item = Part>) -> Self {
+        Self {
+            artifact_id: new_id(),
+            parts: parts.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_artifact_id(mut self, artifact_id: impl Into<ArtifactId>) -> Self {
+        self.artifact_id = artifact_id.into().0;
+        self
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// The artifact's text parts, one per line.
+    pub fn text(&self) -> String {
+        join_text(&self.parts)
+    }
+}
+
+impl Task {
+    /// The task's current state, `Unspecified` if it has no status.
+    pub fn state(&self) -> TaskState {
+        self.status
+            .as_ref()
+            .map_or(TaskState::Unspecified, |status| status.state())
+    }
+}
+
+impl SendMessageRequest {
+    pub fn new(message: Message) -> Self {
+        Self {
+            request: Some(message),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Message> for SendMessageRequest {
+    fn from(message: Message) -> Self {
+        Self::new(message)
+    }
+}
+
+impl SendMessageResponse {
+    pub fn as_task(&self) -> Option<&Task> {
+        match &self.payload {
+            Some(send_message_response::Payload::Task(task)) => Some(task),
+            _ => None,
+        }
+    }
+
+    pub fn as_message(&self) -> Option<&Message> {
+        match &self.payload {
+            Some(send_message_response::Payload::Msg(message)) => Some(message),
+            _ => None,
+        }
+    }
+
+    pub fn into_task(self) -> Option<Task> {
+        match self.payload {
+            Some(send_message_response::Payload::Task(task)) => Some(task),
+            _ => None,
+        }
+    }
+
+    pub fn into_message(self) -> Option<Message> {
+        match self.payload {
+            Some(send_message_response::Payload::Msg(message)) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl StreamResponse {
+    pub fn as_task(&self) -> Option<&Task> {
+        match &self.payload {
+            Some(stream_response::Payload::Task(task)) => Some(task),
+            _ => None,
+        }
+    }
+
+    pub fn as_message(&self) -> Option<&Message> {
+        match &self.payload {
+            Some(stream_response::Payload::Msg(message)) => Some(message),
+            _ => None,
+        }
+    }
+
+    pub fn as_status_update(&self) -> Option<&TaskStatusUpdateEvent> {
+        match &self.payload {
+            Some(stream_response::Payload::StatusUpdate(update)) => Some(update),
+            _ => None,
+        }
+    }
+
+    pub fn as_artifact_update(&self) -> Option<&TaskArtifactUpdateEvent> {
+        match &self.payload {
+            Some(stream_response::Payload::ArtifactUpdate(update)) => Some(update),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TaskStatus;
+    use serde_json::json;
+
+    #[test]
+    fn test_user_text_message() {
+        let message = Message::user_text("hello")
+            .with_context_id("ctx-1")
+            .with_part(Part::text("world"));
+        assert_eq!(message.role(), Role::User);
+        assert_eq!(message.context_id, "ctx-1");
+        assert!(!message.message_id.is_empty());
+        assert_eq!(message.text(), "hello\nworld");
+        assert_ne!(
+            message.message_id,
+            Message::user_text("hello").message_id,
+            "message IDs should be unique"
+        );
+
+        let json = serde_json::to_value(SendMessageRequest::from(message)).unwrap();
+        assert_eq!(json["message"]["role"], "ROLE_USER");
+        assert_eq!(json["message"]["parts"][0]["text"], "hello");
+    }
+
+    #[test]
+    fn test_file_parts() {
+        let part = Part::file_uri("https://example.com/a.png", "image/png");
+        let file = part.as_file().unwrap();
+        assert_eq!(file.uri(), Some("https://example.com/a.png"));
+        assert_eq!(file.media_type, "image/png");
+        assert_eq!(part.as_text(), None);
+
+        let part = Part::file_bytes(b"abc".to_vec(), "text/plain");
+        assert_eq!(part.as_file().unwrap().bytes(), Some(&b"abc"[..]));
+    }
+
+    #[test]
+    fn test_data_part() {
+        let value = json!({"answer": 42, "score": 0.5, "nested": {"ok": true}});
+        let part = Part::data(value.clone()).unwrap();
+        assert_eq!(part.as_data(), Some(value));
+        assert!(matches!(
+            Part::data(json!([1, 2])),
+            Err(Error::InvalidPartType(_))
+        ));
+    }
+
+    #[test]
+    fn test_artifact() {
+        let artifact = Artifact::new([Part::text("result")]).with_name("report");
+        assert!(!artifact.artifact_id.is_empty());
+        assert_eq!(artifact.name, "report");
+        assert_eq!(artifact.text(), "result");
+    }
+
+    #[test]
+    fn test_response_accessors() {
+        let task = Task {
+            id: "t1".to_string(),
+            status: Some(TaskStatus {
+                state: TaskState::Completed.into(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let response = SendMessageResponse {
+            payload: Some(send_message_response::Payload::Task(task)),
+        };
+        assert_eq!(response.as_task().unwrap().state(), TaskState::Completed);
+        assert!(response.as_message().is_none());
+        assert_eq!(response.into_task().unwrap().id, "t1");
+
+        let event = StreamResponse {
+            payload: Some(stream_response::Payload::Msg(Message::agent_text("hi"))),
+        };
+        assert_eq!(event.as_message().unwrap().text(), "hi");
+        assert!(event.as_status_update().is_none());
+        assert_eq!(Task::default().state(), TaskState::Unspecified);
+    }
+}
@@ -0,0 +1,246 @@
+//! Typed access to `google.protobuf.Struct` metadata.
+//!
+//! Metadata is a free-form JSON object. Whole-object access goes through
+//! [`HasMetadata::metadata_as`] and [`HasMetadata::set_metadata`]; A2A
+//! extensions instead keep their data under a key named after the extension
+//! URI, declared once as a [`MetadataKey`]:
+//!
+//! ```
+//! use a2a_types::Message;
+//! use a2a_types::metadata::{HasMetadata, MetadataKey};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Trace {
+//!     span_id: String,
+//! }
+//!
+//! const TRACE: MetadataKey<Trace> = MetadataKey::new("https://example.com/ext/trace/v1");
+//!
+//! let mut message = Message::user_text("hello");
+//! let trace = Trace { span_id: "abc".to_string() };
+//! message.insert_metadata(&TRACE, &trace).unwrap();
+//! assert_eq!(message.get_metadata(&TRACE).unwrap(), Some(trace));
+//! ```
+
+use crate::error::{Error, Result};
+use crate::{
+    Artifact, Message, Part, SendMessageRequest, Task, TaskArtifactUpdateEvent,
+    TaskStatusUpdateEvent,
+};
+use pbjson_types::Struct;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::marker::PhantomData;
+
+/// Largest integer a `google.protobuf.Value` double holds exactly.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+/// Convert a `Struct` to a JSON object.
+///
+/// `Struct` numbers are doubles; integral ones come back as JSON integers so
+/// they deserialize into integer fields.
+pub fn struct_to_json(value: &Struct) -> Value {
+    let mut json = serde_json::to_value(value).unwrap_or_default();
+    restore_integers(&mut json);
+    json
+}
+
+/// Convert a JSON object to a `Struct`.
+pub fn json_to_struct(value: Value) -> Result<Struct> {
+    if !value.is_object() {
+        return Err(Error::InvalidMessage(format!(
+            "metadata must be a JSON object, got {value}"
+        )));
+    }
+    Ok(serde_json::from_value(value)?)
+}
+
+fn restore_integers(value: &mut Value) {
+    match value {
+        Value::Number(number) => {
+            if let Some(float) = number.as_f64()
+                && float.fract() == 0.0
+                && float.abs() <= MAX_SAFE_INTEGER
+            {
+                *value = Value::from(float as i64);
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(restore_integers),
+        Value::Object(object) => object.values_mut().for_each(restore_integers),
+        _ => {}
+    }
+}
+
+/// A metadata key holding a value of type `T`, usually an extension URI.
+pub struct MetadataKey<T> {
+    key: &'static str,
+    value: PhantomData<fn() -> T>,
+}
+
+impl<T> MetadataKey<T> {
+    pub const fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            value: PhantomData,
+        }
+    }
+
+    pub const fn key(&self) -> &'static str {
+        self.key
+    }
+}
+
+/// Types with a `metadata` field.
+pub trait HasMetadata {
+    fn metadata_struct(&self) -> Option<&Struct>;
+
+    fn metadata_struct_mut(&mut self) -> &mut Option<Struct>;
+
+    /// Deserialize the whole metadata object; `None` if there is none.
+    fn metadata_as<T: DeserializeOwned>(&self) -> Result<Option<T>> {
+        self.metadata_struct()
+            .map(|metadata| Ok(serde_json::from_value(struct_to_json(metadata))?))
+            .transpose()
+    }
+
+    /// Replace the metadata with `value`, which must serialize to an object.
+    fn set_metadata<T: Serialize>(&mut self, value: &T) -> Result<()> {
+        *self.metadata_struct_mut() = Some(json_to_struct(serde_json::to_value(value)?)?);
+        Ok(())
+    }
+
+    /// Deserialize the value under `key`; `None` if it isn't set.
+    fn get_metadata<T: DeserializeOwned>(&self, key: &MetadataKey<T>) -> Result<Option<T>> {
+        let Some(value) = self
+            .metadata_struct()
+            .and_then(|metadata| metadata.fields.get(key.key()))
+        else {
+            return Ok(None);
+        };
+        let mut json = serde_json::to_value(value)?;
+        restore_integers(&mut json);
+        Ok(Some(serde_json::from_value(json)?))
+    }
+
+    /// Set the value under `key`, keeping the other keys.
+    fn insert_metadata<T: Serialize>(&mut self, key: &MetadataKey<T>, value: &T) -> Result<()> {
+        let value = serde_json::from_value(serde_json::to_value(value)?)?;
+        self.metadata_struct_mut()
+            .get_or_insert_with(Struct::default)
+            .fields
+            .insert(key.key().to_string(), value);
+        Ok(())
+    }
+
+    /// Remove the value under `key`, returning whether it was set.
+    fn remove_metadata<T>(&mut self, key: &MetadataKey<T>) -> bool {
+        self.metadata_struct_mut()
+            .as_mut()
+            .is_some_and(|metadata| metadata.fields.remove(key.key()).is_some())
+    }
+}
+
+macro_rules! impl_has_metadata {
+    ($($type:ty),* $(,)?) => {
+        $(
+            impl HasMetadata for $type {
+                fn metadata_struct(&self) -> Option<&Struct> {
+                    self.metadata.as_ref()
+                }
+
+                fn metadata_struct_mut(&mut self) -> &mut Option<Struct> {
+                    &mut self.metadata
+                }
+            }
+        )*
+    };
+}
+
+impl_has_metadata!(
+    Message,
+    Part,
+    Artifact,
+    Task,
+    TaskStatusUpdateEvent,
+    TaskArtifactUpdateEvent,
+    SendMessageRequest,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Usage {
+        input_tokens: u32,
+        output_tokens: u64,
+        model: String,
+    }
+
+    const USAGE: MetadataKey<Usage> = MetadataKey::new("https://example.com/ext/usage/v1");
+
+    #[test]
+    fn test_whole_metadata_round_trip() {
+        let mut task = Task::default();
+        assert_eq!(task.metadata_as::<Usage>().unwrap(), None);
+
+        let usage = Usage {
+            input_tokens: 12,
+            output_tokens: 345,
+            model: "m".to_string(),
+        };
+        task.set_metadata(&usage).unwrap();
+        assert_eq!(task.metadata_as::<Usage>().unwrap(), Some(usage));
+
+        // Survives the wire, where numbers are doubles.
+        let json = serde_json::to_string(&task).unwrap();
+        let task: Task = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            task.metadata_as::<Usage>().unwrap().unwrap().input_tokens,
+            12
+        );
+
+        assert!(matches!(
+            task.clone().set_metadata(&[1, 2]),
+            Err(Error::InvalidMessage(_))
+        ));
+    }
+
+    #[test]
+    fn test_extension_keys() {
+        let mut message = Message::user_text("hi");
+        message.set_metadata(&json!({"other": true})).unwrap();
+
+        let usage = Usage {
+            input_tokens: 1,
+            output_tokens: 2,
+            model: "m".to_string(),
+        };
+        message.insert_metadata(&USAGE, &usage).unwrap();
+        assert_eq!(message.get_metadata(&USAGE).unwrap(), Some(usage));
+
+        let json = serde_json::to_value(&message).unwrap();
+        assert_eq!(json["metadata"]["other"], true);
+        assert_eq!(
+            json["metadata"]["https://example.com/ext/usage/v1"]["model"],
+            "m"
+        );
+
+        assert!(message.remove_metadata(&USAGE));
+        assert!(!message.remove_metadata(&USAGE));
+        assert_eq!(message.get_metadata(&USAGE).unwrap(), None);
+    }
+
+    #[test]
+    fn test_mismatched_type_is_an_error() {
+        let mut artifact = Artifact::default();
+        artifact
+            .set_metadata(&json!({"https://example.com/ext/usage/v1": "oops"}))
+            .unwrap();
+        assert!(artifact.get_metadata(&USAGE).is_err());
+    }
+}
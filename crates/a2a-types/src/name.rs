@@ -0,0 +1,262 @@
+//! Typed resource names.
+//!
+//! Tasks are addressed as `tasks/{task_id}` and push notification configs as
+//! `tasks/{task_id}/pushNotificationConfigs/{config_id}`. Either may carry a
+//! tenant prefix, `{tenant}/tasks/{task_id}`, as used in REST paths.
+//!
+//! `Display` writes the name with its tenant prefix and `FromStr` accepts it
+//! with or without one; [`TaskName::resource_name`] gives the unprefixed
+//! form carried in the proto `name` fields, next to a separate `tenant`.
+
+use crate::TaskId;
+use crate::error::ConversionError;
+use std::fmt;
+use std::str::FromStr;
+
+const TASKS: &str = "tasks";
+const PUSH_CONFIGS: &str = "pushNotificationConfigs";
+
+/// Check a single name segment (tenant or ID).
+fn validate_segment(kind: &str, segment: &str) -> Result<(), ConversionError> {
+    if segment.is_empty() {
+        return Err(ConversionError::new(format!("empty {kind}")));
+    }
+    if let Some(c) = segment.chars().find(|c| matches!(c, '/' | ':' | '?' | '#')) {
+        return Err(ConversionError::new(format!(
+            "invalid character {c:?} in {kind} `{segment}`"
+        )));
+    }
+    Ok(())
+}
+
+/// Split an optional tenant off `segments`, which must then start with `tasks`.
+fn split_tenant<'a>(segments: &'a [&'a str]) -> (Option<&'a str>, &'a [&'a str]) {
+    match segments {
+        [tenant, TASKS, rest @ ..] if *tenant != TASKS || rest.len() % 2 == 1 => {
+            (Some(tenant), &segments[1..])
+        }
+        _ => (None, segments),
+    }
+}
+
+/// Name of a task: `[{tenant}/]tasks/{task_id}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TaskName {
+    tenant: Option<String>,
+    task_id: TaskId,
+}
+
+impl TaskName {
+    pub fn new(task_id: impl Into<TaskId>) -> Self {
+        Self {
+            tenant: None,
+            task_id: task_id.into(),
+        }
+    }
+
+    /// Scope the name to a tenant; an empty tenant means none.
+    pub fn with_tenant(mut self, tenant: impl Into<String>) -> Self {
+        let tenant = tenant.into();
+        self.tenant = (!tenant.is_empty()).then_some(tenant);
+        self
+    }
+
+    pub fn tenant(&self) -> Option<&str> {
+        self.tenant.as_deref()
+    }
+
+    pub fn task_id(&self) -> &TaskId {
+        &self.task_id
+    }
+
+    /// The name without its tenant prefix, e.g. `tasks/abc`.
+    pub fn resource_name(&self) -> String {
+        format!("{TASKS}/{}", self.task_id)
+    }
+
+    /// Check that the task ID and tenant are valid name segments.
+    pub fn validate(&self) -> Result<(), ConversionError> {
+        if let Some(tenant) = &self.tenant {
+            validate_segment("tenant", tenant)?;
+        }
+        validate_segment("task ID", self.task_id.as_str())
+    }
+
+    /// Name of one of this task's push notification configs.
+    pub fn push_config(&self, config_id: impl Into<String>) -> PushConfigName {
+        PushConfigName {
+            task: self.clone(),
+            config_id: config_id.into(),
+        }
+    }
+}
+
+impl fmt::Display for TaskName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(tenant) = &self.tenant {
+            write!(f, "{tenant}/")?;
+        }
+        write!(f, "{TASKS}/{}", self.task_id)
+    }
+}
+
+impl FromStr for TaskName {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let segments: Vec<&str> = s.trim_start_matches('/').split('/').collect();
+        match split_tenant(&segments) {
+            (tenant, [TASKS, task_id]) => {
+                let name = Self {
+                    tenant: tenant.map(str::to_string),
+                    task_id: TaskId::new(*task_id),
+                };
+                name.validate()?;
+                Ok(name)
+            }
+            _ => Err(ConversionError::new(format!("invalid task name: {s}"))),
+        }
+    }
+}
+
+/// Name of a push notification config:
+/// `[{tenant}/]tasks/{task_id}/pushNotificationConfigs/{config_id}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PushConfigName {
+    task: TaskName,
+    config_id: String,
+}
+
+impl PushConfigName {
+    pub fn new(task: TaskName, config_id: impl Into<String>) -> Self {
+        task.push_config(config_id)
+    }
+
+    pub fn task(&self) -> &TaskName {
+        &self.task
+    }
+
+    pub fn tenant(&self) -> Option<&str> {
+        self.task.tenant()
+    }
+
+    pub fn config_id(&self) -> &str {
+        &self.config_id
+    }
+
+    /// The name without its tenant prefix.
+    pub fn resource_name(&self) -> String {
+        format!(
+            "{}/{PUSH_CONFIGS}/{}",
+            self.task.resource_name(),
+            self.config_id
+        )
+    }
+
+    /// Name of the collection the config belongs to, the `parent` of
+    /// create and list requests.
+    pub fn parent(&self) -> String {
+        format!("{}/{PUSH_CONFIGS}", self.task.resource_name())
+    }
+
+    /// Check that every segment of the name is valid.
+    pub fn validate(&self) -> Result<(), ConversionError> {
+        self.task.validate()?;
+        validate_segment("config ID", &self.config_id)
+    }
+}
+
+impl fmt::Display for PushConfigName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{PUSH_CONFIGS}/{}", self.task, self.config_id)
+    }
+}
+
+impl FromStr for PushConfigName {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let segments: Vec<&str> = s.trim_start_matches('/').split('/').collect();
+        match split_tenant(&segments) {
+            (tenant, [TASKS, task_id, PUSH_CONFIGS, config_id]) => {
+                let name = Self {
+                    task: TaskName {
+                        tenant: tenant.map(str::to_string),
+                        task_id: TaskId::new(*task_id),
+                    },
+                    config_id: config_id.to_string(),
+                };
+                name.validate()?;
+                Ok(name)
+            }
+            _ => Err(ConversionError::new(format!(
+                "invalid push notification config name: {s}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_name_round_trip() {
+        let name: TaskName = "tasks/abc".parse().unwrap();
+        assert_eq!(name, TaskName::new("abc"));
+        assert_eq!(name.tenant(), None);
+        assert_eq!(name.to_string(), "tasks/abc");
+
+        let name: TaskName = "/acme/tasks/abc".parse().unwrap();
+        assert_eq!(name.tenant(), Some("acme"));
+        assert_eq!(name.task_id().as_str(), "abc");
+        assert_eq!(name.to_string(), "acme/tasks/abc");
+        assert_eq!(name.resource_name(), "tasks/abc");
+    }
+
+    #[test]
+    fn test_tenant_named_tasks() {
+        let name: TaskName = "tasks/tasks/abc".parse().unwrap();
+        assert_eq!(name.tenant(), Some("tasks"));
+        assert_eq!(name.task_id().as_str(), "abc");
+    }
+
+    #[test]
+    fn test_invalid_task_names() {
+        for name in [
+            "",
+            "tasks/",
+            "abc",
+            "tasks/a/b",
+            "tasks/a:cancel",
+            "t/x/tasks/a",
+        ] {
+            assert!(name.parse::<TaskName>().is_err(), "{name} parsed");
+        }
+        assert!(TaskName::new("a?b").validate().is_err());
+    }
+
+    #[test]
+    fn test_push_config_name() {
+        let name: PushConfigName = "acme/tasks/t1/pushNotificationConfigs/c1".parse().unwrap();
+        assert_eq!(name.tenant(), Some("acme"));
+        assert_eq!(name.task().task_id().as_str(), "t1");
+        assert_eq!(name.config_id(), "c1");
+        assert_eq!(name.resource_name(), "tasks/t1/pushNotificationConfigs/c1");
+        assert_eq!(name.parent(), "tasks/t1/pushNotificationConfigs");
+        assert_eq!(
+            TaskName::new("t1")
+                .with_tenant("acme")
+                .push_config("c1")
+                .to_string(),
+            "acme/tasks/t1/pushNotificationConfigs/c1"
+        );
+
+        assert!(
+            "tasks/t1/pushNotificationConfigs/"
+                .parse::<PushConfigName>()
+                .is_err()
+        );
+        assert!("tasks/t1/configs/c1".parse::<PushConfigName>().is_err());
+    }
+}
@@ -0,0 +1,170 @@
+//! JSON Schema of the A2A types.
+//!
+//! The schema is generated by the build script from the same proto
+//! descriptors as the Rust types, and describes exactly what they serialize:
+//! lowerCamelCase field names, enum values as strings (`"ROLE_USER"`),
+//! 64-bit integers as decimal strings and bytes as base64. Fields marked
+//! `REQUIRED` in the proto are required, and each oneof requires exactly one
+//! of its members.
+//!
+//! The generated document is also checked in as
+//! `src/generated/a2a/v1.schema.json` for use outside Rust.
+
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+
+/// The JSON Schema (draft 2020-12) document, with every type under `$defs`.
+///
+/// Types are keyed by their proto name without the `a2a.v1` package, e.g.
+/// `Message` or `AgentCard`; well-known types keep their full name, e.g.
+/// `google.protobuf.Struct`.
+pub const SCHEMA: &str = include_str!("generated/a2a/v1.schema.json");
+
+/// The full schema document.
+pub fn schema() -> Value {
+    serde_json::from_str(SCHEMA).expect("generated schema is valid JSON")
+}
+
+/// A standalone schema for one type, e.g. `schema_for("Task")`.
+///
+/// The type is referenced from the root and only the definitions it depends
+/// on are kept. Returns `None` for an unknown type.
+pub fn schema_for(type_name: &str) -> Option<Value> {
+    let mut document = schema();
+    let Some(Value::Object(defs)) = document.get_mut("$defs").map(Value::take) else {
+        return None;
+    };
+    if !defs.contains_key(type_name) {
+        return None;
+    }
+
+    let mut used = BTreeSet::new();
+    let mut pending = vec![type_name.to_string()];
+    while let Some(name) = pending.pop() {
+        if used.insert(name.clone())
+            && let Some(def) = defs.get(&name)
+        {
+            collect_refs(def, &mut pending);
+        }
+    }
+    let defs: Map<String, Value> = defs
+        .into_iter()
+        .filter(|(name, _)| used.contains(name))
+        .collect();
+
+    Some(serde_json::json!({
+        "$schema": document["$schema"],
+        "$ref": def_ref(type_name),
+        "$defs": defs,
+    }))
+}
+
+fn def_ref(name: &str) -> String {
+    format!("#/$defs/{name}")
+}
+
+/// Push the names of the definitions `value` references onto `names`.
+fn collect_refs(value: &Value, names: &mut Vec<String>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                match (key.as_str(), value) {
+                    ("$ref", Value::String(reference)) => {
+                        if let Some(name) = reference.strip_prefix("#/$defs/") {
+                            names.push(name.to_string());
+                        }
+                    }
+                    _ => collect_refs(value, names),
+                }
+            }
+        }
+        Value::Array(values) => values.iter().for_each(|value| collect_refs(value, names)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Message, SendMessageRequest};
+
+    #[test]
+    fn test_properties_match_serialization() {
+        let request = SendMessageRequest {
+            tenant: "acme".to_string(),
+            metadata: Some(Default::default()),
+            ..SendMessageRequest::from(
+                Message::user_text("hello")
+                    .with_context_id("ctx-1")
+                    .with_task_id("task-1"),
+            )
+        };
+        let json = serde_json::to_value(&request).unwrap();
+
+        let schema = schema();
+        let defs = &schema["$defs"];
+        for (def, value) in [
+            ("SendMessageRequest", &json),
+            ("Message", &json["message"]),
+            ("Part", &json["message"]["parts"][0]),
+        ] {
+            let properties = defs[def]["properties"].as_object().unwrap();
+            for key in value.as_object().unwrap().keys() {
+                assert!(properties.contains_key(key), "{def} lacks {key}");
+            }
+        }
+        assert_eq!(json["message"]["role"], "ROLE_USER");
+        assert!(
+            defs["Role"]["enum"]
+                .as_array()
+                .unwrap()
+                .contains(&json["message"]["role"])
+        );
+    }
+
+    #[test]
+    fn test_encoding_details() {
+        let schema = schema();
+        let defs = &schema["$defs"];
+        assert_eq!(
+            defs["ListTasksRequest"]["properties"]["lastUpdatedAfter"]["type"],
+            "string"
+        );
+        assert_eq!(
+            defs["FilePart"]["properties"]["fileWithBytes"]["contentEncoding"],
+            "base64"
+        );
+        assert_eq!(
+            defs["SendMessageResponse"]["oneOf"][1]["required"][0],
+            "message"
+        );
+
+        let required = defs["AgentCard"]["required"].as_array().unwrap();
+        assert!(required.contains(&"name".into()));
+        assert!(
+            !defs["Message"]["description"]
+                .as_str()
+                .unwrap()
+                .contains("--8<--")
+        );
+    }
+
+    #[test]
+    fn test_schema_for() {
+        let task = schema_for("Task").unwrap();
+        assert_eq!(task["$ref"], "#/$defs/Task");
+        let defs = task["$defs"].as_object().unwrap();
+        for name in [
+            "Task",
+            "TaskStatus",
+            "Message",
+            "Part",
+            "google.protobuf.Value",
+        ] {
+            assert!(defs.contains_key(name), "missing {name}");
+        }
+        assert!(!defs.contains_key("AgentCard"));
+
+        assert!(schema_for("Nope").is_none());
+    }
+}
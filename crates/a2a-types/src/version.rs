@@ -0,0 +1,485 @@
+//! Protocol versions and their JSON-RPC dialects.
+//!
+//! Agents on the 0.2 and 0.3 specifications use slash-separated method names
+//! (`message/send`, `tasks/get`, ...), lowercase enum values and
+//! `kind`-tagged objects; member names are camelCase in every version.
+//! [`JsonRpcMethod`] maps method names between versions and converts params
+//! and results between the legacy dialect and the v1 JSON the generated
+//! types read and write.
+
+use crate::{AgentCard, TaskName};
+use serde_json::{Map, Value};
+
+/// A2A protocol version an agent implements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProtocolVersion {
+    V0_2,
+    V0_3,
+    V1,
+}
+
+impl ProtocolVersion {
+    /// Parse a version string such as `"0.3.0"` or `"1.0"`.
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.trim().split('.');
+        match (parts.next()?, parts.next()) {
+            ("0", Some("2")) => Some(Self::V0_2),
+            ("0", Some("3")) => Some(Self::V0_3),
+            ("1", _) => Some(Self::V1),
+            _ => None,
+        }
+    }
+
+    /// Version declared by an agent card.
+    ///
+    /// Cards without a recognized `protocolVersion` are assumed to be v1.
+    pub fn of(card: &AgentCard) -> Self {
+        card.protocol_version
+            .as_deref()
+            .and_then(Self::parse)
+            .unwrap_or(Self::V1)
+    }
+
+    pub fn dialect(self) -> Dialect {
+        match self {
+            Self::V0_2 | Self::V0_3 => Dialect::Legacy,
+            Self::V1 => Dialect::V1,
+        }
+    }
+}
+
+impl std::fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::V0_2 => write!(f, "0.2"),
+            Self::V0_3 => write!(f, "0.3"),
+            Self::V1 => write!(f, "1.0"),
+        }
+    }
+}
+
+/// JSON-RPC wire dialect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    /// 0.2/0.3: `message/send`, lowercase enums, `kind`-tagged results.
+    Legacy,
+    /// 1.0: `SendMessage`, proto3 JSON.
+    V1,
+}
+
+/// A2A JSON-RPC methods known in every dialect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonRpcMethod {
+    SendMessage,
+    SendStreamingMessage,
+    GetTask,
+    CancelTask,
+    SubscribeToTask,
+}
+
+impl JsonRpcMethod {
+    const ALL: [Self; 5] = [
+        Self::SendMessage,
+        Self::SendStreamingMessage,
+        Self::GetTask,
+        Self::CancelTask,
+        Self::SubscribeToTask,
+    ];
+
+    /// Method name in a dialect.
+    pub fn name(self, dialect: Dialect) -> &'static str {
+        match (dialect, self) {
+            (Dialect::V1, Self::SendMessage) => "SendMessage",
+            (Dialect::V1, Self::SendStreamingMessage) => "SendStreamingMessage",
+            (Dialect::V1, Self::GetTask) => "GetTask",
+            (Dialect::V1, Self::CancelTask) => "CancelTask",
+            (Dialect::V1, Self::SubscribeToTask) => "SubscribeToTask",
+            (Dialect::Legacy, Self::SendMessage) => "message/send",
+            (Dialect::Legacy, Self::SendStreamingMessage) => "message/stream",
+            (Dialect::Legacy, Self::GetTask) => "tasks/get",
+            (Dialect::Legacy, Self::CancelTask) => "tasks/cancel",
+            (Dialect::Legacy, Self::SubscribeToTask) => "tasks/resubscribe",
+        }
+    }
+
+    /// Look up a method name of either dialect.
+    pub fn from_name(name: &str) -> Option<(Self, Dialect)> {
+        [Dialect::V1, Dialect::Legacy]
+            .into_iter()
+            .find_map(|dialect| {
+                Self::ALL
+                    .into_iter()
+                    .find(|method| method.name(dialect) == name)
+                    .map(|method| (method, dialect))
+            })
+    }
+
+    fn targets_task(self) -> bool {
+        matches!(
+            self,
+            Self::GetTask | Self::CancelTask | Self::SubscribeToTask
+        )
+    }
+
+    /// Convert v1 params to the legacy dialect.
+    pub fn params_to_legacy(self, params: Value) -> Value {
+        let mut params = to_legacy(params);
+        // Legacy task methods address tasks by ID rather than resource name.
+        if self.targets_task()
+            && let Some(members) = params.as_object_mut()
+            && let Some(Value::String(name)) = members.remove("name")
+        {
+            let id = match name.parse::<TaskName>() {
+                Ok(name) => name.task_id().to_string(),
+                Err(_) => name,
+            };
+            members.entry("id").or_insert(Value::String(id));
+        }
+        params
+    }
+
+    /// Convert legacy params to v1.
+    pub fn params_from_legacy(self, params: Value) -> Value {
+        let mut params = from_legacy(params);
+        if self.targets_task()
+            && let Some(members) = params.as_object_mut()
+            && !members.contains_key("name")
+            && let Some(Value::String(id)) = members.remove("id")
+        {
+            let name = TaskName::new(id).to_string();
+            members.insert("name".to_string(), Value::String(name));
+        }
+        params
+    }
+
+    /// Convert a v1 result to the legacy dialect.
+    ///
+    /// Legacy results are bare `kind`-tagged objects rather than v1's
+    /// single-member wrappers (`{"task": ...}`).
+    pub fn result_to_legacy(self, result: Value) -> Value {
+        let result = match (self, result) {
+            (Self::GetTask | Self::CancelTask, Value::Object(mut task)) => {
+                task.insert("kind".to_string(), Value::from("task"));
+                Value::Object(task)
+            }
+            (_, Value::Object(members)) if members.len() == 1 => {
+                let (key, value) = members.into_iter().next().expect("one member");
+                match (EVENT_KINDS.iter().find(|(_, v1)| *v1 == key), value) {
+                    (Some((kind, _)), Value::Object(mut event)) => {
+                        event.insert("kind".to_string(), Value::from(*kind));
+                        Value::Object(event)
+                    }
+                    (_, value) => Value::Object(Map::from_iter([(key, value)])),
+                }
+            }
+            (_, result) => result,
+        };
+        to_legacy(result)
+    }
+
+    /// Convert a legacy result to v1.
+    pub fn result_from_legacy(self, result: Value) -> Value {
+        let result = from_legacy(result);
+        if matches!(self, Self::GetTask | Self::CancelTask) {
+            return result;
+        }
+        let kind = result.get("kind").and_then(Value::as_str);
+        match EVENT_KINDS.iter().find(|(legacy, _)| Some(*legacy) == kind) {
+            Some((_, v1)) => Value::Object(Map::from_iter([(v1.to_string(), result)])),
+            None => result,
+        }
+    }
+}
+
+/// Legacy `kind` tags and the v1 members that carry the same objects.
+const EVENT_KINDS: [(&str, &str); 4] = [
+    ("task", "task"),
+    ("message", "message"),
+    ("status-update", "statusUpdate"),
+    ("artifact-update", "artifactUpdate"),
+];
+
+/// Members whose values are user data and must not be rewritten.
+fn is_opaque(key: &str) -> bool {
+    matches!(key, "metadata" | "data" | "params")
+}
+
+fn to_legacy(value: Value) -> Value {
+    convert(value, Dialect::Legacy)
+}
+
+fn from_legacy(value: Value) -> Value {
+    convert(value, Dialect::V1)
+}
+
+fn convert(value: Value, to: Dialect) -> Value {
+    match value {
+        Value::Array(items) => Value::Array(items.into_iter().map(|v| convert(v, to)).collect()),
+        Value::Object(members) => {
+            let mut out = Map::new();
+            for (key, value) in members {
+                let value = match (key.as_str(), value) {
+                    (key, value) if is_opaque(key) => value,
+                    ("role", Value::String(role)) => Value::String(convert_role(&role, to)),
+                    ("state", Value::String(state)) => Value::String(convert_state(&state, to)),
+                    ("parts", Value::Array(parts)) => Value::Array(
+                        parts
+                            .into_iter()
+                            .map(|part| convert_part(convert(part, to), to))
+                            .collect(),
+                    ),
+                    (_, value) => convert(value, to),
+                };
+                out.insert(key, value);
+            }
+            Value::Object(out)
+        }
+        other => other,
+    }
+}
+
+fn convert_role(role: &str, to: Dialect) -> String {
+    match (to, role) {
+        (Dialect::Legacy, "ROLE_USER") => "user".to_string(),
+        (Dialect::Legacy, "ROLE_AGENT") => "agent".to_string(),
+        (Dialect::V1, "user") => "ROLE_USER".to_string(),
+        (Dialect::V1, "agent") => "ROLE_AGENT".to_string(),
+        (_, other) => other.to_string(),
+    }
+}
+
+fn convert_state(state: &str, to: Dialect) -> String {
+    match to {
+        Dialect::Legacy => match state.strip_prefix("TASK_STATE_") {
+            Some(state) => state.to_ascii_lowercase().replace('_', "-"),
+            None => state.to_string(),
+        },
+        Dialect::V1 if state.starts_with("TASK_STATE_") => state.to_string(),
+        Dialect::V1 => format!(
+            "TASK_STATE_{}",
+            state.to_ascii_uppercase().replace('-', "_")
+        ),
+    }
+}
+
+/// Convert between legacy `kind`-tagged parts and v1 `oneof` parts.
+///
+/// Legacy file parts carry `uri`/`bytes`/`mimeType` where v1 has
+/// `fileWithUri`/`fileWithBytes`/`mediaType`.
+fn convert_part(part: Value, to: Dialect) -> Value {
+    let Value::Object(mut part) = part else {
+        return part;
+    };
+    const FILE_FIELDS: [(&str, &str); 3] = [
+        ("uri", "fileWithUri"),
+        ("bytes", "fileWithBytes"),
+        ("mimeType", "mediaType"),
+    ];
+    if let Some(Value::Object(file)) = part.get_mut("file") {
+        for (legacy, v1) in FILE_FIELDS {
+            let (from, into) = match to {
+                Dialect::Legacy => (v1, legacy),
+                Dialect::V1 => (legacy, v1),
+            };
+            if let Some(value) = file.remove(from) {
+                file.insert(into.to_string(), value);
+            }
+        }
+    }
+    match to {
+        Dialect::Legacy => {
+            if let Some(kind) = ["text", "file", "data"]
+                .into_iter()
+                .find(|kind| part.contains_key(*kind))
+            {
+                part.insert("kind".to_string(), Value::from(kind));
+            }
+            // Legacy data parts carry the payload directly.
+            if let Some(Value::Object(data)) = part.get_mut("data")
+                && let Some(payload) = data.remove("data")
+            {
+                part.insert("data".to_string(), payload);
+            }
+        }
+        Dialect::V1 => {
+            if part
+                .remove("kind")
+                .and_then(|k| k.as_str().map(str::to_string))
+                == Some("data".to_string())
+                && let Some(payload) = part.remove("data")
+            {
+                part.insert(
+                    "data".to_string(),
+                    Value::Object(Map::from_iter([("data".to_string(), payload)])),
+                );
+            }
+        }
+    }
+    Value::Object(part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_versions() {
+        assert_eq!(ProtocolVersion::parse("0.2.5"), Some(ProtocolVersion::V0_2));
+        assert_eq!(ProtocolVersion::parse("0.3.0"), Some(ProtocolVersion::V0_3));
+        assert_eq!(ProtocolVersion::parse("1.0"), Some(ProtocolVersion::V1));
+        assert_eq!(ProtocolVersion::parse("0.1"), None);
+        assert_eq!(
+            ProtocolVersion::of(&AgentCard::default()),
+            ProtocolVersion::V1
+        );
+    }
+
+    #[test]
+    fn test_method_names() {
+        assert_eq!(
+            JsonRpcMethod::from_name("tasks/get"),
+            Some((JsonRpcMethod::GetTask, Dialect::Legacy))
+        );
+        assert_eq!(
+            JsonRpcMethod::from_name("SendMessage"),
+            Some((JsonRpcMethod::SendMessage, Dialect::V1))
+        );
+        assert_eq!(JsonRpcMethod::from_name("tasks/list"), None);
+        assert_eq!(
+            JsonRpcMethod::SendStreamingMessage.name(Dialect::Legacy),
+            "message/stream"
+        );
+    }
+
+    #[test]
+    fn test_send_params_round_trip() {
+        let v1 = json!({
+            "message": {
+                "messageId": "m1",
+                "role": "ROLE_USER",
+                "parts": [
+                    {"text": "hi"},
+                    {"data": {"data": {"someKey": 1}}},
+                    {"file": {"fileWithUri": "https://x/y.png", "mediaType": "image/png"}}
+                ],
+                "metadata": {"traceId": "t"}
+            },
+            "configuration": {"acceptedOutputModes": ["text/plain"], "historyLength": 2}
+        });
+
+        let legacy = JsonRpcMethod::SendMessage.params_to_legacy(v1.clone());
+        assert_eq!(
+            legacy,
+            json!({
+                "message": {
+                    "messageId": "m1",
+                    "role": "user",
+                    "parts": [
+                        {"kind": "text", "text": "hi"},
+                        {"kind": "data", "data": {"someKey": 1}},
+                        {"kind": "file", "file": {"uri": "https://x/y.png", "mimeType": "image/png"}}
+                    ],
+                    "metadata": {"traceId": "t"}
+                },
+                "configuration": {"acceptedOutputModes": ["text/plain"], "historyLength": 2}
+            })
+        );
+        assert_eq!(JsonRpcMethod::SendMessage.params_from_legacy(legacy), v1);
+    }
+
+    /// The `message/send` example of the 0.3 specification.
+    #[test]
+    fn test_spec_0_3_send_message() {
+        let params = json!({
+            "message": {
+                "role": "user",
+                "parts": [
+                    {"kind": "text", "text": "tell me a joke"},
+                    {"kind": "file", "file": {"name": "joke.png", "mimeType": "image/png", "bytes": "iVBORw0KGgo="}}
+                ],
+                "messageId": "9229e770-767c-417b-a0b0-f0741243c589"
+            },
+            "metadata": {}
+        });
+        let v1 = JsonRpcMethod::SendMessage.params_from_legacy(params.clone());
+        let request: crate::SendMessageRequest = serde_json::from_value(v1.clone()).unwrap();
+        let message = request.request.unwrap();
+        assert_eq!(message.message_id, "9229e770-767c-417b-a0b0-f0741243c589");
+        assert_eq!(message.parts.len(), 2);
+        assert_eq!(JsonRpcMethod::SendMessage.params_to_legacy(v1), params);
+
+        let result = json!({
+            "id": "363422be-b0f9-4692-a24d-278670e7c7f1",
+            "contextId": "c295ea44-7543-4f78-b524-7a38915ad6e4",
+            "status": {"state": "completed"},
+            "artifacts": [{
+                "artifactId": "9b6934dd-37e3-4eb1-8766-962efaab63a1",
+                "name": "joke",
+                "parts": [{"kind": "text", "text": "Why did the chicken cross the road? To get to the other side!"}]
+            }],
+            "history": [{
+                "role": "user",
+                "parts": [{"kind": "text", "text": "tell me a joke"}],
+                "messageId": "9229e770-767c-417b-a0b0-f0741243c589",
+                "taskId": "363422be-b0f9-4692-a24d-278670e7c7f1",
+                "contextId": "c295ea44-7543-4f78-b524-7a38915ad6e4"
+            }],
+            "kind": "task",
+            "metadata": {}
+        });
+        let v1 = JsonRpcMethod::SendMessage.result_from_legacy(result);
+        let response: crate::SendMessageResponse = serde_json::from_value(v1).unwrap();
+        let Some(crate::send_message_response::Payload::Task(task)) = response.payload else {
+            panic!("expected a task");
+        };
+        assert_eq!(task.context_id, "c295ea44-7543-4f78-b524-7a38915ad6e4");
+        assert_eq!(
+            task.artifacts[0].artifact_id,
+            "9b6934dd-37e3-4eb1-8766-962efaab63a1"
+        );
+        assert_eq!(
+            task.history[0].task_id,
+            "363422be-b0f9-4692-a24d-278670e7c7f1"
+        );
+    }
+
+    #[test]
+    fn test_task_params_use_ids() {
+        let legacy = JsonRpcMethod::GetTask
+            .params_to_legacy(json!({"name": "tasks/t1", "historyLength": 3}));
+        assert_eq!(legacy, json!({"id": "t1", "historyLength": 3}));
+        assert_eq!(
+            JsonRpcMethod::GetTask.params_from_legacy(legacy),
+            json!({"name": "tasks/t1", "historyLength": 3})
+        );
+    }
+
+    #[test]
+    fn test_results_are_kind_tagged() {
+        let v1 = json!({"task": {"id": "t1", "contextId": "c1", "status": {"state": "TASK_STATE_INPUT_REQUIRED"}}});
+        let legacy = JsonRpcMethod::SendMessage.result_to_legacy(v1.clone());
+        assert_eq!(
+            legacy,
+            json!({"kind": "task", "id": "t1", "contextId": "c1", "status": {"state": "input-required"}})
+        );
+
+        let upgraded = JsonRpcMethod::SendMessage.result_from_legacy(legacy);
+        assert_eq!(
+            upgraded["task"]["status"]["state"],
+            "TASK_STATE_INPUT_REQUIRED"
+        );
+        assert_eq!(upgraded["task"]["contextId"], "c1");
+
+        let event = JsonRpcMethod::SubscribeToTask
+            .result_from_legacy(json!({"kind": "status-update", "taskId": "t1", "final": true}));
+        assert_eq!(event["statusUpdate"]["taskId"], "t1");
+
+        let task = JsonRpcMethod::GetTask
+            .result_to_legacy(json!({"id": "t1", "status": {"state": "TASK_STATE_WORKING"}}));
+        assert_eq!(
+            task,
+            json!({"kind": "task", "id": "t1", "status": {"state": "working"}})
+        );
+    }
+}
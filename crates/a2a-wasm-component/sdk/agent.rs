@@ -0,0 +1,23 @@
+// Defaults of the `Agent` traits of a2a-wasm-host and a2a-agent-guest, which
+// include this file next to their bindings of the WIT `Error` record.
+
+/// JSON-RPC error code for operations the agent doesn't support.
+const UNSUPPORTED_OPERATION: i32 = -32004;
+
+/// The error of the default `on_message_stream`.
+fn message_stream_unsupported() -> Error {
+    unsupported("Streaming messages")
+}
+
+/// The error of the default `on_subscribe_task`.
+fn subscribe_task_unsupported() -> Error {
+    unsupported("Task subscriptions")
+}
+
+fn unsupported(operation: &str) -> Error {
+    Error {
+        code: UNSUPPORTED_OPERATION,
+        message: format!("{operation} are not supported by this agent"),
+        data: None,
+    }
+}
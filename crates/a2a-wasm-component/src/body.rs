@@ -0,0 +1,72 @@
+//! Body size limits.
+//!
+//! Request bodies are read into memory before they are decoded, so they are
+//! capped at a maximum size: larger requests fail with 413 without being
+//! read in full. Response bodies the client reads from remote agents are
+//! capped at the same size. The limit defaults to 1 MiB and is set in bytes
+//! with the `A2A_MAX_BODY_SIZE` environment variable. An invalid value isn't
+//! replaced by the default: requests fail with 500 until it is fixed.
+
+use crate::rest::header;
+use crate::wasi::http::types::IncomingRequest;
+use a2a_transport_wasi::{DEFAULT_MAX_BODY_SIZE, IncomingBodyStream, WasiError, block_on};
+
+/// Environment variable setting the maximum body size, in bytes.
+pub const MAX_SIZE_ENV: &str = "A2A_MAX_BODY_SIZE";
+
+/// The configured maximum body size.
+pub fn max_size() -> Result<usize, String> {
+    parse_max_size(std::env::var(MAX_SIZE_ENV).ok().as_deref())
+}
+
+/// Parse a maximum body size, defaulting if unset or empty.
+fn parse_max_size(value: Option<&str>) -> Result<usize, String> {
+    match value.map(str::trim).filter(|value| !value.is_empty()) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("Invalid {MAX_SIZE_ENV} `{value}`: expected a size in bytes")),
+        None => Ok(DEFAULT_MAX_BODY_SIZE),
+    }
+}
+
+/// Read the request body, failing with 413 if it exceeds the maximum size.
+pub fn read_request(request: &IncomingRequest) -> Result<Vec<u8>, (u16, String)> {
+    let max_size = max_size().map_err(|e| (500, e))?;
+    let too_large = || (413, format!("Request body exceeds {max_size} bytes"));
+
+    // Reject declared oversized bodies without reading them
+    let content_length = header(request, "content-length").and_then(|v| v.trim().parse().ok());
+    if content_length.is_some_and(|length: usize| length > max_size) {
+        return Err(too_large());
+    }
+
+    let body = request
+        .consume()
+        .map_err(|_| (500, "Failed to consume request body".to_string()))?;
+    // SAFETY: the handle is taken from the component's own binding of the
+    // same `wasi:http` resource, and ownership moves with it
+    let body = unsafe { wasi::http::types::IncomingBody::from_handle(body.take_handle()) };
+
+    let mut stream = IncomingBodyStream::new(body)
+        .map_err(|_| (500, "Failed to get body stream".to_string()))?
+        .with_max_size(max_size);
+    block_on(stream.read_to_end()).map_err(|e| match e {
+        WasiError::BodyTooLarge { .. } => too_large(),
+        _ => (500, "Failed to read body".to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_max_size() {
+        assert_eq!(parse_max_size(None), Ok(DEFAULT_MAX_BODY_SIZE));
+        assert_eq!(parse_max_size(Some("65536")), Ok(65536));
+        assert_eq!(parse_max_size(Some(" 10 ")), Ok(10));
+        assert_eq!(parse_max_size(Some("")), Ok(DEFAULT_MAX_BODY_SIZE));
+        assert!(parse_max_size(Some("1MB")).is_err());
+        assert!(parse_max_size(Some("-1")).is_err());
+    }
+}
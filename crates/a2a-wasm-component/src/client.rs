@@ -1,190 +1,293 @@
 //! Client interface implementation.
 //!
 //! This module implements the A2A client interface for WASM components,
-//! allowing them to send messages to and query tasks from A2A agents.
+//! allowing them to send messages to, query tasks from and stream events of
+//! A2A agents.
 
 use crate::convert;
-use crate::exports::a2a::protocol::client::{Error, MessageSendParams, SendResponse, Task};
+use crate::exports::a2a::protocol::client::{
+    Error, GuestEventStream, ListTaskPushNotificationConfigResponse, ListTasksParams,
+    ListTasksResponse, MessageSendParams, PushNotificationConfig, SendResponse, StreamResponse,
+    Task, TaskPushNotificationConfig,
+};
 
-use a2a_client::Client;
-use a2a_transport_wasi::WasiHttpClient;
+use a2a_client::{Client, OwnedEventStream};
+use a2a_transport_wasi::{WasiHttpClient, block_on};
+use a2a_types::{PushConfigName, TaskName};
+use futures_core::Stream;
+use std::cell::{Cell, RefCell};
+use std::pin::Pin;
+use std::sync::Arc;
 
 /// Error codes for client operations.
 const ERROR_CODE_TRANSPORT: i32 = -32000;
 const ERROR_CODE_CONVERSION: i32 = -32001;
-/// Error code returned by server when a task is not found.
-/// Note: This uses the same value as ERROR_CODE_CONVERSION (-32001) because
-/// the A2A specification uses -32001 for both conversion errors and task-not-found.
-const ERROR_CODE_TASK_NOT_FOUND: i32 = -32001;
+const ERROR_CODE_INTERNAL: i32 = -32603;
 
-/// Maps an a2a_client error to a WIT Error.
+/// Maps an a2a_client error to a WIT Error, keeping JSON-RPC error data.
 fn map_client_error(e: a2a_client::Error) -> Error {
     match e {
         a2a_client::Error::Agent {
             message,
-            source: a2a_client::ProtocolError::JsonRpc { code, .. },
+            source: a2a_client::ProtocolError::JsonRpc { code, data, .. },
         } => Error {
             code: code.code(),
             message,
+            data: data.as_ref().map(convert::json_value_from_json),
         },
         a2a_client::Error::Transport(msg) => Error {
             code: ERROR_CODE_TRANSPORT,
             message: msg,
+            data: None,
         },
         other => Error {
             code: ERROR_CODE_TRANSPORT,
             message: other.to_string(),
+            data: None,
         },
     }
 }
 
-/// Simple blocking executor for async operations.
-///
-/// Since WASI pollables handle the actual blocking internally,
-/// a simple poll loop works for converting async to sync.
-fn block_on<F: std::future::Future>(future: F) -> F::Output {
-    use std::pin::pin;
-    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
-
-    fn noop_clone(_: *const ()) -> RawWaker {
-        RawWaker::new(std::ptr::null(), &VTABLE)
-    }
-    fn noop(_: *const ()) {}
-
-    static VTABLE: RawWakerVTable = RawWakerVTable::new(noop_clone, noop, noop, noop);
-    let raw_waker = RawWaker::new(std::ptr::null(), &VTABLE);
-    // SAFETY: The vtable functions are valid (all no-ops) and the waker is used
-    // only within this function's scope, so it cannot outlive the vtable.
-    let waker = unsafe { Waker::from_raw(raw_waker) };
-    let mut cx = Context::from_waker(&waker);
-
-    let mut future = pin!(future);
-    loop {
-        match future.as_mut().poll(&mut cx) {
-            Poll::Ready(result) => return result,
-            Poll::Pending => {}
-        }
+/// A WIT Error for a failed type conversion.
+fn conversion_error(message: String) -> Error {
+    Error {
+        code: ERROR_CODE_CONVERSION,
+        message,
+        data: None,
     }
 }
 
+/// Parse a task resource name ("tasks/{task_id}").
+fn task_name(name: &str) -> Result<TaskName, Error> {
+    name.parse()
+        .map_err(|e| conversion_error(format!("Invalid task name: {e}")))
+}
+
+/// Parse a push config resource name
+/// ("tasks/{task_id}/pushNotificationConfigs/{config_id}").
+fn push_config_name(name: &str) -> Result<PushConfigName, Error> {
+    name.parse()
+        .map_err(|e| conversion_error(format!("Invalid push config name: {e}")))
+}
+
+/// Create an A2A client for the agent (discovery is async).
+fn connect(agent_url: &str) -> Result<Client<WasiHttpClient>, Error> {
+    let max_body_size = crate::body::max_size().map_err(|message| Error {
+        code: ERROR_CODE_INTERNAL,
+        message,
+        data: None,
+    })?;
+    let http_client = WasiHttpClient::new().with_max_body_size(max_body_size);
+    block_on(Client::connect(http_client, agent_url)).map_err(map_client_error)
+}
+
 /// Send a message to an A2A agent.
 ///
 /// Creates an HTTP client and A2A client, converts the WIT params to a2a-types,
-/// makes the JSON-RPC call, and converts the response back to WIT types.
+/// sends them on the negotiated binding, and converts the response back to
+/// WIT types.
 pub fn send_message(agent_url: String, params: MessageSendParams) -> Result<SendResponse, Error> {
-    // Convert WIT params to a2a-types
-    let a2a_params = convert::message_send_params_to_a2a(params).map_err(|e| Error {
-        code: ERROR_CODE_CONVERSION,
-        message: e,
-    })?;
+    let a2a_params = convert::message_send_params_to_a2a(params).map_err(conversion_error)?;
+    let client = connect(&agent_url)?;
 
-    // Create HTTP client and A2A client (discovery is async)
-    let http_client = WasiHttpClient::new();
-    let client = block_on(Client::connect(http_client, &agent_url)).map_err(map_client_error)?;
-
-    // Make the RPC call
-    let result: Result<a2a_types::SendMessageResponse, _> =
-        block_on(client.rpc("SendMessage", &a2a_params));
-
-    match result {
-        Ok(response) => {
-            // Convert response back to WIT types
-            convert::send_response_from_a2a(&response).map_err(|e| Error {
-                code: ERROR_CODE_CONVERSION,
-                message: e,
-            })
-        }
-        Err(e) => Err(map_client_error(e)),
-    }
+    let response = block_on(client.send_message(a2a_params)).map_err(map_client_error)?;
+
+    convert::send_response_from_a2a(&response).map_err(conversion_error)
 }
 
 /// Get a task from an A2A agent.
 ///
-/// Returns `Ok(None)` if the task is not found (error code -32001).
+/// Returns `Ok(None)` if the task is not found.
 /// name: Resource name in format "tasks/{task_id}"
 pub fn get_task(
     agent_url: String,
     name: String,
     history_length: Option<u32>,
 ) -> Result<Option<Task>, Error> {
-    // Create the query params (now uses GetTaskRequest)
-    let params = a2a_types::GetTaskRequest {
-        tenant: String::new(),
-        name,
-        history_length: history_length.map(|v| v as i32),
-    };
+    let name = task_name(&name)?;
+    let client = connect(&agent_url)?;
 
-    // Create HTTP client and A2A client (discovery is async)
-    let http_client = WasiHttpClient::new();
-    let client = block_on(Client::connect(http_client, &agent_url)).map_err(map_client_error)?;
-
-    // Make the RPC call
-    let result: Result<a2a_types::Task, _> = block_on(client.rpc("GetTask", &params));
-
-    match result {
-        Ok(task) => {
-            // Convert task to WIT type
-            let wit_task = convert::task_from_a2a(&task).map_err(|e| Error {
-                code: ERROR_CODE_CONVERSION,
-                message: e,
-            })?;
-            Ok(Some(wit_task))
-        }
-        Err(ref e)
-            if matches!(
-                e,
-                a2a_client::Error::Agent {
-                    source: a2a_client::ProtocolError::JsonRpc { code, .. },
-                    ..
-                } if code.code() == ERROR_CODE_TASK_NOT_FOUND
-            ) =>
-        {
-            // Task not found
-            Ok(None)
-        }
-        Err(e) => Err(map_client_error(e)),
-    }
+    let task =
+        block_on(client.get_task(name.task_id(), history_length)).map_err(map_client_error)?;
+
+    task.map(|task| convert::task_from_a2a(&task).map_err(conversion_error))
+        .transpose()
 }
 
 /// Cancel a task on an A2A agent.
 ///
-/// Returns `Ok(None)` if the task is not found (error code -32001).
+/// Returns `Ok(None)` if the task is not found.
 /// name: Resource name in format "tasks/{task_id}"
 pub fn cancel_task(agent_url: String, name: String) -> Result<Option<Task>, Error> {
-    // Create the cancel params (now uses CancelTaskRequest)
-    let params = a2a_types::CancelTaskRequest {
+    let name = task_name(&name)?;
+    let client = connect(&agent_url)?;
+
+    let task = block_on(client.cancel_task(name.task_id())).map_err(map_client_error)?;
+
+    task.map(|task| convert::task_from_a2a(&task).map_err(conversion_error))
+        .transpose()
+}
+
+/// Get the agent card an A2A agent publishes, as JSON.
+pub fn get_agent_card(agent_url: String) -> Result<String, Error> {
+    let client = connect(&agent_url)?;
+
+    serde_json::to_string(client.agent_card()).map_err(|e| conversion_error(e.to_string()))
+}
+
+/// Send a message to an A2A agent, streaming its events.
+///
+/// Uses the binding negotiated from the agent card.
+pub fn send_streaming_message(
+    agent_url: String,
+    params: MessageSendParams,
+) -> Result<EventStream, Error> {
+    let a2a_params = convert::message_send_params_to_a2a(params).map_err(conversion_error)?;
+    let client = Arc::new(connect(&agent_url)?);
+
+    let events =
+        block_on(client.send_streaming_message_owned(a2a_params)).map_err(map_client_error)?;
+    Ok(EventStream::new(events))
+}
+
+/// List the tasks of an A2A agent.
+pub fn list_tasks(agent_url: String, params: ListTasksParams) -> Result<ListTasksResponse, Error> {
+    let params = convert::list_tasks_params_to_a2a(params);
+    let client = connect(&agent_url)?;
+
+    let response = block_on(client.list_tasks(params)).map_err(map_client_error)?;
+
+    convert::list_tasks_response_from_a2a(&response).map_err(conversion_error)
+}
+
+/// Subscribe to the events of an existing task.
+///
+/// name: Resource name in format "tasks/{task_id}"
+pub fn subscribe_to_task(agent_url: String, name: String) -> Result<EventStream, Error> {
+    let name = task_name(&name)?;
+    let client = Arc::new(connect(&agent_url)?);
+
+    let events = block_on(client.subscribe_to_task_owned(name.task_id().clone()))
+        .map_err(map_client_error)?;
+    Ok(EventStream::new(events))
+}
+
+/// Register a push notification config for a task.
+///
+/// parent: Resource name in format "tasks/{task_id}"
+pub fn set_task_push_notification_config(
+    agent_url: String,
+    parent: String,
+    config_id: String,
+    config: PushNotificationConfig,
+) -> Result<TaskPushNotificationConfig, Error> {
+    let name = PushConfigName::new(task_name(&parent)?, config_id.clone());
+    let params = a2a_types::SetTaskPushNotificationConfigRequest {
+        tenant: String::new(),
+        config: Some(a2a_types::TaskPushNotificationConfig {
+            name: name.to_string(),
+            push_notification_config: Some(convert::push_notification_config_to_a2a(config)),
+        }),
+        parent,
+        config_id,
+    };
+    let client = connect(&agent_url)?;
+
+    let config =
+        block_on(client.set_task_push_notification_config(params)).map_err(map_client_error)?;
+
+    convert::task_push_notification_config_from_a2a(&config).map_err(conversion_error)
+}
+
+/// Get a push notification config of a task.
+///
+/// Returns `Ok(None)` if the task or config is not found.
+/// name: Resource name in format "tasks/{task_id}/pushNotificationConfigs/{config_id}"
+pub fn get_task_push_notification_config(
+    agent_url: String,
+    name: String,
+) -> Result<Option<TaskPushNotificationConfig>, Error> {
+    let name = push_config_name(&name)?;
+    let client = connect(&agent_url)?;
+
+    let config =
+        block_on(client.get_task_push_notification_config(&name)).map_err(map_client_error)?;
+
+    config
+        .map(|config| {
+            convert::task_push_notification_config_from_a2a(&config).map_err(conversion_error)
+        })
+        .transpose()
+}
+
+/// List the push notification configs of a task.
+///
+/// parent: Resource name in format "tasks/{task_id}"
+pub fn list_task_push_notification_config(
+    agent_url: String,
+    parent: String,
+    page_size: Option<u32>,
+    page_token: Option<String>,
+) -> Result<ListTaskPushNotificationConfigResponse, Error> {
+    let params = a2a_types::ListTaskPushNotificationConfigRequest {
         tenant: String::new(),
-        name,
+        parent,
+        page_size: page_size.map_or(0, |v| v.min(i32::MAX as u32) as i32),
+        page_token: page_token.unwrap_or_default(),
     };
+    let client = connect(&agent_url)?;
+
+    let response =
+        block_on(client.list_task_push_notification_config(params)).map_err(map_client_error)?;
+
+    convert::list_task_push_notification_config_response_from_a2a(&response)
+        .map_err(conversion_error)
+}
 
-    // Create HTTP client and A2A client (discovery is async)
-    let http_client = WasiHttpClient::new();
-    let client = block_on(Client::connect(http_client, &agent_url)).map_err(map_client_error)?;
-
-    // Make the RPC call
-    let result: Result<a2a_types::Task, _> = block_on(client.rpc("CancelTask", &params));
-
-    match result {
-        Ok(task) => {
-            // Convert task to WIT type
-            let wit_task = convert::task_from_a2a(&task).map_err(|e| Error {
-                code: ERROR_CODE_CONVERSION,
-                message: e,
-            })?;
-            Ok(Some(wit_task))
+/// Delete a push notification config of a task.
+///
+/// name: Resource name in format "tasks/{task_id}/pushNotificationConfigs/{config_id}"
+pub fn delete_task_push_notification_config(agent_url: String, name: String) -> Result<(), Error> {
+    let name = push_config_name(&name)?;
+    let client = connect(&agent_url)?;
+
+    block_on(client.delete_task_push_notification_config(&name)).map_err(map_client_error)
+}
+
+/// Events of a streaming request, handed to the guest as the
+/// `event-stream` resource.
+pub struct EventStream {
+    events: RefCell<OwnedEventStream>,
+    /// Set once the stream returned its last event or an error.
+    ended: Cell<bool>,
+}
+
+impl EventStream {
+    fn new(events: OwnedEventStream) -> Self {
+        Self {
+            events: RefCell::new(events),
+            ended: Cell::new(false),
         }
-        Err(ref e)
-            if matches!(
-                e,
-                a2a_client::Error::Agent {
-                    source: a2a_client::ProtocolError::JsonRpc { code, .. },
-                    ..
-                } if code.code() == ERROR_CODE_TASK_NOT_FOUND
-            ) =>
-        {
-            // Task not found
-            Ok(None)
+    }
+}
+
+impl GuestEventStream for EventStream {
+    fn next(&self) -> Result<Option<StreamResponse>, Error> {
+        if self.ended.get() {
+            return Ok(None);
         }
-        Err(e) => Err(map_client_error(e)),
+        let mut events = self.events.borrow_mut();
+        let event = block_on(std::future::poll_fn(|cx| {
+            Pin::new(&mut *events).poll_next(cx)
+        }));
+        let result = match event {
+            Some(Ok(event)) => convert::stream_response_from_a2a(&event)
+                .map(Some)
+                .map_err(conversion_error),
+            Some(Err(e)) => Err(map_client_error(e)),
+            None => Ok(None),
+        };
+        self.ended.set(!matches!(result, Ok(Some(_))));
+        result
     }
 }
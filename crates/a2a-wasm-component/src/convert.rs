@@ -10,13 +10,78 @@
 // Both paths expose the same types, but we use the types module for foundational types.
 
 use crate::a2a::protocol::types::{
-    Artifact, Message, MessageSendConfig, Part, Role, TaskState, TaskStatus, TextPart,
+    Artifact, AuthenticationInfo, DataPart, FileContent, FilePart, JsonValue,
+    ListTaskPushNotificationConfigResponse, ListTasksParams, ListTasksResponse, Message,
+    MessageSendConfig, Metadata, Part, PushNotificationConfig, Role, StreamResponse,
+    TaskArtifactUpdateEvent, TaskPushNotificationConfig, TaskState, TaskStatus,
+    TaskStatusUpdateEvent, TextPart,
 };
 use crate::exports::a2a::protocol::client::{Error, MessageSendParams, SendResponse, Task};
+use serde_json::Value;
+
+/// Convert a WIT JsonValue to JSON.
+pub fn json_value_to_json(value: &JsonValue) -> Result<Value, String> {
+    match value {
+        JsonValue::Null => Ok(Value::Null),
+        JsonValue::Boolean(b) => Ok(Value::Bool(*b)),
+        JsonValue::Number(n) => serde_json::Number::from_f64(*n)
+            .map(Value::Number)
+            .ok_or_else(|| format!("{n} is not a valid JSON number")),
+        JsonValue::Str(s) => Ok(Value::String(s.clone())),
+        JsonValue::Array(text) | JsonValue::Object(text) => {
+            let json: Value =
+                serde_json::from_str(text).map_err(|e| format!("Invalid JSON value: {e}"))?;
+            match (value, &json) {
+                (JsonValue::Array(_), Value::Array(_))
+                | (JsonValue::Object(_), Value::Object(_)) => Ok(json),
+                _ => Err(format!(
+                    "JSON value {json} doesn't match its json-value case"
+                )),
+            }
+        }
+    }
+}
 
-// Re-export types used in tests
-#[cfg(test)]
-use crate::a2a::protocol::types::{DataPart, FileContent, FilePart};
+/// Convert JSON to a WIT JsonValue.
+pub fn json_value_from_json(value: &Value) -> JsonValue {
+    match value {
+        Value::Null => JsonValue::Null,
+        Value::Bool(b) => JsonValue::Boolean(*b),
+        Value::Number(n) => JsonValue::Number(n.as_f64().unwrap_or_default()),
+        Value::String(s) => JsonValue::Str(s.clone()),
+        Value::Array(_) => JsonValue::Array(value.to_string()),
+        Value::Object(_) => JsonValue::Object(value.to_string()),
+    }
+}
+
+/// Convert WIT metadata to an a2a-types `Struct`.
+pub fn metadata_to_a2a(
+    metadata: Option<&Metadata>,
+) -> Result<Option<pbjson_types::Struct>, String> {
+    let Some(entries) = metadata else {
+        return Ok(None);
+    };
+    let object = entries
+        .iter()
+        .map(|(key, value)| Ok((key.clone(), json_value_to_json(value)?)))
+        .collect::<Result<serde_json::Map<_, _>, String>>()?;
+    a2a_types::metadata::json_to_struct(Value::Object(object))
+        .map(Some)
+        .map_err(|e| e.to_string())
+}
+
+/// Convert an a2a-types `Struct` to WIT metadata.
+pub fn metadata_from_a2a(metadata: Option<&pbjson_types::Struct>) -> Option<Metadata> {
+    let Value::Object(object) = a2a_types::metadata::struct_to_json(metadata?) else {
+        return None;
+    };
+    Some(
+        object
+            .iter()
+            .map(|(key, value)| (key.clone(), json_value_from_json(value)))
+            .collect(),
+    )
+}
 
 /// Convert WIT Role to a2a-types Role (as i32 for prost enum).
 pub fn role_to_a2a(role: Role) -> i32 {
@@ -66,31 +131,50 @@ pub fn task_state_from_a2a(state: i32) -> TaskState {
 }
 
 /// Convert WIT Part to a2a-types Part.
-///
-/// Note: Only TextPart is fully implemented. FilePart and DataPart return errors.
 pub fn part_to_a2a(part: Part) -> Result<a2a_types::Part, String> {
-    match part {
-        Part::Text(text_part) => Ok(a2a_types::Part {
-            part: Some(a2a_types::part::Part::Text(text_part.text)),
-            metadata: None,
-        }),
-        Part::File(_) => Err("FilePart not implemented".to_string()),
-        Part::Data(_) => Err("DataPart not implemented".to_string()),
-    }
+    part_to_a2a_clone(&part)
 }
 
 /// Convert a2a-types Part to WIT Part.
 ///
-/// Note: Only TextPart is fully implemented. FilePart and DataPart return errors.
+/// Data parts become JSON text; file parts keep their name, media type and
+/// either URI or inline bytes.
 pub fn part_from_a2a(part: &a2a_types::Part) -> Result<Part, String> {
     match &part.part {
-        Some(a2a_types::part::Part::Text(text)) => Ok(Part::Text(TextPart { text: text.clone() })),
-        Some(a2a_types::part::Part::File(_)) => Err("FilePart not implemented".to_string()),
-        Some(a2a_types::part::Part::Data(_)) => Err("DataPart not implemented".to_string()),
+        Some(a2a_types::part::Part::Text(text)) => Ok(Part::Text(TextPart {
+            text: text.clone(),
+            metadata: metadata_from_a2a(part.metadata.as_ref()),
+        })),
+        Some(a2a_types::part::Part::File(file)) if file.file.is_none() => {
+            Err("FilePart has neither uri nor bytes".to_string())
+        }
+        Some(a2a_types::part::Part::File(file)) => Ok(Part::File(FilePart {
+            file: FileContent {
+                name: non_empty(&file.name),
+                mime_type: non_empty(&file.media_type),
+                uri: file.uri().map(str::to_string),
+                bytes: file.bytes().map(<[u8]>::to_vec),
+            },
+            metadata: metadata_from_a2a(part.metadata.as_ref()),
+        })),
+        Some(a2a_types::part::Part::Data(_)) => {
+            let data = part.as_data().unwrap_or_default();
+            Ok(Part::Data(DataPart {
+                data: data.to_string(),
+                // A v1 DataPart is always JSON and carries no media type
+                mime_type: None,
+                metadata: metadata_from_a2a(part.metadata.as_ref()),
+            }))
+        }
         None => Err("Part has no content".to_string()),
     }
 }
 
+/// `None` for an empty prost string field.
+fn non_empty(value: &str) -> Option<String> {
+    (!value.is_empty()).then(|| value.to_string())
+}
+
 /// Convert WIT Message to a2a-types Message.
 pub fn message_to_a2a(msg: Message) -> Result<a2a_types::Message, String> {
     let parts: Result<Vec<_>, _> = msg.parts.into_iter().map(part_to_a2a).collect();
@@ -104,7 +188,7 @@ pub fn message_to_a2a(msg: Message) -> Result<a2a_types::Message, String> {
         context_id: msg.context_id.unwrap_or_default(),
         reference_task_ids: vec![],
         extensions: vec![],
-        metadata: None,
+        metadata: metadata_to_a2a(msg.metadata.as_ref())?,
     })
 }
 
@@ -131,6 +215,7 @@ pub fn message_from_a2a(msg: &a2a_types::Message) -> Result<Message, String> {
         } else {
             Some(msg.context_id.clone())
         },
+        metadata: metadata_from_a2a(msg.metadata.as_ref()),
     })
 }
 
@@ -152,6 +237,7 @@ pub fn artifact_from_a2a(artifact: &a2a_types::Artifact) -> Result<Artifact, Str
             Some(artifact.description.clone())
         },
         parts: parts?,
+        metadata: metadata_from_a2a(artifact.metadata.as_ref()),
     })
 }
 
@@ -210,6 +296,7 @@ pub fn task_from_a2a(task: &a2a_types::Task) -> Result<Task, String> {
         status: task_status_from_a2a(status)?,
         history,
         artifacts,
+        metadata: metadata_from_a2a(task.metadata.as_ref()),
     })
 }
 
@@ -222,7 +309,9 @@ pub fn message_send_config_to_a2a(
         history_length: config.history_length.map(|v| v as i32),
         // In prost-generated types, blocking is bool not Option<bool>
         blocking: config.blocking.unwrap_or(false),
-        push_notification_config: None,
+        push_notification_config: config
+            .push_notification_config
+            .map(push_notification_config_to_a2a),
     }
 }
 
@@ -234,12 +323,151 @@ pub fn message_send_params_to_a2a(
 
     Ok(a2a_types::SendMessageRequest {
         tenant: String::new(),
+        metadata: metadata_to_a2a(params.metadata.as_ref())?,
         request: Some(message_to_a2a(params.message)?),
         configuration,
-        metadata: None,
     })
 }
 
+/// Convert WIT PushNotificationConfig to a2a-types PushNotificationConfig.
+pub fn push_notification_config_to_a2a(
+    config: PushNotificationConfig,
+) -> a2a_types::PushNotificationConfig {
+    a2a_types::PushNotificationConfig {
+        id: config.id.unwrap_or_default(),
+        url: config.url,
+        token: config.token.unwrap_or_default(),
+        authentication: config
+            .authentication
+            .map(|auth| a2a_types::AuthenticationInfo {
+                schemes: auth.schemes,
+                credentials: auth.credentials.unwrap_or_default(),
+            }),
+    }
+}
+
+/// Convert a2a-types PushNotificationConfig to WIT PushNotificationConfig.
+pub fn push_notification_config_from_a2a(
+    config: &a2a_types::PushNotificationConfig,
+) -> PushNotificationConfig {
+    PushNotificationConfig {
+        id: non_empty(&config.id),
+        url: config.url.clone(),
+        token: non_empty(&config.token),
+        authentication: config
+            .authentication
+            .as_ref()
+            .map(|auth| AuthenticationInfo {
+                schemes: auth.schemes.clone(),
+                credentials: non_empty(&auth.credentials),
+            }),
+    }
+}
+
+/// Convert a2a-types TaskPushNotificationConfig to WIT TaskPushNotificationConfig.
+pub fn task_push_notification_config_from_a2a(
+    config: &a2a_types::TaskPushNotificationConfig,
+) -> Result<TaskPushNotificationConfig, String> {
+    let push_notification_config = config
+        .push_notification_config
+        .as_ref()
+        .ok_or_else(|| "Push notification config has no configuration".to_string())?;
+
+    Ok(TaskPushNotificationConfig {
+        name: config.name.clone(),
+        push_notification_config: push_notification_config_from_a2a(push_notification_config),
+    })
+}
+
+/// Convert a2a-types ListTaskPushNotificationConfigResponse to its WIT counterpart.
+pub fn list_task_push_notification_config_response_from_a2a(
+    response: &a2a_types::ListTaskPushNotificationConfigResponse,
+) -> Result<ListTaskPushNotificationConfigResponse, String> {
+    let configs: Result<Vec<_>, _> = response
+        .configs
+        .iter()
+        .map(task_push_notification_config_from_a2a)
+        .collect();
+
+    Ok(ListTaskPushNotificationConfigResponse {
+        configs: configs?,
+        next_page_token: non_empty(&response.next_page_token),
+    })
+}
+
+/// Convert WIT ListTasksParams to a2a-types ListTasksRequest.
+pub fn list_tasks_params_to_a2a(params: ListTasksParams) -> a2a_types::ListTasksRequest {
+    a2a_types::ListTasksRequest {
+        tenant: String::new(),
+        context_id: params.context_id.unwrap_or_default(),
+        status: params
+            .status
+            .map(task_state_to_a2a)
+            .unwrap_or(a2a_types::TaskState::Unspecified as i32),
+        page_size: params.page_size.map(|v| v.min(i32::MAX as u32) as i32),
+        page_token: params.page_token.unwrap_or_default(),
+        history_length: params.history_length.map(|v| v.min(i32::MAX as u32) as i32),
+        last_updated_after: params.last_updated_after.unwrap_or_default(),
+        include_artifacts: params.include_artifacts,
+    }
+}
+
+/// Convert a2a-types ListTasksResponse to WIT ListTasksResponse.
+pub fn list_tasks_response_from_a2a(
+    response: &a2a_types::ListTasksResponse,
+) -> Result<ListTasksResponse, String> {
+    let tasks: Result<Vec<_>, _> = response.tasks.iter().map(task_from_a2a).collect();
+
+    Ok(ListTasksResponse {
+        tasks: tasks?,
+        next_page_token: non_empty(&response.next_page_token),
+        page_size: response.page_size.max(0) as u32,
+        total_size: response.total_size.max(0) as u32,
+    })
+}
+
+/// Convert a2a-types StreamResponse to a WIT StreamResponse event.
+///
+/// Used by the client to hand the agent's stream events to the guest.
+pub fn stream_response_from_a2a(
+    event: &a2a_types::StreamResponse,
+) -> Result<StreamResponse, String> {
+    use a2a_types::stream_response::Payload;
+
+    match event.payload.as_ref() {
+        Some(Payload::Task(task)) => Ok(StreamResponse::Task(task_from_a2a(task)?)),
+        Some(Payload::Msg(msg)) => Ok(StreamResponse::Message(message_from_a2a(msg)?)),
+        Some(Payload::StatusUpdate(update)) => {
+            let status = update
+                .status
+                .as_ref()
+                .ok_or_else(|| "Status update has no status".to_string())?;
+            Ok(StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
+                task_id: update.task_id.clone(),
+                context_id: update.context_id.clone(),
+                status: task_status_from_a2a(status)?,
+                final_: update.r#final,
+                metadata: metadata_from_a2a(update.metadata.as_ref()),
+            }))
+        }
+        Some(Payload::ArtifactUpdate(update)) => {
+            let artifact = update
+                .artifact
+                .as_ref()
+                .ok_or_else(|| "Artifact update has no artifact".to_string())?;
+            Ok(StreamResponse::ArtifactUpdate(TaskArtifactUpdateEvent {
+                task_id: update.task_id.clone(),
+                context_id: update.context_id.clone(),
+                artifact: artifact_from_a2a(artifact)?,
+                append: update.append,
+                last_chunk: update.last_chunk,
+                metadata: metadata_from_a2a(update.metadata.as_ref()),
+            }))
+        }
+        None => Err("StreamResponse has no payload".to_string()),
+    }
+}
+
 /// Convert a2a-types SendMessageResponse to WIT SendResponse.
 pub fn send_response_from_a2a(
     response: &a2a_types::SendMessageResponse,
@@ -261,6 +489,7 @@ pub fn error_from_string(msg: String) -> Error {
     Error {
         code: -32603, // Internal error
         message: msg,
+        data: None,
     }
 }
 
@@ -292,6 +521,7 @@ pub fn message_send_params_to_wit(
     Ok(MessageSendParams {
         message: message_from_a2a(message)?,
         configuration,
+        metadata: metadata_from_a2a(params.metadata.as_ref()),
     })
 }
 
@@ -306,6 +536,10 @@ fn message_send_config_from_a2a(config: &a2a_types::SendMessageConfiguration) ->
         history_length: config.history_length.map(|v| v as u32),
         // In prost-generated types, blocking is bool not Option<bool>
         blocking: Some(config.blocking),
+        push_notification_config: config
+            .push_notification_config
+            .as_ref()
+            .map(push_notification_config_from_a2a),
     }
 }
 
@@ -313,53 +547,110 @@ fn message_send_config_from_a2a(config: &a2a_types::SendMessageConfiguration) ->
 ///
 /// Used by the server to convert agent interface responses back to
 /// a2a-types for JSON serialization.
-pub fn send_response_from_wit(response: &SendResponse) -> a2a_types::SendMessageResponse {
+pub fn send_response_from_wit(
+    response: &SendResponse,
+) -> Result<a2a_types::SendMessageResponse, String> {
+    use a2a_types::send_message_response::Payload;
+
     // In prost-generated types, SendMessageResponse uses a payload oneof
-    match response {
-        SendResponse::Task(task) => a2a_types::SendMessageResponse {
-            payload: Some(a2a_types::send_message_response::Payload::Task(
-                task_from_wit(task),
-            )),
-        },
-        SendResponse::Message(msg) => a2a_types::SendMessageResponse {
-            payload: Some(a2a_types::send_message_response::Payload::Msg(
-                message_to_a2a_clone(msg),
-            )),
-        },
-    }
+    let payload = match response {
+        SendResponse::Task(task) => Payload::Task(task_from_wit(task)?),
+        SendResponse::Message(msg) => Payload::Msg(message_to_a2a_clone(msg)?),
+    };
+    Ok(a2a_types::SendMessageResponse {
+        payload: Some(payload),
+    })
 }
 
 /// Convert WIT Task to a2a-types Task.
 ///
 /// Used by the server to convert agent interface responses back to
 /// a2a-types for JSON serialization.
-pub fn task_from_wit(task: &Task) -> a2a_types::Task {
-    let history = task
-        .history
-        .as_ref()
-        .map(|h| h.iter().map(message_to_a2a_clone).collect())
-        .unwrap_or_default();
+pub fn task_from_wit(task: &Task) -> Result<a2a_types::Task, String> {
+    let history = match &task.history {
+        Some(history) => history
+            .iter()
+            .map(message_to_a2a_clone)
+            .collect::<Result<_, _>>()?,
+        None => vec![],
+    };
 
-    let artifacts = task
-        .artifacts
-        .as_ref()
-        .map(|a| a.iter().map(artifact_to_a2a).collect())
-        .unwrap_or_default();
+    let artifacts = match &task.artifacts {
+        Some(artifacts) => artifacts
+            .iter()
+            .map(artifact_to_a2a)
+            .collect::<Result<_, _>>()?,
+        None => vec![],
+    };
 
-    a2a_types::Task {
+    Ok(a2a_types::Task {
         id: task.id.clone(),
         context_id: task.context_id.clone(),
         // In prost-generated types, status is Option<TaskStatus>
-        status: Some(task_status_to_a2a_struct(&task.status)),
+        status: Some(task_status_to_a2a_struct(&task.status)?),
         history,
         artifacts,
-        metadata: None,
-    }
+        metadata: metadata_to_a2a(task.metadata.as_ref())?,
+    })
+}
+
+/// Convert a WIT StreamResponse event to a2a-types StreamResponse.
+///
+/// Used by the server to relay agent stream events as SSE.
+pub fn stream_response_from_wit(
+    event: &StreamResponse,
+) -> Result<a2a_types::StreamResponse, String> {
+    use a2a_types::stream_response::Payload;
+
+    let payload = match event {
+        StreamResponse::Task(task) => Payload::Task(task_from_wit(task)?),
+        StreamResponse::Message(msg) => Payload::Msg(message_to_a2a_clone(msg)?),
+        StreamResponse::StatusUpdate(update) => {
+            Payload::StatusUpdate(status_update_from_wit(update)?)
+        }
+        StreamResponse::ArtifactUpdate(update) => {
+            Payload::ArtifactUpdate(artifact_update_from_wit(update)?)
+        }
+    };
+    Ok(a2a_types::StreamResponse {
+        payload: Some(payload),
+    })
+}
+
+/// Convert WIT TaskStatusUpdateEvent to a2a-types TaskStatusUpdateEvent.
+fn status_update_from_wit(
+    update: &TaskStatusUpdateEvent,
+) -> Result<a2a_types::TaskStatusUpdateEvent, String> {
+    Ok(a2a_types::TaskStatusUpdateEvent {
+        task_id: update.task_id.clone(),
+        context_id: update.context_id.clone(),
+        status: Some(task_status_to_a2a_struct(&update.status)?),
+        r#final: update.final_,
+        metadata: metadata_to_a2a(update.metadata.as_ref())?,
+    })
+}
+
+/// Convert WIT TaskArtifactUpdateEvent to a2a-types TaskArtifactUpdateEvent.
+fn artifact_update_from_wit(
+    update: &TaskArtifactUpdateEvent,
+) -> Result<a2a_types::TaskArtifactUpdateEvent, String> {
+    Ok(a2a_types::TaskArtifactUpdateEvent {
+        task_id: update.task_id.clone(),
+        context_id: update.context_id.clone(),
+        artifact: Some(artifact_to_a2a(&update.artifact)?),
+        append: update.append,
+        last_chunk: update.last_chunk,
+        metadata: metadata_to_a2a(update.metadata.as_ref())?,
+    })
 }
 
 /// Convert WIT TaskStatus to a2a-types TaskStatus struct.
-fn task_status_to_a2a_struct(status: &TaskStatus) -> a2a_types::TaskStatus {
-    let message = status.message.as_ref().map(message_to_a2a_clone);
+fn task_status_to_a2a_struct(status: &TaskStatus) -> Result<a2a_types::TaskStatus, String> {
+    let message = status
+        .message
+        .as_ref()
+        .map(message_to_a2a_clone)
+        .transpose()?;
     // Parse RFC 3339 timestamp string to pbjson_types::Timestamp
     let timestamp = status.timestamp.as_ref().and_then(|ts| {
         chrono::DateTime::parse_from_rfc3339(ts).ok().map(|dt| {
@@ -371,22 +662,22 @@ fn task_status_to_a2a_struct(status: &TaskStatus) -> a2a_types::TaskStatus {
         })
     });
 
-    a2a_types::TaskStatus {
+    Ok(a2a_types::TaskStatus {
         state: task_state_to_a2a(status.state),
         message,
         timestamp,
-    }
+    })
 }
 
 /// Convert WIT Message to a2a-types Message (clone-based, for response conversion).
-fn message_to_a2a_clone(msg: &Message) -> a2a_types::Message {
-    let parts: Vec<_> = msg
+fn message_to_a2a_clone(msg: &Message) -> Result<a2a_types::Message, String> {
+    let parts = msg
         .parts
         .iter()
-        .filter_map(|p| part_to_a2a_clone(p).ok())
-        .collect();
+        .map(part_to_a2a_clone)
+        .collect::<Result<_, _>>()?;
 
-    a2a_types::Message {
+    Ok(a2a_types::Message {
         role: role_to_a2a(msg.role),
         parts,
         // In prost-generated types, these are String not Option<String>
@@ -395,39 +686,71 @@ fn message_to_a2a_clone(msg: &Message) -> a2a_types::Message {
         context_id: msg.context_id.clone().unwrap_or_default(),
         reference_task_ids: vec![],
         extensions: vec![],
-        metadata: None,
-    }
+        metadata: metadata_to_a2a(msg.metadata.as_ref())?,
+    })
 }
 
 /// Convert WIT Part to a2a-types Part (clone-based).
+///
+/// A file must have a URI or bytes, not both, and data must be a JSON
+/// object. The data part's `mime-type` has no v1 counterpart and is ignored.
 fn part_to_a2a_clone(part: &Part) -> Result<a2a_types::Part, String> {
-    match part {
-        Part::Text(text_part) => Ok(a2a_types::Part {
-            part: Some(a2a_types::part::Part::Text(text_part.text.clone())),
-            metadata: None,
-        }),
-        Part::File(_) => Err("FilePart not implemented".to_string()),
-        Part::Data(_) => Err("DataPart not implemented".to_string()),
-    }
+    let (content, metadata) = match part {
+        Part::Text(text_part) => (
+            a2a_types::part::Part::Text(text_part.text.clone()),
+            &text_part.metadata,
+        ),
+        Part::File(file_part) => {
+            let content = &file_part.file;
+            let file = match (&content.uri, &content.bytes) {
+                (Some(_), Some(_)) => {
+                    return Err("FilePart has both uri and bytes".to_string());
+                }
+                (Some(uri), None) => a2a_types::file_part::File::FileWithUri(uri.clone()),
+                (None, Some(bytes)) => a2a_types::file_part::File::FileWithBytes(bytes.clone()),
+                (None, None) => return Err("FilePart has neither uri nor bytes".to_string()),
+            };
+            let file = a2a_types::part::Part::File(a2a_types::FilePart {
+                media_type: content.mime_type.clone().unwrap_or_default(),
+                name: content.name.clone().unwrap_or_default(),
+                file: Some(file),
+            });
+            (file, &file_part.metadata)
+        }
+        Part::Data(data_part) => {
+            let data: Value = serde_json::from_str(&data_part.data)
+                .map_err(|e| format!("DataPart is not valid JSON: {e}"))?;
+            if !data.is_object() {
+                return Err(format!("DataPart must be a JSON object, got {data}"));
+            }
+            let data = a2a_types::metadata::json_to_struct(data).map_err(|e| e.to_string())?;
+            let data = a2a_types::part::Part::Data(a2a_types::DataPart { data: Some(data) });
+            (data, &data_part.metadata)
+        }
+    };
+    Ok(a2a_types::Part {
+        part: Some(content),
+        metadata: metadata_to_a2a(metadata.as_ref())?,
+    })
 }
 
 /// Convert WIT Artifact to a2a-types Artifact.
-fn artifact_to_a2a(artifact: &Artifact) -> a2a_types::Artifact {
-    let parts: Vec<_> = artifact
+fn artifact_to_a2a(artifact: &Artifact) -> Result<a2a_types::Artifact, String> {
+    let parts = artifact
         .parts
         .iter()
-        .filter_map(|p| part_to_a2a_clone(p).ok())
-        .collect();
+        .map(part_to_a2a_clone)
+        .collect::<Result<_, _>>()?;
 
-    a2a_types::Artifact {
+    Ok(a2a_types::Artifact {
         artifact_id: artifact.artifact_id.clone(),
         // In prost-generated types, name and description are String not Option<String>
         name: artifact.name.clone().unwrap_or_default(),
         description: artifact.description.clone().unwrap_or_default(),
         parts,
         extensions: vec![],
-        metadata: None,
-    }
+        metadata: metadata_to_a2a(artifact.metadata.as_ref())?,
+    })
 }
 
 #[cfg(test)]
@@ -495,6 +818,7 @@ mod tests {
     fn test_text_part_conversion() {
         let wit_part = Part::Text(TextPart {
             text: "hello".to_string(),
+            metadata: None,
         });
         let a2a_part = part_to_a2a(wit_part).unwrap();
         // Part is now a struct with a part field (oneof)
@@ -509,25 +833,92 @@ mod tests {
     }
 
     #[test]
-    fn test_file_part_not_implemented() {
+    fn test_file_part_round_trip() {
         let wit_part = Part::File(FilePart {
+            file: FileContent {
+                name: Some("report.pdf".to_string()),
+                mime_type: Some("application/pdf".to_string()),
+                uri: None,
+                bytes: Some(b"%PDF-1.7".to_vec()),
+            },
+            metadata: None,
+        });
+        let a2a_part = part_to_a2a(wit_part).unwrap();
+        let file = a2a_part.as_file().unwrap();
+        assert_eq!(file.name, "report.pdf");
+        assert_eq!(file.media_type, "application/pdf");
+        assert_eq!(file.bytes(), Some(&b"%PDF-1.7"[..]));
+        assert_eq!(
+            part_to_a2a(part_from_a2a(&a2a_part).unwrap()).unwrap(),
+            a2a_part
+        );
+
+        let a2a_part = a2a_types::Part::file_uri("https://example.com/a.pdf", "application/pdf");
+        let Part::File(wit_file) = part_from_a2a(&a2a_part).unwrap() else {
+            panic!("Expected FilePart");
+        };
+        assert_eq!(
+            wit_file.file.uri.as_deref(),
+            Some("https://example.com/a.pdf")
+        );
+        assert_eq!(wit_file.file.name, None);
+        assert_eq!(wit_file.file.bytes, None);
+        assert_eq!(part_to_a2a(Part::File(wit_file)).unwrap(), a2a_part);
+
+        let both = Part::File(FilePart {
             file: FileContent {
                 name: None,
                 mime_type: None,
+                uri: Some("https://example.com/a.pdf".to_string()),
+                bytes: Some(vec![0]),
+            },
+            metadata: None,
+        });
+        assert!(part_to_a2a(both).is_err());
+
+        let neither = Part::File(FilePart {
+            file: FileContent {
+                name: Some("report.pdf".to_string()),
+                mime_type: None,
                 uri: None,
                 bytes: None,
             },
+            metadata: None,
         });
-        assert!(part_to_a2a(wit_part).is_err());
+        assert!(part_to_a2a(neither).is_err());
+        let empty = a2a_types::Part {
+            part: Some(a2a_types::part::Part::File(a2a_types::FilePart::default())),
+            metadata: None,
+        };
+        assert!(part_from_a2a(&empty).is_err());
     }
 
     #[test]
-    fn test_data_part_not_implemented() {
+    fn test_data_part_round_trip() {
         let wit_part = Part::Data(DataPart {
-            data: "{}".to_string(),
+            data: r#"{"pages": 3, "title": "Q3", "tags": ["a", "b"], "draft": null}"#.to_string(),
             mime_type: None,
+            metadata: None,
         });
-        assert!(part_to_a2a(wit_part).is_err());
+        let a2a_part = part_to_a2a(wit_part).unwrap();
+        assert_eq!(
+            a2a_part.as_data().unwrap(),
+            serde_json::json!({"pages": 3, "title": "Q3", "tags": ["a", "b"], "draft": null})
+        );
+
+        let Part::Data(wit_data) = part_from_a2a(&a2a_part).unwrap() else {
+            panic!("Expected DataPart");
+        };
+        assert_eq!(part_to_a2a(Part::Data(wit_data)).unwrap(), a2a_part);
+
+        for data in ["[1, 2]", "not json"] {
+            let wit_part = Part::Data(DataPart {
+                data: data.to_string(),
+                mime_type: None,
+                metadata: None,
+            });
+            assert!(part_to_a2a(wit_part).is_err(), "{data}");
+        }
     }
 
     #[test]
@@ -538,16 +929,25 @@ mod tests {
                 role: Role::User,
                 parts: vec![Part::Text(TextPart {
                     text: "Hello, agent!".to_string(),
+                    metadata: None,
                 })],
                 message_id: Some("msg-123".to_string()),
                 task_id: Some("task-456".to_string()),
                 context_id: Some("ctx-789".to_string()),
+                metadata: None,
             },
             configuration: Some(MessageSendConfig {
                 accepted_output_modes: Some(vec!["text".to_string(), "json".to_string()]),
                 history_length: Some(10),
                 blocking: Some(true),
+                push_notification_config: Some(PushNotificationConfig {
+                    id: None,
+                    url: "https://client.example.com/webhook".to_string(),
+                    token: Some("secret".to_string()),
+                    authentication: None,
+                }),
             }),
+            metadata: None,
         };
 
         // Convert to a2a-types (now returns SendMessageRequest)
@@ -576,6 +976,10 @@ mod tests {
         assert_eq!(config.history_length, Some(10));
         // In prost-generated types, blocking is bool not Option<bool>
         assert!(config.blocking);
+        let push = config.push_notification_config.unwrap();
+        assert_eq!(push.url, "https://client.example.com/webhook");
+        assert_eq!(push.token, "secret");
+        assert!(push.id.is_empty());
     }
 
     #[test]
@@ -653,6 +1057,144 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_stream_response_from_wit() {
+        let event = StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
+            task_id: "task-1".to_string(),
+            context_id: "ctx-1".to_string(),
+            status: TaskStatus {
+                state: TaskState::Working,
+                message: None,
+                timestamp: None,
+            },
+            final_: false,
+            metadata: None,
+        });
+        let json = serde_json::to_value(stream_response_from_wit(&event).unwrap()).unwrap();
+        assert_eq!(json["statusUpdate"]["taskId"], "task-1");
+        assert_eq!(
+            json["statusUpdate"]["status"]["state"],
+            "TASK_STATE_WORKING"
+        );
+
+        let event = StreamResponse::ArtifactUpdate(TaskArtifactUpdateEvent {
+            task_id: "task-1".to_string(),
+            context_id: "ctx-1".to_string(),
+            artifact: Artifact {
+                artifact_id: "art-1".to_string(),
+                name: None,
+                description: None,
+                parts: vec![Part::Text(TextPart {
+                    text: "chunk".to_string(),
+                    metadata: None,
+                })],
+                metadata: None,
+            },
+            append: true,
+            last_chunk: false,
+            metadata: None,
+        });
+        let a2a = stream_response_from_wit(&event).unwrap();
+        let update = a2a.as_artifact_update().unwrap();
+        assert!(update.append);
+        assert_eq!(update.artifact.as_ref().unwrap().text(), "chunk");
+    }
+
+    #[test]
+    fn test_json_value_conversion() {
+        let json = serde_json::json!({
+            "null": null,
+            "flag": true,
+            "count": 3.0,
+            "ratio": 0.5,
+            "name": "trace",
+            "list": [1, "two"],
+            "nested": {"a": {"b": []}},
+        });
+        let Value::Object(object) = &json else {
+            unreachable!()
+        };
+        for value in object.values() {
+            let wit = json_value_from_json(value);
+            assert_eq!(&json_value_to_json(&wit).unwrap(), value);
+        }
+        assert!(matches!(
+            json_value_from_json(&object["list"]),
+            JsonValue::Array(_)
+        ));
+
+        assert!(json_value_to_json(&JsonValue::Number(f64::NAN)).is_err());
+        assert!(json_value_to_json(&JsonValue::Object("[1]".to_string())).is_err());
+        assert!(json_value_to_json(&JsonValue::Array("[1".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_metadata_round_trip() {
+        let metadata: Metadata = vec![
+            ("traceId".to_string(), JsonValue::Str("abc123".to_string())),
+            (
+                "https://example.com/ext/cost/v1".to_string(),
+                JsonValue::Object(r#"{"tokens":12}"#.to_string()),
+            ),
+        ];
+        let wit_params = MessageSendParams {
+            message: Message {
+                role: Role::User,
+                parts: vec![Part::Text(TextPart {
+                    text: "hi".to_string(),
+                    metadata: Some(metadata.clone()),
+                })],
+                message_id: Some("msg-1".to_string()),
+                task_id: None,
+                context_id: None,
+                metadata: Some(metadata.clone()),
+            },
+            configuration: None,
+            metadata: Some(metadata.clone()),
+        };
+
+        let a2a_params = message_send_params_to_a2a(wit_params).unwrap();
+        let json = serde_json::to_value(&a2a_params).unwrap();
+        assert_eq!(json["metadata"]["traceId"], "abc123");
+        assert_eq!(
+            json["message"]["metadata"]["https://example.com/ext/cost/v1"]["tokens"],
+            12.0
+        );
+        assert_eq!(json["message"]["parts"][0]["metadata"]["traceId"], "abc123");
+
+        // WIT types don't implement PartialEq; compare their Debug output
+        let round_trip = message_send_params_to_wit(&a2a_params).unwrap();
+        let mut expected = metadata;
+        expected.sort_by(|a, b| a.0.cmp(&b.0));
+        let expected = format!("{:?}", Some(expected));
+        assert_eq!(format!("{:?}", round_trip.metadata), expected);
+        assert_eq!(format!("{:?}", round_trip.message.metadata), expected);
+        let Part::Text(text) = &round_trip.message.parts[0] else {
+            panic!("Expected TextPart");
+        };
+        assert_eq!(format!("{:?}", text.metadata), expected);
+    }
+
+    #[test]
+    fn test_invalid_agent_metadata_is_an_error() {
+        let task = Task {
+            id: "task-1".to_string(),
+            context_id: "ctx-1".to_string(),
+            status: TaskStatus {
+                state: TaskState::Completed,
+                message: None,
+                timestamp: None,
+            },
+            history: None,
+            artifacts: None,
+            metadata: Some(vec![(
+                "bad".to_string(),
+                JsonValue::Object("not json".to_string()),
+            )]),
+        };
+        assert!(task_from_wit(&task).is_err());
+    }
+
     // Note: test_error_from_jsonrpc_preserves_code was removed because
     // JsonrpcError type no longer exists in prost-generated types.
 
@@ -675,4 +1217,119 @@ mod tests {
         assert_eq!(wit_err2.code, -32603);
         assert_eq!(wit_err2.message, another_error);
     }
+
+    #[test]
+    fn test_push_notification_config_round_trip() {
+        let config = PushNotificationConfig {
+            id: Some("cfg-1".to_string()),
+            url: "https://client.example.com/webhook".to_string(),
+            token: None,
+            authentication: Some(AuthenticationInfo {
+                schemes: vec!["Bearer".to_string()],
+                credentials: Some("token".to_string()),
+            }),
+        };
+
+        let a2a_config = push_notification_config_to_a2a(config);
+        assert_eq!(a2a_config.id, "cfg-1");
+        assert!(a2a_config.token.is_empty());
+
+        let task_config = a2a_types::TaskPushNotificationConfig {
+            name: "tasks/task-1/pushNotificationConfigs/cfg-1".to_string(),
+            push_notification_config: Some(a2a_config),
+        };
+        let round_trip = task_push_notification_config_from_a2a(&task_config).unwrap();
+        assert_eq!(round_trip.name, task_config.name);
+        let config = round_trip.push_notification_config;
+        assert_eq!(config.id.as_deref(), Some("cfg-1"));
+        assert_eq!(config.token, None);
+        let auth = config.authentication.unwrap();
+        assert_eq!(auth.schemes, vec!["Bearer".to_string()]);
+        assert_eq!(auth.credentials.as_deref(), Some("token"));
+
+        let missing = a2a_types::TaskPushNotificationConfig {
+            name: task_config.name.clone(),
+            push_notification_config: None,
+        };
+        assert!(task_push_notification_config_from_a2a(&missing).is_err());
+    }
+
+    #[test]
+    fn test_list_tasks_conversion() {
+        let request = list_tasks_params_to_a2a(ListTasksParams {
+            context_id: Some("ctx-1".to_string()),
+            status: Some(TaskState::Working),
+            page_size: Some(10),
+            page_token: None,
+            history_length: None,
+            last_updated_after: None,
+            include_artifacts: Some(true),
+        });
+        assert_eq!(request.context_id, "ctx-1");
+        assert_eq!(request.status, a2a_types::TaskState::Working as i32);
+        assert_eq!(request.page_size, Some(10));
+        assert_eq!(request.include_artifacts, Some(true));
+
+        let unfiltered = list_tasks_params_to_a2a(ListTasksParams {
+            context_id: None,
+            status: None,
+            page_size: None,
+            page_token: None,
+            history_length: None,
+            last_updated_after: None,
+            include_artifacts: None,
+        });
+        assert_eq!(unfiltered.status, a2a_types::TaskState::Unspecified as i32);
+
+        let response = a2a_types::ListTasksResponse {
+            tasks: vec![a2a_types::Task {
+                id: "task-1".to_string(),
+                context_id: "ctx-1".to_string(),
+                status: Some(a2a_types::TaskStatus {
+                    state: a2a_types::TaskState::Working as i32,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }],
+            next_page_token: String::new(),
+            page_size: 10,
+            total_size: 1,
+        };
+        let wit_response = list_tasks_response_from_a2a(&response).unwrap();
+        assert_eq!(wit_response.tasks.len(), 1);
+        assert_eq!(wit_response.tasks[0].id, "task-1");
+        assert_eq!(wit_response.next_page_token, None);
+        assert_eq!(wit_response.total_size, 1);
+    }
+
+    #[test]
+    fn test_stream_response_from_a2a() {
+        use a2a_types::stream_response::Payload;
+
+        let event = a2a_types::StreamResponse {
+            payload: Some(Payload::StatusUpdate(a2a_types::TaskStatusUpdateEvent {
+                task_id: "task-1".to_string(),
+                context_id: "ctx-1".to_string(),
+                status: Some(a2a_types::TaskStatus {
+                    state: a2a_types::TaskState::Completed as i32,
+                    ..Default::default()
+                }),
+                r#final: true,
+                metadata: None,
+            })),
+        };
+        let StreamResponse::StatusUpdate(update) = stream_response_from_a2a(&event).unwrap() else {
+            panic!("Expected StatusUpdate");
+        };
+        assert_eq!(update.task_id, "task-1");
+        assert!(matches!(update.status.state, TaskState::Completed));
+        assert!(update.final_);
+
+        // Round trip back through the server conversion
+        let round_trip = stream_response_from_wit(&StreamResponse::StatusUpdate(update)).unwrap();
+        assert_eq!(round_trip, event);
+
+        let empty = a2a_types::StreamResponse { payload: None };
+        assert!(stream_response_from_a2a(&empty).is_err());
+    }
 }
@@ -72,6 +72,18 @@ impl Response {
     pub fn internal_error(id: Value, msg: impl Into<String>) -> Self {
         Self::error(id, -32603, msg)
     }
+
+    /// An error returned by the agent, with its data.
+    pub fn agent_error(id: Value, error: crate::a2a::protocol::types::Error) -> Self {
+        let mut response = Self::error(id, error.code, error.message);
+        if let Some(rpc_error) = &mut response.error {
+            rpc_error.data = error
+                .data
+                .as_ref()
+                .and_then(|data| convert::json_value_to_json(data).ok());
+        }
+        response
+    }
 }
 
 // Standard JSON-RPC error codes
@@ -84,20 +96,35 @@ pub const INTERNAL_ERROR: i32 = -32603;
 // A2A-specific error codes
 pub const TASK_NOT_FOUND: i32 = -32001;
 pub const TASK_NOT_CANCELABLE: i32 = -32002;
+pub const EXTENSION_SUPPORT_REQUIRED: i32 = -32008;
 
+use crate::a2a::protocol::agent::EventStream;
+use crate::a2a::protocol::types::RequestContext;
+use crate::body;
 use crate::convert;
+use crate::server::{Extensions, Reply};
+use crate::tenant;
 use crate::wasi::http::types::IncomingRequest;
-
-/// Maximum request body size (1 MB).
-const MAX_BODY_SIZE: usize = 1024 * 1024;
-
-/// Handle a JSON-RPC request.
-pub fn handle(request: &IncomingRequest) -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
-    let body = read_request_body(request)?;
-    handle_jsonrpc(&body)
+use a2a_types::version::{Dialect, JsonRpcMethod};
+
+/// Handle a JSON-RPC request for the tenant resolved from the request,
+/// negotiating the requested `extensions`.
+///
+/// A `tenant` in the params must match it.
+pub fn handle(
+    request: &IncomingRequest,
+    tenant: Option<&str>,
+    extensions: &Extensions,
+) -> Result<Reply, (u16, String)> {
+    let body = body::read_request(request)?;
+    handle_jsonrpc(&body, tenant, extensions)
 }
 
-fn handle_jsonrpc(body: &[u8]) -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
+fn handle_jsonrpc(
+    body: &[u8],
+    tenant: Option<&str>,
+    extensions: &Extensions,
+) -> Result<Reply, (u16, String)> {
     // Parse JSON-RPC request
     let request: Request = match serde_json::from_slice(body) {
         Ok(r) => r,
@@ -108,26 +135,78 @@ fn handle_jsonrpc(body: &[u8]) -> Result<(u16, &'static str, Vec<u8>), (u16, Str
                 format!("Parse error: {e}"),
             );
             let body = serde_json::to_vec(&response).unwrap_or_default();
-            return Ok((400, "application/json", body));
+            return Ok(Reply::Full(400, "application/json", body));
         }
     };
 
+    // Accept both the v1 and the 0.2/0.3 method names; legacy requests are
+    // upgraded to v1 JSON and answered in their own dialect.
+    let Some((method, dialect)) = JsonRpcMethod::from_name(&request.method) else {
+        let response = Response::method_not_found(request.id.clone());
+        let body = serde_json::to_vec(&response).unwrap_or_default();
+        return Ok(Reply::Full(200, "application/json", body));
+    };
+    let mut request = request;
+    if dialect == Dialect::Legacy {
+        request.params = method.params_from_legacy(std::mem::take(&mut request.params));
+    }
+
     // Route to handler
-    let response = match request.method.as_str() {
-        "SendMessage" => handle_message_send(&request),
-        "GetTask" => handle_tasks_get(&request),
-        "CancelTask" => handle_tasks_cancel(&request),
-        "SendStreamingMessage" | "SubscribeToTask" => {
-            Response::error(request.id.clone(), -32601, "Streaming not implemented")
+    let routed = match method {
+        JsonRpcMethod::SendMessage => {
+            Routed::Response(handle_message_send(&request, tenant, extensions))
+        }
+        JsonRpcMethod::GetTask => Routed::Response(handle_tasks_get(&request, tenant, extensions)),
+        JsonRpcMethod::CancelTask => {
+            Routed::Response(handle_tasks_cancel(&request, tenant, extensions))
         }
-        _ => Response::method_not_found(request.id.clone()),
+        JsonRpcMethod::SendStreamingMessage => handle_message_stream(&request, tenant, extensions),
+        JsonRpcMethod::SubscribeToTask => handle_tasks_subscribe(&request, tenant, extensions),
     };
 
-    let body = serde_json::to_vec(&response).unwrap_or_default();
-    Ok((200, "application/json", body))
+    match routed {
+        // Each event is a JSON-RPC response to the streaming request
+        Routed::Events(stream) => {
+            let id = request.id;
+            Ok(Reply::events(stream, move |event| {
+                let response = match event {
+                    Ok(event) => Response::success(id.clone(), event),
+                    Err(e) => Response::agent_error(id.clone(), e),
+                };
+                let response = to_dialect(response, method, dialect);
+                serde_json::to_vec(&response).unwrap_or_default()
+            }))
+        }
+        Routed::Response(response) => {
+            let response = to_dialect(response, method, dialect);
+            let body = serde_json::to_vec(&response).unwrap_or_default();
+            Ok(Reply::Full(200, "application/json", body))
+        }
+    }
 }
 
-fn handle_message_send(request: &Request) -> Response {
+/// A routed request: a single response, or the event stream of a streaming
+/// method.
+enum Routed {
+    Response(Response),
+    Events(EventStream),
+}
+
+/// Convert a v1 response to the request's dialect.
+fn to_dialect(mut response: Response, method: JsonRpcMethod, dialect: Dialect) -> Response {
+    if dialect == Dialect::Legacy {
+        response.result = response
+            .result
+            .map(|result| method.result_to_legacy(result));
+    }
+    response
+}
+
+fn handle_message_send(
+    request: &Request,
+    tenant: Option<&str>,
+    extensions: &Extensions,
+) -> Response {
     use crate::a2a::protocol::agent;
 
     // In prost-generated types, MessageSendParams is now SendMessageRequest
@@ -142,23 +221,83 @@ fn handle_message_send(request: &Request) -> Response {
         Err(e) => return Response::invalid_params(request.id.clone(), e),
     };
 
-    // Extract tenant from params (empty string means no tenant)
-    let tenant = if params.tenant.is_empty() {
-        None
-    } else {
-        Some(params.tenant.as_str())
+    let context = match request_context(request, tenant, &params.tenant, extensions) {
+        Ok(context) => context,
+        Err(response) => return *response,
     };
 
-    match agent::on_message(tenant, &wit_params) {
-        Ok(response) => {
-            let a2a_response = convert::send_response_from_wit(&response);
-            Response::success(request.id.clone(), a2a_response)
+    match agent::on_message(&context, &wit_params) {
+        Ok(response) => match convert::send_response_from_wit(&response) {
+            Ok(a2a_response) => Response::success(request.id.clone(), a2a_response),
+            Err(e) => invalid_agent_response(request, e),
+        },
+        Err(e) => Response::agent_error(request.id.clone(), e),
+    }
+}
+
+/// Start a streaming message, or answer with an error if it can't start.
+fn handle_message_stream(
+    request: &Request,
+    tenant: Option<&str>,
+    extensions: &Extensions,
+) -> Routed {
+    use crate::a2a::protocol::agent;
+
+    let params: a2a_types::SendMessageRequest = match serde_json::from_value(request.params.clone())
+    {
+        Ok(p) => p,
+        Err(e) => {
+            return Routed::Response(Response::invalid_params(request.id.clone(), e.to_string()));
         }
-        Err(e) => Response::error(request.id.clone(), e.code, e.message),
+    };
+
+    let wit_params = match convert::message_send_params_to_wit(&params) {
+        Ok(p) => p,
+        Err(e) => return Routed::Response(Response::invalid_params(request.id.clone(), e)),
+    };
+
+    let context = match request_context(request, tenant, &params.tenant, extensions) {
+        Ok(context) => context,
+        Err(response) => return Routed::Response(*response),
+    };
+
+    match agent::on_message_stream(&context, &wit_params) {
+        Ok(stream) => Routed::Events(stream),
+        Err(e) => Routed::Response(Response::agent_error(request.id.clone(), e)),
     }
 }
 
-fn handle_tasks_get(request: &Request) -> Response {
+/// Subscribe to a task's events, or answer with an error if that fails.
+fn handle_tasks_subscribe(
+    request: &Request,
+    tenant: Option<&str>,
+    extensions: &Extensions,
+) -> Routed {
+    use crate::a2a::protocol::agent;
+
+    let params: a2a_types::SubscribeToTaskRequest =
+        match serde_json::from_value(request.params.clone()) {
+            Ok(p) => p,
+            Err(e) => {
+                return Routed::Response(Response::invalid_params(
+                    request.id.clone(),
+                    e.to_string(),
+                ));
+            }
+        };
+
+    let context = match request_context(request, tenant, &params.tenant, extensions) {
+        Ok(context) => context,
+        Err(response) => return Routed::Response(*response),
+    };
+
+    match agent::on_subscribe_task(&context, &params.name) {
+        Ok(stream) => Routed::Events(stream),
+        Err(e) => Routed::Response(Response::agent_error(request.id.clone(), e)),
+    }
+}
+
+fn handle_tasks_get(request: &Request, tenant: Option<&str>, extensions: &Extensions) -> Response {
     use crate::a2a::protocol::agent;
 
     // Use a2a-types GetTaskRequest for consistency with protobuf
@@ -167,27 +306,29 @@ fn handle_tasks_get(request: &Request) -> Response {
         Err(e) => return Response::invalid_params(request.id.clone(), e.to_string()),
     };
 
-    // Extract tenant from params (empty string means no tenant)
-    let tenant = if params.tenant.is_empty() {
-        None
-    } else {
-        Some(params.tenant.as_str())
+    let context = match request_context(request, tenant, &params.tenant, extensions) {
+        Ok(context) => context,
+        Err(response) => return *response,
     };
 
     // Convert i32 to u32 for WIT compatibility (protobuf uses i32, WIT uses u32)
     let history_length = params.history_length.map(|v| v as u32);
 
-    match agent::on_get_task(tenant, &params.name, history_length) {
-        Ok(Some(task)) => {
-            let a2a_task = convert::task_from_wit(&task);
-            Response::success(request.id.clone(), a2a_task)
-        }
+    match agent::on_get_task(&context, &params.name, history_length) {
+        Ok(Some(task)) => match convert::task_from_wit(&task) {
+            Ok(a2a_task) => Response::success(request.id.clone(), a2a_task),
+            Err(e) => invalid_agent_response(request, e),
+        },
         Ok(None) => Response::success(request.id.clone(), serde_json::Value::Null),
-        Err(e) => Response::error(request.id.clone(), e.code, e.message),
+        Err(e) => Response::agent_error(request.id.clone(), e),
     }
 }
 
-fn handle_tasks_cancel(request: &Request) -> Response {
+fn handle_tasks_cancel(
+    request: &Request,
+    tenant: Option<&str>,
+    extensions: &Extensions,
+) -> Response {
     use crate::a2a::protocol::agent;
 
     // Use a2a-types CancelTaskRequest for consistency with protobuf
@@ -197,48 +338,43 @@ fn handle_tasks_cancel(request: &Request) -> Response {
         Err(e) => return Response::invalid_params(request.id.clone(), e.to_string()),
     };
 
-    // Extract tenant from params (empty string means no tenant)
-    let tenant = if params.tenant.is_empty() {
-        None
-    } else {
-        Some(params.tenant.as_str())
+    let context = match request_context(request, tenant, &params.tenant, extensions) {
+        Ok(context) => context,
+        Err(response) => return *response,
     };
 
-    match agent::on_cancel_task(tenant, &params.name) {
-        Ok(Some(task)) => {
-            let a2a_task = convert::task_from_wit(&task);
-            Response::success(request.id.clone(), a2a_task)
-        }
+    match agent::on_cancel_task(&context, &params.name) {
+        Ok(Some(task)) => match convert::task_from_wit(&task) {
+            Ok(a2a_task) => Response::success(request.id.clone(), a2a_task),
+            Err(e) => invalid_agent_response(request, e),
+        },
         Ok(None) => Response::success(request.id.clone(), serde_json::Value::Null),
-        Err(e) => Response::error(request.id.clone(), e.code, e.message),
+        Err(e) => Response::agent_error(request.id.clone(), e),
     }
 }
 
-fn read_request_body(request: &IncomingRequest) -> Result<Vec<u8>, (u16, String)> {
-    let body = request
-        .consume()
-        .map_err(|_| (500, "Failed to consume request body".to_string()))?;
-
-    let stream = body
-        .stream()
-        .map_err(|_| (500, "Failed to get body stream".to_string()))?;
-
-    let mut data = Vec::new();
-    loop {
-        match stream.blocking_read(64 * 1024) {
-            Ok(chunk) => {
-                if chunk.is_empty() {
-                    break;
-                }
-                if data.len() + chunk.len() > MAX_BODY_SIZE {
-                    return Err((413, "Request body too large".to_string()));
-                }
-                data.extend_from_slice(&chunk);
-            }
-            Err(crate::wasi::io::streams::StreamError::Closed) => break,
-            Err(_) => return Err((500, "Failed to read body".to_string())),
-        }
-    }
+/// The context of a request whose params name `params_tenant`, or the error
+/// answering it.
+fn request_context(
+    request: &Request,
+    tenant: Option<&str>,
+    params_tenant: &str,
+    extensions: &Extensions,
+) -> Result<RequestContext, Box<Response>> {
+    let tenant = tenant::merge(tenant, Some(params_tenant))
+        .map_err(|e| Response::invalid_params(request.id.clone(), e))?;
+    extensions.context(tenant).map_err(|e| {
+        Box::new(Response::error(
+            request.id.clone(),
+            EXTENSION_SUPPORT_REQUIRED,
+            e,
+        ))
+    })
+}
 
-    Ok(data)
+fn invalid_agent_response(request: &Request, error: String) -> Response {
+    Response::internal_error(
+        request.id.clone(),
+        format!("Invalid agent response: {error}"),
+    )
 }
@@ -3,11 +3,14 @@
 //!
 //! This component exports:
 //! - `wasi:http/incoming-handler` - HTTP server for A2A requests
-//! - `a2a:protocol/client` - Client interface for calling other agents
+//! - `a2a:protocol/client` - Client interface for calling other agents: the
+//!   full A2A service, with streaming calls returning an `event-stream`
+//!   resource the guest reads events from
 //!
 //! And imports:
 //! - `wasi:http/outgoing-handler` - For client HTTP requests
-//! - `a2a:protocol/agent` - Host-provided agent logic
+//! - `a2a:protocol/agent` - Agent logic, provided by the host or by an
+//!   `a2a-agent` component composed with this one
 //!
 //! # HTTP Endpoints
 //!
@@ -15,26 +18,50 @@
 //! - `GET /.well-known/agent-card.json` - Agent card discovery
 //!
 //! ## JSON-RPC Binding
-//! - `POST /` - JSON-RPC (message/send, tasks/get, tasks/cancel)
+//! - `POST /` - JSON-RPC (SendMessage, SendStreamingMessage, GetTask,
+//!   CancelTask, SubscribeToTask; the 0.2/0.3 names such as message/send and
+//!   tasks/resubscribe are also accepted)
 //!
 //! ## REST Binding (HTTP+JSON)
 //! - `POST /v1/message:send` - Send a message
+//! - `POST /v1/message:stream` - Send a message, streaming events (SSE)
 //! - `GET /v1/tasks/{id}` - Get task by ID
 //! - `GET /v1/tasks/{id}?historyLength=N` - Get task with history
 //! - `POST /v1/tasks/{id}:cancel` - Cancel a task
+//! - `GET /v1/tasks/{id}:subscribe` - Stream a task's events (SSE)
 //! - `GET /v1/agentCard` - Extended agent card (authenticated)
 //!
-//! # Limitations
+//! Bodies are JSON or, negotiated via `Content-Type` and `Accept`, binary
+//! protobuf (`application/x-protobuf`).
 //!
-//! - Only `TextPart` is supported; `FilePart` and `DataPart` return errors
-//! - Streaming (message/stream) is not yet implemented
-//! - Metadata fields are not supported (deferred)
+//! ## Multi-tenancy
+//! Every route may be prefixed with `/{tenant}`, e.g.
+//! `GET /{tenant}/.well-known/agent-card.json` for the tenant's own card or
+//! `POST /{tenant}/v1/message:send`. The tenant may instead come from the
+//! `tenant` params field, a REST task path (`/v1/{tenant}/tasks/{id}`), or
+//! the header named by the `A2A_TENANT_HEADER` environment variable; every
+//! agent call receives it.
+//!
+//! Request bodies are limited to 1 MiB, or the number of bytes in the
+//! `A2A_MAX_BODY_SIZE` environment variable; larger requests get a 413. The
+//! client applies the same limit to the responses of remote agents.
+//!
+//! Streaming requests call the agent's `on-message-stream` or
+//! `on-subscribe-task`, and each event the returned `event-stream` yields is
+//! written to the response body as a server-sent event.
+//!
+//! Metadata maps cross the WIT boundary as `json-value` entries; arrays and
+//! objects are carried as serialized JSON text since WIT types can't be
+//! recursive. Agent errors may attach `data`, which is returned as the
+//! JSON-RPC error data or the REST error body's `data` field.
 
+mod body;
 mod client;
 mod convert;
 mod jsonrpc;
 mod rest;
 mod server;
+mod tenant;
 
 // Generate WIT bindings
 wit_bindgen::generate!({
@@ -51,6 +78,12 @@ struct Component;
 export!(Component);
 
 impl client_exports::Guest for Component {
+    type EventStream = client::EventStream;
+
+    fn get_agent_card(agent_url: String) -> Result<String, client_exports::Error> {
+        client::get_agent_card(agent_url)
+    }
+
     fn send_message(
         agent_url: String,
         params: client_exports::MessageSendParams,
@@ -58,6 +91,13 @@ impl client_exports::Guest for Component {
         client::send_message(agent_url, params)
     }
 
+    fn send_streaming_message(
+        agent_url: String,
+        params: client_exports::MessageSendParams,
+    ) -> Result<client_exports::EventStream, client_exports::Error> {
+        client::send_streaming_message(agent_url, params).map(client_exports::EventStream::new)
+    }
+
     fn get_task(
         agent_url: String,
         name: String,
@@ -66,12 +106,58 @@ impl client_exports::Guest for Component {
         client::get_task(agent_url, name, history_length)
     }
 
+    fn list_tasks(
+        agent_url: String,
+        params: client_exports::ListTasksParams,
+    ) -> Result<client_exports::ListTasksResponse, client_exports::Error> {
+        client::list_tasks(agent_url, params)
+    }
+
     fn cancel_task(
         agent_url: String,
         name: String,
     ) -> Result<Option<client_exports::Task>, client_exports::Error> {
         client::cancel_task(agent_url, name)
     }
+
+    fn subscribe_to_task(
+        agent_url: String,
+        name: String,
+    ) -> Result<client_exports::EventStream, client_exports::Error> {
+        client::subscribe_to_task(agent_url, name).map(client_exports::EventStream::new)
+    }
+
+    fn set_task_push_notification_config(
+        agent_url: String,
+        parent: String,
+        config_id: String,
+        config: client_exports::PushNotificationConfig,
+    ) -> Result<client_exports::TaskPushNotificationConfig, client_exports::Error> {
+        client::set_task_push_notification_config(agent_url, parent, config_id, config)
+    }
+
+    fn get_task_push_notification_config(
+        agent_url: String,
+        name: String,
+    ) -> Result<Option<client_exports::TaskPushNotificationConfig>, client_exports::Error> {
+        client::get_task_push_notification_config(agent_url, name)
+    }
+
+    fn list_task_push_notification_config(
+        agent_url: String,
+        parent: String,
+        page_size: Option<u32>,
+        page_token: Option<String>,
+    ) -> Result<client_exports::ListTaskPushNotificationConfigResponse, client_exports::Error> {
+        client::list_task_push_notification_config(agent_url, parent, page_size, page_token)
+    }
+
+    fn delete_task_push_notification_config(
+        agent_url: String,
+        name: String,
+    ) -> Result<(), client_exports::Error> {
+        client::delete_task_push_notification_config(agent_url, name)
+    }
 }
 
 // HTTP handler implementation is in server.rs
@@ -1,223 +1,319 @@
 //! REST binding implementation for A2A server.
 //!
-//! Handles `/v1/*` paths per A2A HTTP+JSON/REST binding spec.
-
+//! Handles `/v1/*` paths per A2A HTTP+JSON/REST binding spec. Task paths may
+//! name a tenant, `/v1/{tenant}/tasks/{id}`, which must match the tenant the
+//! request was routed for.
+//!
+//! Request bodies are decoded per their `Content-Type` and responses encoded
+//! per `Accept`, as JSON or binary protobuf (`application/x-protobuf`).
+//! Error bodies are always JSON. Streaming routes answer with server-sent
+//! events whose data are JSON `StreamResponse`s.
+
+use crate::a2a::protocol::agent::EventStream;
+use crate::a2a::protocol::types::Error;
+use crate::body;
 use crate::convert;
+use crate::server::{Extensions, Reply};
+use crate::tenant;
 use crate::wasi::http::types::{IncomingRequest, Method};
+use a2a_types::TaskName;
+use a2a_types::codec::Codec;
 use serde::Serialize;
 
-/// Handle a REST request.
+/// Handle a REST request for the tenant resolved from the request,
+/// negotiating the requested `extensions`.
 pub fn handle(
     method: Method,
     path: &str,
     request: &IncomingRequest,
-) -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
-    match (method, path) {
-        // POST /v1/message:send
-        (Method::Post, "/v1/message:send") => handle_send_message(request),
+    tenant: Option<&str>,
+    extensions: &Extensions,
+) -> Result<Reply, (u16, String)> {
+    let (route, query) = match path.split_once('?') {
+        Some((route, query)) => (route, Some(query)),
+        None => (path, None),
+    };
 
-        // GET /v1/tasks/{id} or GET /v1/tasks/{id}?historyLength=N
-        (Method::Get, p) if is_task_get(p) => {
-            let (task_id, history_length) = parse_task_get(p)?;
-            handle_get_task(&task_id, history_length)
+    match (method, route) {
+        // POST /v1/message:send
+        (Method::Post, "/v1/message:send") => {
+            handle_send_message(request, tenant, extensions, response_codec(request)?).map(full)
         }
 
-        // POST /v1/tasks/{id}:cancel
-        (Method::Post, p) if p.starts_with("/v1/tasks/") && p.ends_with(":cancel") => {
-            let task_id = extract_task_id_before_action(p)?;
-            handle_cancel_task(&task_id)
+        // POST /v1/message:stream
+        (Method::Post, "/v1/message:stream") => {
+            handle_send_streaming_message(request, tenant, extensions)
         }
 
         // GET /v1/agentCard (extended, authenticated)
-        (Method::Get, "/v1/agentCard") => handle_extended_agent_card(),
+        (Method::Get, "/v1/agentCard") => handle_extended_agent_card(tenant).map(full),
+
+        (method, route) => match (method, parse_task_route(route, tenant)?) {
+            // GET /v1/[{tenant}/]tasks/{id} or GET /v1/tasks/{id}?historyLength=N
+            (Method::Get, Some((name, None))) => handle_get_task(
+                &name,
+                extensions,
+                history_length(query),
+                response_codec(request)?,
+            )
+            .map(full),
+
+            // POST /v1/[{tenant}/]tasks/{id}:cancel
+            (Method::Post, Some((name, Some("cancel")))) => {
+                handle_cancel_task(&name, extensions, response_codec(request)?).map(full)
+            }
 
-        _ => Err((404, "Not Found".to_string())),
-    }
-}
+            // GET /v1/[{tenant}/]tasks/{id}:subscribe
+            (Method::Get, Some((name, Some("subscribe")))) => {
+                handle_subscribe_task(&name, extensions)
+            }
 
-fn is_task_get(path: &str) -> bool {
-    path.starts_with("/v1/tasks/") && !path.ends_with(":cancel")
+            _ => Err((404, "Not Found".to_string())),
+        },
+    }
 }
 
-fn parse_task_get(path: &str) -> Result<(String, Option<u32>), (u16, String)> {
-    // Path format: /v1/tasks/{id} or /v1/tasks/{id}?historyLength=N
-    let path = path.strip_prefix("/v1/tasks/").unwrap_or(path);
-
-    let (task_id, query) = match path.split_once('?') {
-        Some((id, q)) => (id, Some(q)),
-        None => (path, None),
+/// A task name and the custom method (`cancel`, ...) applied to it.
+type TaskRoute<'a> = (TaskName, Option<&'a str>);
+
+/// Parse `/v1/{task name}[:{action}]`; `None` when the route isn't a task.
+///
+/// The name is scoped to `tenant`, which a tenant in the name must match.
+fn parse_task_route<'a>(
+    route: &'a str,
+    tenant: Option<&str>,
+) -> Result<Option<TaskRoute<'a>>, (u16, String)> {
+    let Some(rest) = route.strip_prefix("/v1/") else {
+        return Ok(None);
     };
-
-    if task_id.is_empty() {
-        return Err((400, "Missing task ID".to_string()));
+    let (name, action) = match rest.split_once(':') {
+        Some((name, action)) => (name, Some(action)),
+        None => (rest, None),
+    };
+    if !name.split('/').any(|segment| segment == "tasks") {
+        return Ok(None);
     }
+    let name = name
+        .parse::<TaskName>()
+        .map_err(|e| (400, format!("Invalid task name: {e}")))?;
+    let tenant = tenant::merge(tenant, name.tenant())
+        .map_err(|e| (400, e))?
+        .map(str::to_string);
+    let name = match tenant {
+        Some(tenant) => name.with_tenant(tenant),
+        None => name,
+    };
+    Ok(Some((name, action)))
+}
 
-    let history_length = query
+fn history_length(query: Option<&str>) -> Option<u32> {
+    query
         .and_then(|q| {
             q.split('&')
                 .find_map(|param| param.strip_prefix("historyLength="))
         })
-        .and_then(|v| v.parse().ok());
-
-    Ok((task_id.to_string(), history_length))
+        .and_then(|v| v.parse().ok())
 }
 
-fn extract_task_id_before_action(path: &str) -> Result<String, (u16, String)> {
-    // Path format: /v1/tasks/{id}:cancel
-    let path = path
-        .strip_prefix("/v1/tasks/")
-        .ok_or((400, "Invalid path".to_string()))?;
+pub(crate) fn header(request: &IncomingRequest, name: &str) -> Option<String> {
+    let values = request.headers().get(name);
+    values
+        .first()
+        .and_then(|value| String::from_utf8(value.clone()).ok())
+}
 
-    let task_id = path
-        .strip_suffix(":cancel")
-        .ok_or((400, "Invalid path".to_string()))?;
+/// Negotiate the response codec from the `Accept` header.
+fn response_codec(request: &IncomingRequest) -> Result<Codec, (u16, String)> {
+    Codec::negotiate(header(request, "accept").as_deref()).ok_or_else(|| {
+        (
+            406,
+            "Not Acceptable: supported media types are application/json and application/x-protobuf"
+                .to_string(),
+        )
+    })
+}
 
-    if task_id.is_empty() {
-        return Err((400, "Missing task ID".to_string()));
+/// The codec of the request body; JSON when there is no `Content-Type`.
+fn request_codec(request: &IncomingRequest) -> Result<Codec, (u16, String)> {
+    match header(request, "content-type") {
+        Some(content_type) => Codec::from_content_type(&content_type)
+            .ok_or_else(|| (415, format!("Unsupported Media Type: {content_type}"))),
+        None => Ok(Codec::Json),
     }
-
-    Ok(task_id.to_string())
 }
 
 fn handle_send_message(
     request: &IncomingRequest,
+    tenant: Option<&str>,
+    extensions: &Extensions,
+    codec: Codec,
 ) -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
     use crate::a2a::protocol::agent;
 
-    let body = read_request_body(request)?;
+    let request_codec = request_codec(request)?;
+    let body = body::read_request(request)?;
 
     // In prost-generated types, MessageSendParams is now SendMessageRequest
     let params: a2a_types::SendMessageRequest =
-        serde_json::from_slice(&body).map_err(|e| (400, format!("Invalid JSON: {e}")))?;
+        request_codec.decode(&body).map_err(|e| match e {
+            a2a_types::error::Error::Json(e) => (400, format!("Invalid JSON: {e}")),
+            e => (400, format!("Invalid request body: {e}")),
+        })?;
 
     let wit_params = convert::message_send_params_to_wit(&params)
         .map_err(|e| (400, format!("Invalid params: {e}")))?;
 
-    // Extract tenant from params (empty string means no tenant)
-    let tenant = if params.tenant.is_empty() {
-        None
-    } else {
-        Some(params.tenant.as_str())
-    };
+    let tenant = tenant::merge(tenant, Some(&params.tenant)).map_err(|e| (400, e))?;
+    let context = extensions.context(tenant).map_err(|e| (400, e))?;
 
-    match agent::on_message(tenant, &wit_params) {
-        Ok(response) => {
-            let a2a_response = convert::send_response_from_wit(&response);
-            let body = serde_json::to_vec(&a2a_response).unwrap_or_default();
-            Ok((200, "application/json", body))
-        }
-        Err(e) => {
-            let body = serde_json::to_vec(&ErrorResponse { error: e.message }).unwrap_or_default();
-            Ok((500, "application/json", body))
-        }
+    match agent::on_message(&context, &wit_params) {
+        Ok(response) => match convert::send_response_from_wit(&response) {
+            Ok(a2a_response) => {
+                let body = codec.encode(&a2a_response).unwrap_or_default();
+                Ok((200, codec.media_type(), body))
+            }
+            Err(e) => Ok(invalid_agent_response(e)),
+        },
+        Err(e) => Ok(agent_error(e)),
     }
 }
 
-fn handle_get_task(
-    task_id: &str,
-    history_length: Option<u32>,
-) -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
+fn handle_send_streaming_message(
+    request: &IncomingRequest,
+    tenant: Option<&str>,
+    extensions: &Extensions,
+) -> Result<Reply, (u16, String)> {
     use crate::a2a::protocol::agent;
 
-    // Construct resource name from task ID per A2A spec
-    let name = format!("tasks/{task_id}");
+    let request_codec = request_codec(request)?;
+    let body = body::read_request(request)?;
 
-    // TODO: Extract tenant from request headers or path for multi-tenancy
-    match agent::on_get_task(None, &name, history_length) {
-        Ok(Some(task)) => {
-            let a2a_task = convert::task_from_wit(&task);
-            let body = serde_json::to_vec(&a2a_task).unwrap_or_default();
-            Ok((200, "application/json", body))
-        }
-        Ok(None) => {
-            let body = serde_json::to_vec(&ErrorResponse {
-                error: "Task not found".to_string(),
-            })
-            .unwrap_or_default();
-            Ok((404, "application/json", body))
-        }
-        Err(e) => {
-            let body = serde_json::to_vec(&ErrorResponse { error: e.message }).unwrap_or_default();
-            Ok((500, "application/json", body))
-        }
+    let params: a2a_types::SendMessageRequest =
+        request_codec.decode(&body).map_err(|e| match e {
+            a2a_types::error::Error::Json(e) => (400, format!("Invalid JSON: {e}")),
+            e => (400, format!("Invalid request body: {e}")),
+        })?;
+
+    let wit_params = convert::message_send_params_to_wit(&params)
+        .map_err(|e| (400, format!("Invalid params: {e}")))?;
+
+    let tenant = tenant::merge(tenant, Some(&params.tenant)).map_err(|e| (400, e))?;
+    let context = extensions.context(tenant).map_err(|e| (400, e))?;
+
+    match agent::on_message_stream(&context, &wit_params) {
+        Ok(stream) => Ok(events(stream)),
+        Err(e) => Ok(full(agent_error(e))),
     }
 }
 
-fn handle_cancel_task(task_id: &str) -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
+fn handle_subscribe_task(name: &TaskName, extensions: &Extensions) -> Result<Reply, (u16, String)> {
     use crate::a2a::protocol::agent;
 
-    // Construct resource name from task ID per A2A spec
-    let name = format!("tasks/{task_id}");
-
-    // TODO: Extract tenant from request headers or path for multi-tenancy
-    match agent::on_cancel_task(None, &name) {
-        Ok(Some(task)) => {
-            let a2a_task = convert::task_from_wit(&task);
-            let body = serde_json::to_vec(&a2a_task).unwrap_or_default();
-            Ok((200, "application/json", body))
-        }
-        Ok(None) => {
-            let body = serde_json::to_vec(&ErrorResponse {
-                error: "Task not found".to_string(),
-            })
-            .unwrap_or_default();
-            Ok((404, "application/json", body))
-        }
-        Err(e) => {
-            let body = serde_json::to_vec(&ErrorResponse { error: e.message }).unwrap_or_default();
-            Ok((500, "application/json", body))
-        }
+    let context = extensions.context(name.tenant()).map_err(|e| (400, e))?;
+    match agent::on_subscribe_task(&context, &name.resource_name()) {
+        Ok(stream) => Ok(events(stream)),
+        Err(e) => Ok(full(agent_error(e))),
     }
 }
 
-fn handle_extended_agent_card() -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
+/// Relay an agent event stream; an error ends it with an `{"error": ...}` event.
+fn events(stream: EventStream) -> Reply {
+    Reply::events(stream, |event| match event {
+        Ok(event) => serde_json::to_vec(&event).unwrap_or_default(),
+        Err(e) => agent_error(e).2,
+    })
+}
+
+fn full((status, content_type, body): (u16, &'static str, Vec<u8>)) -> Reply {
+    Reply::Full(status, content_type, body)
+}
+
+fn handle_get_task(
+    name: &TaskName,
+    extensions: &Extensions,
+    history_length: Option<u32>,
+    codec: Codec,
+) -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
     use crate::a2a::protocol::agent;
 
-    // For now, return the same card as the public one
-    // In the future, this could return additional authenticated details
-    // TODO: Extract tenant from request headers or path for multi-tenancy
-    match agent::get_agent_card(None) {
-        Ok(card_json) => Ok((200, "application/json", card_json.into_bytes())),
-        Err(e) => {
-            let body = serde_json::to_vec(&ErrorResponse { error: e.message }).unwrap_or_default();
-            Ok((500, "application/json", body))
-        }
+    let context = extensions.context(name.tenant()).map_err(|e| (400, e))?;
+    match agent::on_get_task(&context, &name.resource_name(), history_length) {
+        Ok(Some(task)) => match convert::task_from_wit(&task) {
+            Ok(a2a_task) => {
+                let body = codec.encode(&a2a_task).unwrap_or_default();
+                Ok((200, codec.media_type(), body))
+            }
+            Err(e) => Ok(invalid_agent_response(e)),
+        },
+        Ok(None) => Ok(error_body(404, "Task not found", None)),
+        Err(e) => Ok(agent_error(e)),
     }
 }
 
-/// Maximum request body size (1 MB).
-const MAX_BODY_SIZE: usize = 1024 * 1024;
-
-fn read_request_body(request: &IncomingRequest) -> Result<Vec<u8>, (u16, String)> {
-    let body = request
-        .consume()
-        .map_err(|_| (500, "Failed to consume request body".to_string()))?;
-
-    let stream = body
-        .stream()
-        .map_err(|_| (500, "Failed to get body stream".to_string()))?;
-
-    let mut data = Vec::new();
-    loop {
-        match stream.blocking_read(64 * 1024) {
-            Ok(chunk) => {
-                if chunk.is_empty() {
-                    break;
-                }
-                if data.len() + chunk.len() > MAX_BODY_SIZE {
-                    return Err((413, "Request body too large".to_string()));
-                }
-                data.extend_from_slice(&chunk);
+fn handle_cancel_task(
+    name: &TaskName,
+    extensions: &Extensions,
+    codec: Codec,
+) -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
+    use crate::a2a::protocol::agent;
+
+    let context = extensions.context(name.tenant()).map_err(|e| (400, e))?;
+    match agent::on_cancel_task(&context, &name.resource_name()) {
+        Ok(Some(task)) => match convert::task_from_wit(&task) {
+            Ok(a2a_task) => {
+                let body = codec.encode(&a2a_task).unwrap_or_default();
+                Ok((200, codec.media_type(), body))
             }
-            Err(crate::wasi::io::streams::StreamError::Closed) => break,
-            Err(_) => return Err((500, "Failed to read body".to_string())),
-        }
+            Err(e) => Ok(invalid_agent_response(e)),
+        },
+        Ok(None) => Ok(error_body(404, "Task not found", None)),
+        Err(e) => Ok(agent_error(e)),
     }
+}
 
-    Ok(data)
+fn handle_extended_agent_card(
+    tenant: Option<&str>,
+) -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
+    use crate::a2a::protocol::agent;
+
+    // For now, return the same card as the public one
+    // In the future, this could return additional authenticated details
+    match agent::get_agent_card(tenant) {
+        Ok(card_json) => Ok((200, "application/json", card_json.into_bytes())),
+        Err(e) => Ok(agent_error(e)),
+    }
 }
 
 #[derive(Serialize)]
 struct ErrorResponse {
     error: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<serde_json::Value>,
+}
+
+/// A JSON error body.
+fn error_body(
+    status: u16,
+    error: impl Into<String>,
+    data: Option<serde_json::Value>,
+) -> (u16, &'static str, Vec<u8>) {
+    let error = ErrorResponse {
+        error: error.into(),
+        data,
+    };
+    let body = serde_json::to_vec(&error).unwrap_or_default();
+    (status, "application/json", body)
+}
+
+/// The error body for an error returned by the agent, with its data.
+fn agent_error(error: Error) -> (u16, &'static str, Vec<u8>) {
+    let data = error
+        .data
+        .as_ref()
+        .and_then(|data| convert::json_value_to_json(data).ok());
+    error_body(500, error.message, data)
+}
+
+fn invalid_agent_response(error: String) -> (u16, &'static str, Vec<u8>) {
+    error_body(500, format!("Invalid agent response: {error}"), None)
 }
@@ -4,63 +4,184 @@
 //! - JSON-RPC: `POST /`
 //! - REST: `/v1/*` paths
 //! - Agent card: `GET /.well-known/agent-card.json`
+//!
+//! Each route may be prefixed with `/{tenant}`; see [`crate::tenant`] for how
+//! the tenant passed to the agent is resolved.
+//!
+//! Streaming requests (`SendStreamingMessage`, `SubscribeToTask` and their
+//! REST routes) answer with server-sent events, written to the outgoing body
+//! as the agent's `event-stream` yields them.
+//!
+//! JSON-RPC and REST requests negotiate extensions against the agent card of
+//! their tenant, once the handler resolved it from the params (see
+//! [`Extensions`]). Requests missing a required extension fail, and the
+//! activated extensions are echoed in the `X-A2A-Extensions` response header
+//! and passed to the agent in the [`RequestContext`] of the request.
 
+use crate::a2a::protocol::agent::EventStream;
+use crate::a2a::protocol::types::{Error, RequestContext};
+use crate::convert;
 use crate::exports::wasi::http::incoming_handler::Guest;
 use crate::jsonrpc;
+use crate::tenant;
 use crate::wasi::http::types::{
     Headers, IncomingRequest, OutgoingBody, OutgoingResponse, ResponseOutparam,
 };
+use a2a_types::extension::{self, ExtensionRegistry};
+use std::cell::RefCell;
+
+/// A handler's response.
+pub enum Reply {
+    /// A complete body: status, content type and bytes.
+    Full(u16, &'static str, Vec<u8>),
+    /// Server-sent events, each item the `data` of one event.
+    Events(Box<dyn Iterator<Item = Vec<u8>>>),
+}
+
+impl Reply {
+    /// Relay an agent event stream, encoding each event (or the error ending
+    /// the stream) with `encode`.
+    pub fn events(
+        stream: EventStream,
+        encode: impl Fn(Result<a2a_types::StreamResponse, Error>) -> Vec<u8> + 'static,
+    ) -> Self {
+        let mut done = false;
+        Self::Events(Box::new(std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+            let event = match stream.next() {
+                Ok(Some(event)) => convert::stream_response_from_wit(&event).map_err(|e| Error {
+                    code: jsonrpc::INTERNAL_ERROR,
+                    message: format!("Invalid agent event: {e}"),
+                    data: None,
+                }),
+                Ok(None) => return None,
+                Err(e) => Err(e),
+            };
+            done = event.is_err();
+            Some(encode(event))
+        })))
+    }
+}
 
 impl Guest for crate::Component {
     fn handle(request: IncomingRequest, response_out: ResponseOutparam) {
-        let result = handle_request(request);
+        let extensions = Extensions::requested(&request);
+        let result = handle_request(request, &extensions);
+        let extensions = extensions.activated.into_inner();
 
         match result {
-            Ok((status, content_type, body)) => {
-                send_response(response_out, status, content_type, body);
+            Ok(Reply::Full(status, content_type, body)) => {
+                send_response(response_out, status, content_type, &extensions, body);
+            }
+            Ok(Reply::Events(events)) => {
+                send_events(response_out, &extensions, events);
             }
             Err((status, message)) => {
-                send_response(response_out, status, "text/plain", message.into_bytes());
+                send_response(
+                    response_out,
+                    status,
+                    "text/plain",
+                    &extensions,
+                    message.into_bytes(),
+                );
             }
         }
     }
 }
 
-fn handle_request(request: IncomingRequest) -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
+/// Handle a request, negotiating the requested `extensions`.
+fn handle_request(
+    request: IncomingRequest,
+    extensions: &Extensions,
+) -> Result<Reply, (u16, String)> {
     let method = request.method();
-    let path = request.path_with_query().unwrap_or_default();
+    let path_with_query = request.path_with_query().unwrap_or_default();
+
+    let (path_tenant, path) = tenant::split_path(&path_with_query);
+    let header_tenant = tenant::from_header(&request);
+    let tenant = tenant::merge(path_tenant, header_tenant.as_deref()).map_err(|e| (400, e))?;
 
     // Agent card discovery (new spec-compliant path)
     if path == "/.well-known/agent-card.json"
         && matches!(method, crate::wasi::http::types::Method::Get)
     {
-        return handle_agent_card();
+        return handle_agent_card(tenant)
+            .map(|(status, content_type, body)| Reply::Full(status, content_type, body));
     }
 
     // REST binding: /v1/* paths
     if path.starts_with("/v1/") {
-        return crate::rest::handle(method, &path, &request);
+        return crate::rest::handle(method, path, &request, tenant, extensions);
     }
 
     // JSON-RPC binding: POST /
     if (path == "/" || path.is_empty()) && matches!(method, crate::wasi::http::types::Method::Post)
     {
-        return jsonrpc::handle(&request);
+        return jsonrpc::handle(&request, tenant, extensions);
     }
 
     // CORS preflight
     if matches!(method, crate::wasi::http::types::Method::Options) {
-        return Ok((204, "text/plain", vec![]));
+        return Ok(Reply::Full(204, "text/plain", vec![]));
     }
 
     Err((404, "Not Found".to_string()))
 }
 
-fn handle_agent_card() -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
+/// Extension negotiation of a request.
+///
+/// A request's tenant may come from its params, so handlers activate the
+/// requested extensions only once they resolved it. The activated ones are
+/// kept for the response header.
+pub struct Extensions {
+    requested: Vec<String>,
+    activated: RefCell<Vec<String>>,
+}
+
+impl Extensions {
+    /// The extensions requested in the `X-A2A-Extensions` header.
+    fn requested(request: &IncomingRequest) -> Self {
+        let requested = crate::rest::header(request, extension::HEADER)
+            .map(|value| extension::parse_header(&value))
+            .unwrap_or_default();
+        Self {
+            requested,
+            activated: RefCell::default(),
+        }
+    }
+
+    /// The context passed to the agent for a request of `tenant`, with the
+    /// requested extensions the tenant's agent card declares.
+    ///
+    /// Without a readable card no extensions are declared, so none activate.
+    /// Fails if the card requires an extension that wasn't requested.
+    pub fn context(&self, tenant: Option<&str>) -> Result<RequestContext, String> {
+        use crate::a2a::protocol::agent;
+
+        let registry = agent::get_agent_card(tenant)
+            .ok()
+            .and_then(|card_json| serde_json::from_str(&card_json).ok())
+            .and_then(|value| a2a_types::card::from_json(value).ok())
+            .map(|card| ExtensionRegistry::from_card(&card))
+            .unwrap_or_default();
+        let activated = registry
+            .activate(&self.requested)
+            .map_err(|e| e.to_string())?;
+        self.activated.replace(activated.clone());
+        Ok(RequestContext {
+            tenant: tenant.map(str::to_string),
+            extensions: activated,
+        })
+    }
+}
+
+/// Serve the agent card of the request's tenant.
+fn handle_agent_card(tenant: Option<&str>) -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
     use crate::a2a::protocol::agent;
 
-    // TODO: Extract tenant from URL path for multi-tenancy support
-    match agent::get_agent_card(None) {
+    match agent::get_agent_card(tenant) {
         Ok(card_json) => Ok((200, "application/json", card_json.into_bytes())),
         Err(e) => {
             let response = jsonrpc::Response::internal_error(serde_json::Value::Null, e.message);
@@ -70,11 +191,14 @@ fn handle_agent_card() -> Result<(u16, &'static str, Vec<u8>), (u16, String)> {
     }
 }
 
-fn send_response(response_out: ResponseOutparam, status: u16, content_type: &str, body: Vec<u8>) {
-    let headers = Headers::new();
-    headers
-        .set("Content-Type", &[content_type.as_bytes().to_vec()])
-        .ok();
+fn send_response(
+    response_out: ResponseOutparam,
+    status: u16,
+    content_type: &str,
+    extensions: &[String],
+    body: Vec<u8>,
+) {
+    let headers = response_headers(content_type, extensions);
     headers
         .set("Content-Length", &[body.len().to_string().into_bytes()])
         .ok();
@@ -86,8 +210,64 @@ fn send_response(response_out: ResponseOutparam, status: u16, content_type: &str
     ResponseOutparam::set(response_out, Ok(response));
 
     let stream = outgoing_body.write().unwrap();
-    stream.blocking_write_and_flush(&body).ok();
+    write_all(&stream, &body);
     drop(stream);
 
     OutgoingBody::finish(outgoing_body, None).ok();
 }
+
+/// Send a `text/event-stream` response, flushing each event as it arrives.
+///
+/// Stops early if the client goes away.
+fn send_events(
+    response_out: ResponseOutparam,
+    extensions: &[String],
+    events: Box<dyn Iterator<Item = Vec<u8>>>,
+) {
+    let headers = response_headers("text/event-stream", extensions);
+    headers.set("Cache-Control", &[b"no-cache".to_vec()]).ok();
+
+    let response = OutgoingResponse::new(headers);
+    response.set_status_code(200).ok();
+
+    let outgoing_body = response.body().unwrap();
+    ResponseOutparam::set(response_out, Ok(response));
+
+    let stream = outgoing_body.write().unwrap();
+    for data in events {
+        let mut event = Vec::with_capacity(data.len() + 8);
+        event.extend_from_slice(b"data: ");
+        event.extend_from_slice(&data);
+        event.extend_from_slice(b"\n\n");
+        if !write_all(&stream, &event) {
+            break;
+        }
+    }
+    drop(stream);
+
+    OutgoingBody::finish(outgoing_body, None).ok();
+}
+
+fn response_headers(content_type: &str, extensions: &[String]) -> Headers {
+    let headers = Headers::new();
+    headers
+        .set("Content-Type", &[content_type.as_bytes().to_vec()])
+        .ok();
+    if !extensions.is_empty() {
+        headers
+            .set(
+                extension::HEADER,
+                &[extension::format_header(extensions).into_bytes()],
+            )
+            .ok();
+    }
+    headers
+}
+
+/// Write and flush `data`; false if the stream is closed.
+fn write_all(stream: &crate::wasi::io::streams::OutputStream, data: &[u8]) -> bool {
+    // blocking_write_and_flush accepts at most 4096 bytes per call
+    const CHUNK_SIZE: usize = 4096;
+    data.chunks(CHUNK_SIZE)
+        .all(|chunk| stream.blocking_write_and_flush(chunk).is_ok())
+}
@@ -0,0 +1,102 @@
+//! Tenant resolution for multi-tenant agents.
+//!
+//! A request's tenant comes from a `/{tenant}` path prefix in front of the
+//! binding routes (`/{tenant}/v1/...`, `POST /{tenant}/`,
+//! `/{tenant}/.well-known/agent-card.json`), or from the header named by the
+//! `A2A_TENANT_HEADER` environment variable. The header is off by default:
+//! enable it only behind a gateway that sets it, since clients could
+//! otherwise pick any tenant.
+//!
+//! Requests may also name a tenant themselves, in the `tenant` params field
+//! or a REST task path (`/v1/{tenant}/tasks/{id}`). All the tenants a request
+//! names must agree; the agent gets the one they agree on.
+
+use crate::wasi::http::types::IncomingRequest;
+
+/// Environment variable naming the header that carries the tenant.
+pub const HEADER_ENV: &str = "A2A_TENANT_HEADER";
+
+/// First path segments that belong to the routes rather than a tenant.
+const RESERVED: [&str; 2] = ["v1", ".well-known"];
+
+/// Split a `/{tenant}` prefix off `path`.
+///
+/// The remaining path keeps its leading `/`, so `/acme/v1/message:send`
+/// becomes `(Some("acme"), "/v1/message:send")` and `/acme` becomes
+/// `(Some("acme"), "/")`.
+pub fn split_path(path: &str) -> (Option<&str>, &str) {
+    let Some(rest) = path.strip_prefix('/') else {
+        return (None, path);
+    };
+    let end = rest.find(['/', '?']).unwrap_or(rest.len());
+    let (segment, remainder) = rest.split_at(end);
+    if segment.is_empty() || RESERVED.contains(&segment) || segment.contains([':', '#']) {
+        return (None, path);
+    }
+    if remainder.starts_with('/') {
+        (Some(segment), remainder)
+    } else {
+        // `/{tenant}` or `/{tenant}?query`: the JSON-RPC route of the tenant
+        (Some(segment), "/")
+    }
+}
+
+/// The tenant in the configured tenant header, if enabled and present.
+pub fn from_header(request: &IncomingRequest) -> Option<String> {
+    let name = std::env::var(HEADER_ENV)
+        .ok()
+        .filter(|name| !name.is_empty())?;
+    crate::rest::header(request, &name).filter(|tenant| !tenant.is_empty())
+}
+
+/// Combine two tenants named by a request, failing if they differ.
+pub fn merge<'a>(
+    tenant: Option<&'a str>,
+    other: Option<&'a str>,
+) -> Result<Option<&'a str>, String> {
+    match (tenant, other.filter(|other| !other.is_empty())) {
+        (Some(tenant), Some(other)) if tenant != other => Err(format!(
+            "Tenant `{other}` doesn't match the request's tenant `{tenant}`"
+        )),
+        (Some(tenant), _) => Ok(Some(tenant)),
+        (None, other) => Ok(other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_path() {
+        assert_eq!(split_path("/"), (None, "/"));
+        assert_eq!(split_path("/v1/message:send"), (None, "/v1/message:send"));
+        assert_eq!(
+            split_path("/.well-known/agent-card.json"),
+            (None, "/.well-known/agent-card.json")
+        );
+        assert_eq!(
+            split_path("/acme/v1/tasks/abc?historyLength=2"),
+            (Some("acme"), "/v1/tasks/abc?historyLength=2")
+        );
+        assert_eq!(
+            split_path("/acme/.well-known/agent-card.json"),
+            (Some("acme"), "/.well-known/agent-card.json")
+        );
+        assert_eq!(split_path("/acme"), (Some("acme"), "/"));
+        assert_eq!(split_path("/acme/"), (Some("acme"), "/"));
+        assert_eq!(split_path("/acme?x=1"), (Some("acme"), "/"));
+        // Custom methods aren't tenants
+        assert_eq!(split_path("/tasks:list"), (None, "/tasks:list"));
+    }
+
+    #[test]
+    fn test_merge() {
+        assert_eq!(merge(None, None), Ok(None));
+        assert_eq!(merge(None, Some("")), Ok(None));
+        assert_eq!(merge(Some("acme"), None), Ok(Some("acme")));
+        assert_eq!(merge(None, Some("acme")), Ok(Some("acme")));
+        assert_eq!(merge(Some("acme"), Some("acme")), Ok(Some("acme")));
+        assert!(merge(Some("acme"), Some("globex")).is_err());
+    }
+}
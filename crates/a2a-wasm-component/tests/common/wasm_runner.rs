@@ -4,7 +4,7 @@
 //! and calls its exported functions using wasmtime's component model.
 
 use serde_json::{Value, json};
-use wasmtime::component::{Component, Linker, ResourceTable};
+use wasmtime::component::{Component, Linker, Resource, ResourceTable};
 use wasmtime::{Config, Engine, Store};
 use wasmtime_wasi::{WasiCtx, WasiCtxBuilder, WasiView};
 use wasmtime_wasi_http::{WasiHttpCtx, WasiHttpView};
@@ -24,10 +24,10 @@ wasmtime::component::bindgen!({
 });
 
 // Re-export types from the generated bindings for convenience
-use a2a::protocol::agent::Host as AgentHost;
+use a2a::protocol::agent::{EventStream, Host as AgentHost, HostEventStream};
 use a2a::protocol::types::{
-    Error as A2aError, Message, MessageSendParams, Part, Role, SendResponse, Task, TaskState,
-    TaskStatus, TextPart,
+    Error as A2aError, Message, MessageSendParams, Part, RequestContext, Role, SendResponse,
+    StreamResponse, Task, TaskState, TaskStatus, TextPart,
 };
 
 /// State held by the wasmtime Store, providing WASI and HTTP contexts.
@@ -71,7 +71,7 @@ impl AgentHost for TestState {
     /// Process incoming message (blocking) - returns a simple echo response
     async fn on_message(
         &mut self,
-        _tenant: Option<String>,
+        _context: RequestContext,
         params: MessageSendParams,
     ) -> Result<SendResponse, A2aError> {
         // Extract text from the first part of the message for echoing
@@ -96,15 +96,20 @@ impl AgentHost for TestState {
                 state: TaskState::Completed,
                 message: Some(Message {
                     role: Role::Agent,
-                    parts: vec![Part::Text(TextPart { text: echo_text })],
+                    parts: vec![Part::Text(TextPart {
+                        text: echo_text,
+                        metadata: None,
+                    })],
                     message_id: Some("mock-response-id".to_string()),
                     task_id: Some("mock-task-id".to_string()),
                     context_id: None,
+                    metadata: None,
                 }),
                 timestamp: Some("2024-01-01T00:00:00Z".to_string()),
             },
             history: None,
             artifacts: None,
+            metadata: None,
         };
 
         Ok(SendResponse::Task(task))
@@ -113,7 +118,7 @@ impl AgentHost for TestState {
     /// Retrieve task by resource name - returns None (task not found)
     async fn on_get_task(
         &mut self,
-        _tenant: Option<String>,
+        _context: RequestContext,
         _name: String,
         _history_length: Option<u32>,
     ) -> Result<Option<Task>, A2aError> {
@@ -123,11 +128,51 @@ impl AgentHost for TestState {
     /// Handle cancellation - returns None (task not found)
     async fn on_cancel_task(
         &mut self,
-        _tenant: Option<String>,
+        _context: RequestContext,
         _name: String,
     ) -> Result<Option<Task>, A2aError> {
         Ok(None)
     }
+
+    /// Streaming is not mocked
+    async fn on_message_stream(
+        &mut self,
+        _context: RequestContext,
+        _params: MessageSendParams,
+    ) -> Result<Resource<EventStream>, A2aError> {
+        Err(streaming_unsupported())
+    }
+
+    /// Streaming is not mocked
+    async fn on_subscribe_task(
+        &mut self,
+        _context: RequestContext,
+        _name: String,
+    ) -> Result<Resource<EventStream>, A2aError> {
+        Err(streaming_unsupported())
+    }
+}
+
+/// The mock agent never creates event streams.
+impl HostEventStream for TestState {
+    async fn next(
+        &mut self,
+        _stream: Resource<EventStream>,
+    ) -> Result<Option<StreamResponse>, A2aError> {
+        Err(streaming_unsupported())
+    }
+
+    async fn drop(&mut self, _stream: Resource<EventStream>) -> wasmtime::Result<()> {
+        Ok(())
+    }
+}
+
+fn streaming_unsupported() -> A2aError {
+    A2aError {
+        code: -32004,
+        message: "Streaming is not supported".to_string(),
+        data: None,
+    }
 }
 
 /// WASM component runner for testing A2A client operations.
@@ -213,34 +258,7 @@ impl WasmRunner {
     ///
     /// The response as a JSON value, or an error string.
     pub async fn send_message(&mut self, url: &str, message_text: &str) -> Result<Value, String> {
-        use a2a::protocol::types::{
-            Message, MessageSendConfig, MessageSendParams, Part, Role, TextPart,
-        };
-
-        // Generate a unique message ID (simple counter-based for tests)
-        static MESSAGE_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
-        let message_id = format!(
-            "test-msg-{}",
-            MESSAGE_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
-        );
-
-        // Build the message parameters
-        let params = MessageSendParams {
-            message: Message {
-                role: Role::User,
-                parts: vec![Part::Text(TextPart {
-                    text: message_text.to_string(),
-                })],
-                message_id: Some(message_id),
-                task_id: None,
-                context_id: None,
-            },
-            configuration: Some(MessageSendConfig {
-                accepted_output_modes: None,
-                history_length: None,
-                blocking: Some(true),
-            }),
-        };
+        let params = text_message_params(message_text);
 
         // Call the component's send_message export
         let result = self
@@ -255,6 +273,64 @@ impl WasmRunner {
         }
     }
 
+    /// Send a message via the WASM component, collecting the streamed events.
+    ///
+    /// # Returns
+    ///
+    /// The events as JSON values, or an error string.
+    pub async fn send_streaming_message(
+        &mut self,
+        url: &str,
+        message_text: &str,
+    ) -> Result<Vec<Value>, String> {
+        let params = text_message_params(message_text);
+
+        let stream = self
+            .client
+            .call_send_streaming_message(&mut self.store, url, &params)
+            .await
+            .map_err(|e| format!("WASM trap: {e}"))?
+            .map_err(|err| format!("A2A error {}: {}", err.code, err.message))?;
+
+        let mut events = Vec::new();
+        let result = loop {
+            let next = self
+                .client
+                .event_stream()
+                .call_next(&mut self.store, stream)
+                .await
+                .map_err(|e| format!("WASM trap: {e}"))?;
+            match next {
+                Ok(Some(event)) => events.push(stream_response_to_json(&event)),
+                Ok(None) => break Ok(events),
+                Err(err) => break Err(format!("A2A error {}: {}", err.code, err.message)),
+            }
+        };
+        stream
+            .resource_drop_async(&mut self.store)
+            .await
+            .map_err(|e| format!("WASM trap: {e}"))?;
+        result
+    }
+
+    /// Get the agent card of an A2A agent via the WASM component.
+    ///
+    /// # Returns
+    ///
+    /// The card as a JSON value, or an error string.
+    pub async fn get_agent_card(&mut self, url: &str) -> Result<Value, String> {
+        let result = self
+            .client
+            .call_get_agent_card(&mut self.store, url)
+            .await
+            .map_err(|e| format!("WASM trap: {e}"))?;
+
+        match result {
+            Ok(card) => serde_json::from_str(&card).map_err(|e| format!("Invalid card: {e}")),
+            Err(err) => Err(format!("A2A error {}: {}", err.code, err.message)),
+        }
+    }
+
     /// Get a task by ID from an A2A agent via the WASM component.
     ///
     /// # Arguments
@@ -310,8 +386,69 @@ impl WasmRunner {
     }
 }
 
+/// Build message/send params for a single text part.
+fn text_message_params(message_text: &str) -> MessageSendParams {
+    use a2a::protocol::types::MessageSendConfig;
+
+    // Generate a unique message ID (simple counter-based for tests)
+    static MESSAGE_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+    let message_id = format!(
+        "test-msg-{}",
+        MESSAGE_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    );
+
+    MessageSendParams {
+        message: Message {
+            role: Role::User,
+            parts: vec![Part::Text(TextPart {
+                text: message_text.to_string(),
+                metadata: None,
+            })],
+            message_id: Some(message_id),
+            task_id: None,
+            context_id: None,
+            metadata: None,
+        },
+        configuration: Some(MessageSendConfig {
+            accepted_output_modes: None,
+            history_length: None,
+            blocking: Some(true),
+            push_notification_config: None,
+        }),
+        metadata: None,
+    }
+}
+
 // Helper functions to convert WIT types to JSON for snapshot testing
 
+fn stream_response_to_json(event: &StreamResponse) -> Value {
+    match event {
+        StreamResponse::Task(task) => json!({
+            "type": "task",
+            "task": task_to_json(task)
+        }),
+        StreamResponse::Message(msg) => json!({
+            "type": "message",
+            "message": message_to_json(msg)
+        }),
+        StreamResponse::StatusUpdate(update) => json!({
+            "type": "status-update",
+            "task_id": update.task_id,
+            "context_id": update.context_id,
+            "status": task_status_to_json(&update.status),
+            "final": update.final_,
+        }),
+        StreamResponse::ArtifactUpdate(update) => json!({
+            "type": "artifact-update",
+            "task_id": update.task_id,
+            "context_id": update.context_id,
+            "artifact": artifact_to_json(&update.artifact),
+            "append": update.append,
+            "last_chunk": update.last_chunk,
+        }),
+    }
+}
+
 fn send_response_to_json(response: &a2a::protocol::types::SendResponse) -> Value {
     use a2a::protocol::types::SendResponse;
     match response {
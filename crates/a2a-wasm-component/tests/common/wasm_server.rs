@@ -1,53 +1,28 @@
 //! WASM HTTP server for integration tests.
 //!
-//! This module provides a test harness that runs the WASM component as an HTTP server,
+//! This module runs the WASM component as an HTTP server with `a2a-wasm-host`,
 //! allowing external clients (like Python A2A SDK) to test against it.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::net::SocketAddr;
-use std::sync::{Arc, Mutex};
+use std::sync::Mutex;
 
-use hyper::body::Incoming;
-use hyper::server::conn::http1;
-use hyper::service::service_fn;
-use hyper::{Request, Response};
+use a2a_wasm_host::types::{
+    Artifact, Error as A2aError, JsonValue, Message, MessageSendParams, Part, RequestContext, Role,
+    SendResponse, StreamResponse, Task, TaskArtifactUpdateEvent, TaskState, TaskStatus,
+    TaskStatusUpdateEvent, TextPart,
+};
+use a2a_wasm_host::{A2aHost, Agent, EventStream};
 use tokio::net::TcpListener;
 use tokio::sync::oneshot;
-use wasmtime::component::{Component, Linker, ResourceTable};
-use wasmtime::{Config, Engine, Store};
-use wasmtime_wasi::{WasiCtx, WasiCtxBuilder, WasiView};
-use wasmtime_wasi_http::bindings::http::types::Scheme;
-use wasmtime_wasi_http::body::HyperOutgoingBody;
-use wasmtime_wasi_http::io::TokioIo;
-use wasmtime_wasi_http::{WasiHttpCtx, WasiHttpView};
-
-// Generate bindings for the a2a:protocol world from the WIT file.
-// Use `with` to reuse wasmtime_wasi_http's types instead of generating new ones.
-wasmtime::component::bindgen!({
-    world: "a2a-component",
-    path: "wit",
-    async: true,
-    with: {
-        "wasi:http/types": wasmtime_wasi_http::bindings::http::types,
-        "wasi:io/error": wasmtime_wasi_http::bindings::io::error,
-        "wasi:io/streams": wasmtime_wasi_http::bindings::io::streams,
-        "wasi:io/poll": wasmtime_wasi_http::bindings::io::poll,
-    },
-});
-
-// Use types from this module's bindgen
-use a2a::protocol::agent::Host as AgentHost;
-use a2a::protocol::types::{
-    Error as A2aError, Message, MessageSendParams, Part, Role, SendResponse, Task, TaskState,
-    TaskStatus, TextPart,
-};
 
 /// Task storage for the mock agent.
 ///
 /// Maintains task state across HTTP requests for the WASM server tests.
+/// Tasks are keyed by tenant and ID, so tenants only see their own tasks.
 #[derive(Default)]
 pub struct TaskStore {
-    tasks: HashMap<String, Task>,
+    tasks: HashMap<(Option<String>, String), Task>,
 }
 
 impl TaskStore {
@@ -57,7 +32,7 @@ impl TaskStore {
         }
     }
 
-    pub fn create_task(&mut self, message: &Message) -> Task {
+    pub fn create_task(&mut self, tenant: Option<String>, message: &Message) -> Task {
         let id = uuid::Uuid::new_v4().to_string();
         let context_id = message
             .context_id
@@ -73,27 +48,86 @@ impl TaskStore {
                     role: Role::Agent,
                     parts: vec![Part::Text(TextPart {
                         text: "Hello World".to_string(),
+                        metadata: None,
                     })],
                     message_id: Some(uuid::Uuid::new_v4().to_string()),
                     task_id: Some(id.clone()),
                     context_id: None,
+                    metadata: None,
                 }),
                 timestamp: Some(chrono::Utc::now().to_rfc3339()),
             },
             history: None,
             artifacts: None,
+            metadata: None,
         };
 
-        self.tasks.insert(id, task.clone());
+        self.tasks.insert((tenant, id), task.clone());
         task
     }
 
-    pub fn get_task(&self, id: &str) -> Option<Task> {
-        self.tasks.get(id).cloned()
+    /// Create a completed task, returning the events that stream its progress:
+    /// the submitted task, a working status, a "Hello World" artifact and the
+    /// final completed status.
+    pub fn create_streaming_task(
+        &mut self,
+        tenant: Option<String>,
+        message: &Message,
+    ) -> VecDeque<StreamResponse> {
+        let mut task = self.create_task(tenant, message);
+        let completed = task.status.clone();
+        task.status = TaskStatus {
+            state: TaskState::Submitted,
+            message: None,
+            timestamp: completed.timestamp.clone(),
+        };
+
+        let status_update = |status: TaskStatus, final_: bool| {
+            StreamResponse::StatusUpdate(TaskStatusUpdateEvent {
+                task_id: task.id.clone(),
+                context_id: task.context_id.clone(),
+                status,
+                final_,
+                metadata: None,
+            })
+        };
+        VecDeque::from([
+            StreamResponse::Task(task.clone()),
+            status_update(
+                TaskStatus {
+                    state: TaskState::Working,
+                    message: None,
+                    timestamp: completed.timestamp.clone(),
+                },
+                false,
+            ),
+            StreamResponse::ArtifactUpdate(TaskArtifactUpdateEvent {
+                task_id: task.id.clone(),
+                context_id: task.context_id.clone(),
+                artifact: Artifact {
+                    artifact_id: "greeting".to_string(),
+                    name: None,
+                    description: None,
+                    parts: vec![Part::Text(TextPart {
+                        text: "Hello World".to_string(),
+                        metadata: None,
+                    })],
+                    metadata: None,
+                },
+                append: false,
+                last_chunk: true,
+                metadata: None,
+            }),
+            status_update(completed, true),
+        ])
+    }
+
+    pub fn get_task(&self, tenant: Option<String>, id: &str) -> Option<Task> {
+        self.tasks.get(&(tenant, id.to_string())).cloned()
     }
 
-    pub fn cancel_task(&mut self, id: &str) -> Option<Task> {
-        self.tasks.get_mut(id).map(|task| {
+    pub fn cancel_task(&mut self, tenant: Option<String>, id: &str) -> Option<Task> {
+        self.tasks.get_mut(&(tenant, id.to_string())).map(|task| {
             task.status.state = TaskState::Canceled;
             task.status.timestamp = Some(chrono::Utc::now().to_rfc3339());
             task.clone()
@@ -107,79 +141,119 @@ const WASM_PATH: &str = concat!(
     "/../../target/wasm32-wasip2/release/a2a_wasm_component.wasm"
 );
 
-/// Server state shared across requests
-struct ServerState {
-    engine: Engine,
-    component: Component,
-    linker: Linker<ServerClientState>,
-    task_store: Arc<Mutex<TaskStore>>,
-    port: u16,
-}
+/// Extension the mock agent declares; sent messages report whether it was
+/// activated in their task's metadata.
+const ECHO_EXTENSION: &str = "https://example.com/extensions/echo/v1";
+/// Extension the `premium` tenant's card requires.
+const PREMIUM_EXTENSION: &str = "https://example.com/extensions/premium/v1";
 
-/// Per-request state
-struct ServerClientState {
-    wasi: WasiCtx,
-    http: WasiHttpCtx,
-    table: ResourceTable,
-    task_store: Arc<Mutex<TaskStore>>,
+/// Mock agent backed by an in-memory task store.
+struct MockAgent {
+    task_store: Mutex<TaskStore>,
     port: u16,
 }
 
-impl WasiView for ServerClientState {
-    fn ctx(&mut self) -> &mut WasiCtx {
-        &mut self.wasi
-    }
-    fn table(&mut self) -> &mut ResourceTable {
-        &mut self.table
-    }
-}
-
-impl WasiHttpView for ServerClientState {
-    fn ctx(&mut self) -> &mut WasiHttpCtx {
-        &mut self.http
-    }
-    fn table(&mut self) -> &mut ResourceTable {
-        &mut self.table
-    }
-}
-
-impl AgentHost for ServerClientState {
-    async fn get_agent_card(&mut self, _tenant: Option<String>) -> Result<String, A2aError> {
+impl Agent for MockAgent {
+    async fn get_agent_card(&self, tenant: Option<String>) -> Result<String, A2aError> {
+        // Each tenant has its own card, named and served under its prefix
         let port = self.port;
+        let mut extensions = format!(r#"{{"uri":"{ECHO_EXTENSION}"}}"#);
+        if tenant.as_deref() == Some("premium") {
+            extensions.push_str(&format!(
+                r#",{{"uri":"{PREMIUM_EXTENSION}","required":true}}"#
+            ));
+        }
+        let (name, path) = match tenant {
+            Some(tenant) => (format!("test-wasm-agent ({tenant})"), format!("/{tenant}")),
+            None => ("test-wasm-agent".to_string(), String::new()),
+        };
         Ok(format!(
-            r#"{{"name":"test-wasm-agent","description":"Test WASM agent","url":"http://localhost:{}","version":"1.0.0","capabilities":{{}},"defaultInputModes":["text"],"defaultOutputModes":["text"],"skills":[]}}"#,
-            port
+            r#"{{"name":"{name}","description":"Test WASM agent","url":"http://localhost:{port}{path}","version":"1.0.0","capabilities":{{"extensions":[{extensions}]}},"defaultInputModes":["text"],"defaultOutputModes":["text"],"skills":[]}}"#,
         ))
     }
 
     async fn on_message(
-        &mut self,
-        _tenant: Option<String>,
+        &self,
+        context: RequestContext,
         params: MessageSendParams,
     ) -> Result<SendResponse, A2aError> {
-        let task = self.task_store.lock().unwrap().create_task(&params.message);
+        let mut task = self
+            .task_store
+            .lock()
+            .unwrap()
+            .create_task(context.tenant, &params.message);
+        if !context.extensions.is_empty() {
+            let extensions = serde_json::to_string(&context.extensions).unwrap();
+            task.metadata = Some(vec![(
+                "activatedExtensions".to_string(),
+                JsonValue::Array(extensions),
+            )]);
+        }
         Ok(SendResponse::Task(task))
     }
 
+    async fn on_message_stream(
+        &self,
+        context: RequestContext,
+        params: MessageSendParams,
+    ) -> Result<EventStream, A2aError> {
+        let events = self
+            .task_store
+            .lock()
+            .unwrap()
+            .create_streaming_task(context.tenant, &params.message);
+        Ok(EventStream::from_events(events))
+    }
+
     async fn on_get_task(
-        &mut self,
-        _tenant: Option<String>,
+        &self,
+        context: RequestContext,
         name: String,
         _history_length: Option<u32>,
     ) -> Result<Option<Task>, A2aError> {
         // name is resource name in format "tasks/{task_id}", extract the task_id
         let task_id = name.strip_prefix("tasks/").unwrap_or(&name);
-        Ok(self.task_store.lock().unwrap().get_task(task_id))
+        Ok(self
+            .task_store
+            .lock()
+            .unwrap()
+            .get_task(context.tenant, task_id))
     }
 
     async fn on_cancel_task(
-        &mut self,
-        _tenant: Option<String>,
+        &self,
+        context: RequestContext,
         name: String,
     ) -> Result<Option<Task>, A2aError> {
         // name is resource name in format "tasks/{task_id}", extract the task_id
         let task_id = name.strip_prefix("tasks/").unwrap_or(&name);
-        Ok(self.task_store.lock().unwrap().cancel_task(task_id))
+        Ok(self
+            .task_store
+            .lock()
+            .unwrap()
+            .cancel_task(context.tenant, task_id))
+    }
+
+    async fn on_subscribe_task(
+        &self,
+        context: RequestContext,
+        name: String,
+    ) -> Result<EventStream, A2aError> {
+        // Tasks complete immediately, so a subscription yields the task and ends
+        let task_id = name.strip_prefix("tasks/").unwrap_or(&name);
+        let task = self
+            .task_store
+            .lock()
+            .unwrap()
+            .get_task(context.tenant, task_id);
+        match task {
+            Some(task) => Ok(EventStream::from_events([StreamResponse::Task(task)])),
+            None => Err(A2aError {
+                code: -32001,
+                message: "Task not found".to_string(),
+                data: None,
+            }),
+        }
     }
 }
 
@@ -193,16 +267,40 @@ pub struct WasmServer {
 impl WasmServer {
     /// Start the WASM server on a dynamic port.
     pub async fn start() -> Self {
-        let (shutdown_tx, shutdown_rx) = oneshot::channel();
-        let (ready_tx, ready_rx) = oneshot::channel::<u16>();
+        // Bind to port 0 (OS assigns free port)
+        let addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
+        let listener = TcpListener::bind(addr).await.expect("Failed to bind");
+        let port = listener
+            .local_addr()
+            .expect("Failed to get local addr")
+            .port();
+
+        let agent = MockAgent {
+            task_store: Mutex::new(TaskStore::new()),
+            port,
+        };
+        // Accept the tenant from the X-Tenant header too, and lower the body
+        // limit so oversized requests stay cheap to send
+        let host = A2aHost::builder(agent)
+            .component_file(WASM_PATH)
+            .inherit_env()
+            .env("A2A_TENANT_HEADER", "X-Tenant")
+            .env("A2A_MAX_BODY_SIZE", "65536")
+            .build()
+            .expect("Failed to load WASM component");
 
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
         let handle = tokio::spawn(async move {
-            run_server(shutdown_rx, ready_tx).await;
+            tokio::select! {
+                _ = shutdown_rx => {}
+                result = host.serve(listener) => {
+                    if let Err(e) = result {
+                        eprintln!("Error serving: {e}");
+                    }
+                }
+            }
         });
 
-        // Wait for server to be ready and get the assigned port
-        let port = ready_rx.await.expect("Server failed to start");
-
         Self {
             shutdown_tx: Some(shutdown_tx),
             handle,
@@ -219,154 +317,3 @@ impl Drop for WasmServer {
         self.handle.abort();
     }
 }
-
-async fn run_server(mut shutdown_rx: oneshot::Receiver<()>, ready_tx: oneshot::Sender<u16>) {
-    // Setup wasmtime engine and component
-    let mut config = Config::new();
-    config.async_support(true);
-    config.wasm_component_model(true);
-    let engine = Engine::new(&config).expect("Failed to create engine");
-
-    let component =
-        Component::from_file(&engine, WASM_PATH).expect("Failed to load WASM component");
-
-    // Setup linker with all required interfaces
-    let mut linker = Linker::<ServerClientState>::new(&engine);
-    wasmtime_wasi::add_to_linker_async(&mut linker).expect("Failed to add WASI");
-    wasmtime_wasi_http::add_only_http_to_linker_async(&mut linker)
-        .expect("Failed to add WASI HTTP");
-    a2a::protocol::agent::add_to_linker(&mut linker, |state| state)
-        .expect("Failed to add agent interface");
-
-    let task_store = Arc::new(Mutex::new(TaskStore::new()));
-
-    // Bind to port 0 (OS assigns free port)
-    let addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
-    let listener = TcpListener::bind(addr).await.expect("Failed to bind");
-    let port = listener
-        .local_addr()
-        .expect("Failed to get local addr")
-        .port();
-
-    let server_state = Arc::new(ServerState {
-        engine,
-        component,
-        linker,
-        task_store,
-        port,
-    });
-
-    // Signal that we're ready with the assigned port
-    let _ = ready_tx.send(port);
-
-    loop {
-        tokio::select! {
-            _ = &mut shutdown_rx => {
-                break;
-            }
-            result = listener.accept() => {
-                match result {
-                    Ok((stream, _addr)) => {
-                        let server_state = server_state.clone();
-                        tokio::spawn(async move {
-                            let service = service_fn(move |req| {
-                                let server_state = server_state.clone();
-                                async move {
-                                    handle_request(server_state, req).await
-                                }
-                            });
-
-                            if let Err(e) = http1::Builder::new()
-                                .keep_alive(true)
-                                .serve_connection(TokioIo::new(stream), service)
-                                .await
-                            {
-                                eprintln!("Error serving connection: {e}");
-                            }
-                        });
-                    }
-                    Err(e) => {
-                        eprintln!("Error accepting connection: {e}");
-                    }
-                }
-            }
-        }
-    }
-}
-
-async fn handle_request(
-    server_state: Arc<ServerState>,
-    req: Request<Incoming>,
-) -> Result<Response<HyperOutgoingBody>, hyper::Error> {
-    // Fresh state per request
-    let wasi = WasiCtxBuilder::new().inherit_env().build();
-    let state = ServerClientState {
-        wasi,
-        http: WasiHttpCtx::new(),
-        table: ResourceTable::new(),
-        task_store: server_state.task_store.clone(),
-        port: server_state.port,
-    };
-
-    let mut store = Store::new(&server_state.engine, state);
-
-    // Convert hyper request to WASI HTTP types
-    let (sender, receiver) = tokio::sync::oneshot::channel();
-    let incoming_req = store
-        .data_mut()
-        .new_incoming_request(Scheme::Http, req)
-        .expect("Failed to create incoming request");
-    let out = store
-        .data_mut()
-        .new_response_outparam(sender)
-        .expect("Failed to create response outparam");
-
-    // Instantiate the component using our own bindgen's A2aComponent
-    let bindings =
-        A2aComponent::instantiate_async(&mut store, &server_state.component, &server_state.linker)
-            .await
-            .expect("Failed to instantiate component");
-
-    // Spawn the handler in a task
-    let handle_task = tokio::spawn(async move {
-        bindings
-            .wasi_http_incoming_handler()
-            .call_handle(store, incoming_req, out)
-            .await
-    });
-
-    // Response comes back through the oneshot channel
-    match receiver.await {
-        Ok(Ok(resp)) => Ok(resp),
-        Ok(Err(e)) => {
-            eprintln!("Response error: {e:?}");
-            Ok(Response::builder()
-                .status(500)
-                .body(HyperOutgoingBody::default())
-                .unwrap())
-        }
-        Err(_) => {
-            // Receiver dropped - check task result
-            match handle_task.await {
-                Ok(Ok(())) => Ok(Response::builder()
-                    .status(500)
-                    .body(HyperOutgoingBody::default())
-                    .unwrap()),
-                Ok(Err(e)) => {
-                    eprintln!("Handler error: {e}");
-                    Ok(Response::builder()
-                        .status(500)
-                        .body(HyperOutgoingBody::default())
-                        .unwrap())
-                }
-                Err(e) => {
-                    eprintln!("Task join error: {e}");
-                    Ok(Response::builder()
-                        .status(500)
-                        .body(HyperOutgoingBody::default())
-                        .unwrap())
-                }
-            }
-        }
-    }
-}
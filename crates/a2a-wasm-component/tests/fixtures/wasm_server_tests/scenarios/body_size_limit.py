@@ -0,0 +1,39 @@
+"""Test oversized request bodies are rejected with 413."""
+
+import asyncio
+import json
+import os
+
+import httpx
+
+# Over the 64 KiB limit the test server sets with A2A_MAX_BODY_SIZE
+OVERSIZED = 100_000
+
+
+async def main():
+    server_url = os.environ["WASM_SERVER_URL"]
+
+    async with httpx.AsyncClient() as client:
+        json_rpc = await client.post(
+            f"{server_url}/",
+            content=b"x" * OVERSIZED,
+            headers={"Content-Type": "application/json"},
+        )
+        rest = await client.post(
+            f"{server_url}/v1/message:send",
+            content=b"x" * OVERSIZED,
+            headers={"Content-Type": "application/json"},
+        )
+
+    print(json.dumps({
+        "step": "json_rpc_oversized",
+        "status_code": json_rpc.status_code,
+    }))
+    print(json.dumps({
+        "step": "rest_oversized",
+        "status_code": rest.status_code,
+    }))
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
@@ -0,0 +1,111 @@
+"""Test that the extensions activated for a request reach the agent."""
+
+import asyncio
+import json
+import os
+
+import httpx
+
+ECHO_EXTENSION = "https://example.com/extensions/echo/v1"
+UNKNOWN_EXTENSION = "https://example.com/extensions/unknown/v1"
+# Required by the card of the `premium` tenant only
+PREMIUM_EXTENSION = "https://example.com/extensions/premium/v1"
+
+MESSAGE = {
+    "messageId": "extension-1",
+    "role": "ROLE_USER",
+    "parts": [{"text": "Hello"}],
+}
+
+
+def activated(task):
+    """The extensions the agent saw activated, from the task metadata."""
+    return ((task or {}).get("metadata") or {}).get("activatedExtensions")
+
+
+async def main():
+    server_url = os.environ["WASM_SERVER_URL"]
+    headers = {
+        "Content-Type": "application/json",
+        "X-A2A-Extensions": f"{ECHO_EXTENSION}, {UNKNOWN_EXTENSION}",
+    }
+
+    async with httpx.AsyncClient() as client:
+        response = await client.post(
+            f"{server_url}/",
+            json={
+                "jsonrpc": "2.0",
+                "id": "1",
+                "method": "SendMessage",
+                "params": {"message": MESSAGE},
+            },
+            headers=headers,
+        )
+        task = response.json().get("result", {}).get("task")
+
+        print(json.dumps({
+            "step": "jsonrpc_extensions",
+            "status": response.status_code,
+            "header": response.headers.get("x-a2a-extensions"),
+            "agent_activated": activated(task),
+        }))
+
+        response = await client.post(
+            f"{server_url}/v1/message:send",
+            json={"message": MESSAGE},
+            headers=headers,
+        )
+        task = response.json().get("task")
+
+        print(json.dumps({
+            "step": "rest_extensions",
+            "status": response.status_code,
+            "header": response.headers.get("x-a2a-extensions"),
+            "agent_activated": activated(task),
+        }))
+
+        response = await client.post(
+            f"{server_url}/v1/message:send",
+            json={"message": MESSAGE},
+            headers={"Content-Type": "application/json"},
+        )
+        task = response.json().get("task")
+
+        print(json.dumps({
+            "step": "no_extensions",
+            "status": response.status_code,
+            "header": response.headers.get("x-a2a-extensions"),
+            "agent_activated": activated(task),
+        }))
+
+        # The tenant named in the params selects the card negotiated against
+        for step, requested in [
+            ("params_tenant_missing_required", ECHO_EXTENSION),
+            ("params_tenant_extensions", f"{ECHO_EXTENSION}, {PREMIUM_EXTENSION}"),
+        ]:
+            response = await client.post(
+                f"{server_url}/",
+                json={
+                    "jsonrpc": "2.0",
+                    "id": "2",
+                    "method": "SendMessage",
+                    "params": {"tenant": "premium", "message": MESSAGE},
+                },
+                headers={
+                    "Content-Type": "application/json",
+                    "X-A2A-Extensions": requested,
+                },
+            )
+            body = response.json()
+
+            print(json.dumps({
+                "step": step,
+                "status": response.status_code,
+                "header": response.headers.get("x-a2a-extensions"),
+                "error_code": (body.get("error") or {}).get("code"),
+                "agent_activated": activated((body.get("result") or {}).get("task")),
+            }))
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
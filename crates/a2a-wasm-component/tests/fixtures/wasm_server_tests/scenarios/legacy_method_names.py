@@ -0,0 +1,58 @@
+"""Test 0.2/0.3 JSON-RPC method names and params are accepted."""
+
+import asyncio
+import json
+import os
+
+import httpx
+
+
+async def rpc(client, server_url, method, params):
+    response = await client.post(
+        f"{server_url}/",
+        json={"jsonrpc": "2.0", "id": "1", "method": method, "params": params},
+        headers={"Content-Type": "application/json"},
+    )
+    return response.json()
+
+
+async def main():
+    server_url = os.environ["WASM_SERVER_URL"]
+
+    async with httpx.AsyncClient() as client:
+        sent = await rpc(client, server_url, "message/send", {
+            "message": {
+                "kind": "message",
+                "messageId": "legacy-1",
+                "role": "user",
+                "parts": [{"kind": "text", "text": "Hello"}],
+            },
+        })
+        result = sent.get("result", {})
+
+        print(json.dumps({
+            "step": "message_send",
+            "has_error": "error" in sent,
+            "kind": result.get("kind"),
+        }))
+
+        if result.get("kind") != "task":
+            return
+
+        got = await rpc(client, server_url, "tasks/get", {
+            "id": result["id"],
+            "historyLength": 1,
+        })
+        task = got.get("result") or {}
+
+        print(json.dumps({
+            "step": "tasks_get",
+            "has_error": "error" in got,
+            "kind": task.get("kind"),
+            "task_id_matches": task.get("id") == result["id"],
+            "has_context_id": "contextId" in task,
+        }))
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
@@ -0,0 +1,73 @@
+"""Test the REST binding's binary protobuf codec and content negotiation."""
+
+import asyncio
+import json
+import os
+
+import httpx
+from a2a.types import Message, Part, Role, SendMessageRequest, SendMessageResponse, Task
+
+PROTOBUF = "application/x-protobuf"
+
+
+async def main():
+    server_url = os.environ["WASM_SERVER_URL"]
+
+    request = SendMessageRequest(
+        request=Message(
+            message_id="protobuf-1",
+            role=Role.ROLE_USER,
+            parts=[Part(text="Hello over protobuf")],
+        ),
+    )
+
+    async with httpx.AsyncClient() as client:
+        sent = await client.post(
+            f"{server_url}/v1/message:send",
+            content=request.SerializeToString(),
+            headers={"Content-Type": PROTOBUF, "Accept": PROTOBUF},
+        )
+        response = SendMessageResponse.FromString(sent.content)
+
+        print(json.dumps({
+            "step": "send_protobuf",
+            "status": sent.status_code,
+            "content_type": sent.headers.get("content-type"),
+            "has_task": response.HasField("task"),
+        }))
+
+        if not response.HasField("task"):
+            return
+
+        got = await client.get(
+            f"{server_url}/v1/tasks/{response.task.id}",
+            headers={"Accept": f"{PROTOBUF}, application/json;q=0.5"},
+        )
+        task = Task.FromString(got.content)
+
+        print(json.dumps({
+            "step": "get_task_protobuf",
+            "status": got.status_code,
+            "content_type": got.headers.get("content-type"),
+            "task_id_matches": task.id == response.task.id,
+        }))
+
+        unsupported = await client.post(
+            f"{server_url}/v1/message:send",
+            content=b"hello",
+            headers={"Content-Type": "text/plain"},
+        )
+        not_acceptable = await client.get(
+            f"{server_url}/v1/tasks/{response.task.id}",
+            headers={"Accept": "text/html"},
+        )
+
+        print(json.dumps({
+            "step": "negotiation_errors",
+            "unsupported_media_type": unsupported.status_code,
+            "not_acceptable": not_acceptable.status_code,
+        }))
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
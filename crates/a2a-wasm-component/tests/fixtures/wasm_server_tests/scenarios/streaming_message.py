@@ -0,0 +1,84 @@
+"""Test SendStreamingMessage and SubscribeToTask over server-sent events."""
+
+import asyncio
+import json
+import os
+
+import httpx
+
+
+async def read_events(response):
+    """Collect the JSON data of each server-sent event."""
+    events = []
+    async for line in response.aiter_lines():
+        if line.startswith("data:"):
+            events.append(json.loads(line[len("data:"):]))
+    return events
+
+
+async def main():
+    server_url = os.environ["WASM_SERVER_URL"]
+    message = {
+        "messageId": "stream-1",
+        "role": "ROLE_USER",
+        "parts": [{"text": "Hello, streaming"}],
+    }
+
+    async with httpx.AsyncClient() as client:
+        request = {
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "SendStreamingMessage",
+            "params": {"message": message},
+        }
+        async with client.stream("POST", f"{server_url}/", json=request) as response:
+            events = await read_events(response)
+            print(json.dumps({
+                "step": "jsonrpc_stream",
+                "status": response.status_code,
+                "content_type": response.headers.get("content-type"),
+                "ids": [event["id"] for event in events],
+                "events": [next(iter(event["result"])) for event in events],
+                "final": events[-1]["result"]["statusUpdate"].get("final", False),
+            }))
+
+        async with client.stream(
+            "POST", f"{server_url}/v1/message:stream", json={"message": message}
+        ) as response:
+            events = await read_events(response)
+            print(json.dumps({
+                "step": "rest_stream",
+                "status": response.status_code,
+                "content_type": response.headers.get("content-type"),
+                "events": [next(iter(event)) for event in events],
+                "artifact_text": events[2]["artifactUpdate"]["artifact"]["parts"][0]["text"],
+            }))
+
+        task_id = events[0]["task"]["id"]
+        async with client.stream(
+            "GET", f"{server_url}/v1/tasks/{task_id}:subscribe"
+        ) as response:
+            events = await read_events(response)
+            print(json.dumps({
+                "step": "rest_subscribe",
+                "status": response.status_code,
+                "events": [next(iter(event)) for event in events],
+                "state": events[0]["task"]["status"]["state"],
+            }))
+
+        request = {
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "SubscribeToTask",
+            "params": {"name": "tasks/does-not-exist"},
+        }
+        missing = await client.post(f"{server_url}/", json=request)
+        print(json.dumps({
+            "step": "subscribe_not_found",
+            "status": missing.status_code,
+            "error_code": missing.json()["error"]["code"],
+        }))
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
@@ -0,0 +1,87 @@
+"""Test tenant resolution from path prefixes, params and the tenant header."""
+
+import asyncio
+import json
+import os
+
+import httpx
+
+
+async def rpc(client, url, method, params):
+    response = await client.post(
+        url,
+        json={"jsonrpc": "2.0", "id": "1", "method": method, "params": params},
+        headers={"Content-Type": "application/json"},
+    )
+    return response.json()
+
+
+async def main():
+    server_url = os.environ["WASM_SERVER_URL"]
+
+    async with httpx.AsyncClient() as client:
+        card = await client.get(f"{server_url}/acme/.well-known/agent-card.json")
+
+        print(json.dumps({
+            "step": "tenant_agent_card",
+            "status": card.status_code,
+            "name": card.json().get("name"),
+        }))
+
+        sent = await rpc(client, f"{server_url}/acme/", "SendMessage", {
+            "message": {
+                "messageId": "tenant-1",
+                "role": "ROLE_USER",
+                "parts": [{"text": "Hello"}],
+            },
+        })
+        task = sent.get("result", {}).get("task")
+
+        print(json.dumps({
+            "step": "tenant_send_message",
+            "has_error": "error" in sent,
+            "has_task": task is not None,
+        }))
+
+        if task is None:
+            return
+
+        task_id = task["id"]
+        prefixed = await client.get(f"{server_url}/acme/v1/tasks/{task_id}")
+        in_name = await client.get(f"{server_url}/v1/acme/tasks/{task_id}")
+        header = await client.get(
+            f"{server_url}/v1/tasks/{task_id}",
+            headers={"X-Tenant": "acme"},
+        )
+        params = await rpc(client, f"{server_url}/", "GetTask", {
+            "tenant": "acme",
+            "name": f"tasks/{task_id}",
+        })
+        other_tenant = await client.get(f"{server_url}/v1/tasks/{task_id}")
+
+        print(json.dumps({
+            "step": "tenant_get_task",
+            "path_prefix": prefixed.status_code,
+            "task_name": in_name.status_code,
+            "header": header.status_code,
+            "params_found": (params.get("result") or {}).get("id") == task_id,
+            "other_tenant": other_tenant.status_code,
+        }))
+
+        conflicting_path = await client.get(
+            f"{server_url}/acme/v1/globex/tasks/{task_id}"
+        )
+        conflicting_params = await rpc(client, f"{server_url}/acme/", "GetTask", {
+            "tenant": "globex",
+            "name": f"tasks/{task_id}",
+        })
+
+        print(json.dumps({
+            "step": "tenant_conflicts",
+            "path": conflicting_path.status_code,
+            "params_error_code": conflicting_params.get("error", {}).get("code"),
+        }))
+
+
+if __name__ == "__main__":
+    asyncio.run(main())
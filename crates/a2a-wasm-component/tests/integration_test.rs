@@ -476,3 +476,49 @@ async fn response_parts_not_empty() {
         }
     }
 }
+
+// ============ Discovery and Streaming (2 tests) ============
+
+/// Test that the agent card is returned as published by the agent.
+#[tokio::test]
+async fn get_agent_card_returns_card() {
+    let server = TestServer::start();
+    let mut runner = WasmRunner::new().await;
+
+    let result = runner.get_agent_card(&server.url).await;
+
+    assert!(
+        result.is_ok(),
+        "get_agent_card should succeed: {:?}",
+        result
+    );
+    let card = result.unwrap();
+    assert!(
+        card["name"].as_str().is_some_and(|name| !name.is_empty()),
+        "Card should have a name: {card}"
+    );
+    assert_eq!(card["capabilities"]["streaming"], true);
+}
+
+/// Test that a streamed message yields at least one event.
+#[tokio::test]
+async fn send_streaming_message_yields_events() {
+    let server = TestServer::start();
+    let mut runner = WasmRunner::new().await;
+
+    let result = runner.send_streaming_message(&server.url, "Hello").await;
+
+    assert!(
+        result.is_ok(),
+        "send_streaming_message should succeed: {:?}",
+        result
+    );
+    let events = result.unwrap();
+    assert!(!events.is_empty(), "Stream should yield events");
+    let event_type = events[0]["type"].as_str();
+    assert!(
+        matches!(event_type, Some("message" | "task" | "status-update")),
+        "Unexpected first event: {:?}",
+        events[0]
+    );
+}
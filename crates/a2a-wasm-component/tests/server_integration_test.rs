@@ -21,6 +21,7 @@ const SCENARIOS_DIR: &str = concat!(
 );
 
 #[test_case("agent_card_discovery" ; "agent_card_discovery")]
+#[test_case("body_size_limit" ; "body_size_limit")]
 #[test_case("send_message_success" ; "send_message_success")]
 #[test_case("send_message_creates_task" ; "send_message_creates_task")]
 #[test_case("get_task_not_found" ; "get_task_not_found")]
@@ -28,6 +29,11 @@ const SCENARIOS_DIR: &str = concat!(
 #[test_case("cancel_task_not_found" ; "cancel_task_not_found")]
 #[test_case("cancel_task_success" ; "cancel_task_success")]
 #[test_case("json_rpc_invalid_method" ; "json_rpc_invalid_method")]
+#[test_case("legacy_method_names" ; "legacy_method_names")]
+#[test_case("rest_protobuf_codec" ; "rest_protobuf_codec")]
+#[test_case("streaming_message" ; "streaming_message")]
+#[test_case("tenant_routing" ; "tenant_routing")]
+#[test_case("extension_activation" ; "extension_activation")]
 #[test_case("journey_basic_flow" ; "journey_basic_flow")]
 #[test_case("journey_error_handling" ; "journey_error_handling")]
 fn test_scenario(scenario: &str) {
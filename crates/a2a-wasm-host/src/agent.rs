@@ -0,0 +1,113 @@
+// crates/a2a-wasm-host/src/agent.rs
+//! The agent logic behind the component's `a2a:protocol/agent` import.
+
+use crate::types::{Error, MessageSendParams, RequestContext, SendResponse, StreamResponse, Task};
+use futures_core::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+include!("../../a2a-wasm-component/sdk/agent.rs");
+
+/// Agent logic called by the hosted component.
+///
+#[doc = include_str!("../../a2a-wasm-component/sdk/agent.md")]
+pub trait Agent: Send + Sync + 'static {
+    /// The agent card, as JSON.
+    fn get_agent_card(
+        &self,
+        tenant: Option<String>,
+    ) -> impl Future<Output = Result<String, Error>> + Send;
+
+    /// Handle a message, returning the task or message it produced.
+    fn on_message(
+        &self,
+        context: RequestContext,
+        params: MessageSendParams,
+    ) -> impl Future<Output = Result<SendResponse, Error>> + Send;
+
+    /// Handle a message, streaming the events it produces.
+    fn on_message_stream(
+        &self,
+        context: RequestContext,
+        params: MessageSendParams,
+    ) -> impl Future<Output = Result<EventStream, Error>> + Send {
+        let _ = (context, params);
+        async { Err(message_stream_unsupported()) }
+    }
+
+    /// Get a task by resource name (`tasks/{task_id}`).
+    fn on_get_task(
+        &self,
+        context: RequestContext,
+        name: String,
+        history_length: Option<u32>,
+    ) -> impl Future<Output = Result<Option<Task>, Error>> + Send;
+
+    /// Cancel a task by resource name (`tasks/{task_id}`).
+    fn on_cancel_task(
+        &self,
+        context: RequestContext,
+        name: String,
+    ) -> impl Future<Output = Result<Option<Task>, Error>> + Send;
+
+    /// Stream the events of an existing task, by resource name
+    /// (`tasks/{task_id}`).
+    fn on_subscribe_task(
+        &self,
+        context: RequestContext,
+        name: String,
+    ) -> impl Future<Output = Result<EventStream, Error>> + Send {
+        let _ = (context, name);
+        async { Err(subscribe_task_unsupported()) }
+    }
+}
+
+/// Events of a streaming request, handed to the component as an
+/// `event-stream` resource.
+///
+/// The component relays each event to the client as it arrives. An error
+/// ends the stream.
+pub struct EventStream {
+    inner: Pin<Box<dyn Stream<Item = Result<StreamResponse, Error>> + Send>>,
+}
+
+impl EventStream {
+    /// Wrap a stream of events.
+    pub fn new<S>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<StreamResponse, Error>> + Send + 'static,
+    {
+        Self {
+            inner: Box::pin(stream),
+        }
+    }
+
+    /// A stream of events known up front.
+    pub fn from_events<I>(events: I) -> Self
+    where
+        I: IntoIterator<Item = StreamResponse>,
+        I::IntoIter: Send + Unpin + 'static,
+    {
+        Self::new(Iter(events.into_iter()))
+    }
+}
+
+impl Stream for EventStream {
+    type Item = Result<StreamResponse, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+/// A stream over an iterator that is always ready.
+struct Iter<I>(I);
+
+impl<I: Iterator<Item = StreamResponse> + Unpin> Stream for Iter<I> {
+    type Item = Result<StreamResponse, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.0.next().map(Ok))
+    }
+}
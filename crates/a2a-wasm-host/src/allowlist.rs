@@ -0,0 +1,187 @@
+// crates/a2a-wasm-host/src/allowlist.rs
+//! Outgoing HTTP allowlists.
+
+use crate::error::{Error, Result};
+
+/// Hosts the component may send outgoing HTTP requests to.
+///
+/// An empty allowlist denies every request. Patterns are matched against
+/// the authority of each request:
+///
+/// - `api.example.com` matches that host on any port
+/// - `*.example.com` matches any subdomain of `example.com`, but not
+///   `example.com` itself
+/// - `*` matches any host
+/// - a `:port` suffix (`localhost:8080`, `*.example.com:443`) restricts the
+///   pattern to that port; requests without an explicit port use 80 or 443
+///
+/// Hosts match case-insensitively. IPv6 hosts are written in brackets
+/// (`[::1]:8080`).
+#[derive(Debug, Clone, Default)]
+pub struct Allowlist {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    host: HostPattern,
+    port: Option<u16>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum HostPattern {
+    Any,
+    Exact(String),
+    /// Subdomains of a domain, stored with its leading dot (`.example.com`)
+    Subdomains(String),
+}
+
+impl Allowlist {
+    /// An allowlist that denies every request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An allowlist that allows every request.
+    pub fn any() -> Self {
+        Self {
+            rules: vec![Rule {
+                host: HostPattern::Any,
+                port: None,
+            }],
+        }
+    }
+
+    /// Allow the hosts matching `pattern`.
+    pub fn allow(&mut self, pattern: &str) -> Result<()> {
+        let rule = Rule::parse(pattern)
+            .ok_or_else(|| Error::InvalidAllowlistPattern(pattern.to_string()))?;
+        self.rules.push(rule);
+        Ok(())
+    }
+
+    /// Whether a request to `host` on `port` is allowed.
+    pub fn is_allowed(&self, host: &str, port: u16) -> bool {
+        let host = host.to_ascii_lowercase();
+        self.rules.iter().any(|rule| rule.matches(&host, port))
+    }
+}
+
+impl Rule {
+    fn parse(pattern: &str) -> Option<Self> {
+        let pattern = pattern.trim().to_ascii_lowercase();
+        let (host, port) = split_port(&pattern)?;
+        let host = if host == "*" {
+            HostPattern::Any
+        } else if let Some(domain) = host.strip_prefix("*.") {
+            if !is_valid_host(domain) {
+                return None;
+            }
+            HostPattern::Subdomains(format!(".{domain}"))
+        } else {
+            if !is_valid_host(host) {
+                return None;
+            }
+            HostPattern::Exact(host.to_string())
+        };
+        Some(Self { host, port })
+    }
+
+    fn matches(&self, host: &str, port: u16) -> bool {
+        if self.port.is_some_and(|allowed| allowed != port) {
+            return false;
+        }
+        match &self.host {
+            HostPattern::Any => true,
+            HostPattern::Exact(allowed) => host == allowed,
+            HostPattern::Subdomains(domain) => {
+                host.len() > domain.len() && host.ends_with(domain.as_str())
+            }
+        }
+    }
+}
+
+/// Split an optional `:port` off a pattern, keeping bracketed IPv6 hosts
+/// whole.
+fn split_port(pattern: &str) -> Option<(&str, Option<u16>)> {
+    let (host, port) = match pattern.strip_prefix('[') {
+        Some(rest) => {
+            let end = rest.find(']')? + 2;
+            let (host, rest) = pattern.split_at(end);
+            match rest {
+                "" => (host, None),
+                _ => (host, Some(rest.strip_prefix(':')?)),
+            }
+        }
+        None => match pattern.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (pattern, None),
+        },
+    };
+    let port = port.map(str::parse).transpose().ok()?;
+    Some((host, port))
+}
+
+fn is_valid_host(host: &str) -> bool {
+    if host.starts_with('[') {
+        return host.len() > 2 && host.ends_with(']');
+    }
+    !host.is_empty() && !host.contains(['*', '/', ':', '[', ']'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn allowing(patterns: &[&str]) -> Allowlist {
+        let mut allowlist = Allowlist::new();
+        for pattern in patterns {
+            allowlist.allow(pattern).unwrap();
+        }
+        allowlist
+    }
+
+    #[test]
+    fn test_empty_denies_everything() {
+        assert!(!Allowlist::new().is_allowed("example.com", 443));
+        assert!(Allowlist::any().is_allowed("example.com", 443));
+    }
+
+    #[test]
+    fn test_exact_hosts() {
+        let allowlist = allowing(&["api.example.com", "localhost:8080"]);
+        assert!(allowlist.is_allowed("api.example.com", 443));
+        assert!(allowlist.is_allowed("API.Example.com", 80));
+        assert!(!allowlist.is_allowed("example.com", 443));
+        assert!(allowlist.is_allowed("localhost", 8080));
+        assert!(!allowlist.is_allowed("localhost", 8081));
+    }
+
+    #[test]
+    fn test_wildcards() {
+        let allowlist = allowing(&["*.example.com:443"]);
+        assert!(allowlist.is_allowed("api.example.com", 443));
+        assert!(allowlist.is_allowed("a.b.example.com", 443));
+        assert!(!allowlist.is_allowed("example.com", 443));
+        assert!(!allowlist.is_allowed("badexample.com", 443));
+        assert!(!allowlist.is_allowed("api.example.com", 80));
+
+        assert!(allowing(&["*:443"]).is_allowed("anything", 443));
+        assert!(!allowing(&["*:443"]).is_allowed("anything", 80));
+    }
+
+    #[test]
+    fn test_ipv6_hosts() {
+        let allowlist = allowing(&["[::1]:8080"]);
+        assert!(allowlist.is_allowed("[::1]", 8080));
+        assert!(!allowlist.is_allowed("[::1]", 80));
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        let mut allowlist = Allowlist::new();
+        for pattern in ["", ":80", "api.*.com", "*.", "example.com:http", "[::1"] {
+            assert!(allowlist.allow(pattern).is_err(), "{pattern:?}");
+        }
+    }
+}
@@ -0,0 +1,26 @@
+// crates/a2a-wasm-host/src/error.rs
+use thiserror::Error;
+
+/// Host errors.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("no component configured")]
+    MissingComponent,
+
+    #[error("invalid allowlist pattern: {0:?}")]
+    InvalidAllowlistPattern(String),
+
+    #[error("wasmtime error: {0:#}")]
+    Wasmtime(#[from] wasmtime::Error),
+
+    #[error("component sent no response")]
+    NoResponse,
+
+    #[error("component error response: {0}")]
+    Response(wasmtime_wasi_http::bindings::http::types::ErrorCode),
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
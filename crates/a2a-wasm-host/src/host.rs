@@ -0,0 +1,262 @@
+// crates/a2a-wasm-host/src/host.rs
+//! Loading the component and serving it over HTTP.
+
+use crate::agent::Agent;
+use crate::allowlist::Allowlist;
+use crate::bindings::A2aComponentPre;
+use crate::error::{Error, Result};
+use crate::state::HostState;
+use hyper::body::{Body, Bytes};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use wasmtime::component::{Component, Linker, ResourceTable};
+use wasmtime::{Config, Engine, Store};
+use wasmtime_wasi::WasiCtxBuilder;
+use wasmtime_wasi_http::bindings::http::types::Scheme;
+use wasmtime_wasi_http::body::HyperOutgoingBody;
+use wasmtime_wasi_http::io::TokioIo;
+use wasmtime_wasi_http::{WasiHttpCtx, WasiHttpView};
+
+/// Where the component comes from.
+enum Source {
+    File(PathBuf),
+    Bytes(Vec<u8>),
+}
+
+/// Builder for [`A2aHost`].
+pub struct A2aHostBuilder<A> {
+    agent: A,
+    source: Option<Source>,
+    allow_outgoing: Vec<String>,
+    allow_any_outgoing: bool,
+    env: Vec<(String, String)>,
+    inherit_env: bool,
+    inherit_stdio: bool,
+}
+
+impl<A: Agent> A2aHostBuilder<A> {
+    /// Load the component from a `.wasm` file.
+    pub fn component_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.source = Some(Source::File(path.into()));
+        self
+    }
+
+    /// Load the component from its binary (or text) encoding.
+    pub fn component_bytes(mut self, bytes: impl Into<Vec<u8>>) -> Self {
+        self.source = Some(Source::Bytes(bytes.into()));
+        self
+    }
+
+    /// Allow outgoing requests to hosts matching `pattern`.
+    ///
+    /// Outgoing requests are denied unless a pattern allows them. Patterns
+    /// are exact hosts (`api.example.com`), subdomain wildcards
+    /// (`*.example.com`) or `*`, optionally restricted to a port
+    /// (`localhost:8080`). See [`Allowlist`] for the details.
+    pub fn allow_outgoing(mut self, pattern: impl Into<String>) -> Self {
+        self.allow_outgoing.push(pattern.into());
+        self
+    }
+
+    /// Allow outgoing requests to any host.
+    pub fn allow_any_outgoing(mut self) -> Self {
+        self.allow_any_outgoing = true;
+        self
+    }
+
+    /// Set an environment variable for the component, such as
+    /// `A2A_TENANT_HEADER`.
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Pass the host's environment variables to the component.
+    pub fn inherit_env(mut self) -> Self {
+        self.inherit_env = true;
+        self
+    }
+
+    /// Pass the host's stdio to the component, so its logs show up.
+    pub fn inherit_stdio(mut self) -> Self {
+        self.inherit_stdio = true;
+        self
+    }
+
+    /// Compile the component and link it against the agent.
+    pub fn build(self) -> Result<A2aHost<A>> {
+        let mut allowlist = if self.allow_any_outgoing {
+            Allowlist::any()
+        } else {
+            Allowlist::new()
+        };
+        for pattern in &self.allow_outgoing {
+            allowlist.allow(pattern)?;
+        }
+
+        let mut config = Config::new();
+        config.async_support(true);
+        config.wasm_component_model(true);
+        let engine = Engine::new(&config)?;
+
+        let component = match self.source.ok_or(Error::MissingComponent)? {
+            Source::File(path) => Component::from_file(&engine, path)?,
+            Source::Bytes(bytes) => Component::new(&engine, bytes)?,
+        };
+
+        let mut linker = Linker::<HostState<A>>::new(&engine);
+        wasmtime_wasi::add_to_linker_async(&mut linker)?;
+        wasmtime_wasi_http::add_only_http_to_linker_async(&mut linker)?;
+        crate::bindings::a2a::protocol::agent::add_to_linker(&mut linker, |state| state)?;
+        let pre = A2aComponentPre::new(linker.instantiate_pre(&component)?)?;
+
+        Ok(A2aHost {
+            inner: Arc::new(Inner {
+                agent: Arc::new(self.agent),
+                pre,
+                allowlist: Arc::new(allowlist),
+                env: self.env,
+                inherit_env: self.inherit_env,
+                inherit_stdio: self.inherit_stdio,
+            }),
+        })
+    }
+}
+
+/// An A2A component running under wasmtime, backed by an [`Agent`].
+///
+/// Every request gets a fresh instance of the component, so requests share
+/// nothing but the agent. Cloning the host is cheap.
+pub struct A2aHost<A> {
+    inner: Arc<Inner<A>>,
+}
+
+struct Inner<A> {
+    agent: Arc<A>,
+    pre: A2aComponentPre<HostState<A>>,
+    allowlist: Arc<Allowlist>,
+    env: Vec<(String, String)>,
+    inherit_env: bool,
+    inherit_stdio: bool,
+}
+
+impl<A> Clone for A2aHost<A> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<A: Agent> A2aHost<A> {
+    /// Start building a host for `agent`.
+    pub fn builder(agent: A) -> A2aHostBuilder<A> {
+        A2aHostBuilder {
+            agent,
+            source: None,
+            allow_outgoing: Vec::new(),
+            allow_any_outgoing: false,
+            env: Vec::new(),
+            inherit_env: false,
+            inherit_stdio: false,
+        }
+    }
+
+    /// The agent behind the component.
+    pub fn agent(&self) -> &A {
+        &self.inner.agent
+    }
+
+    /// Accept connections on `listener` and serve each with the component.
+    ///
+    /// Runs until accepting fails. Requests the component fails to answer
+    /// get a 500 response.
+    pub async fn serve(&self, listener: TcpListener) -> Result<()> {
+        loop {
+            let (stream, _addr) = listener.accept().await?;
+            let host = self.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |request| {
+                    let host = host.clone();
+                    async move { Ok::<_, hyper::Error>(host.handle_or_500(request).await) }
+                });
+                // Connection errors only concern that client
+                let _ = http1::Builder::new()
+                    .keep_alive(true)
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await;
+            });
+        }
+    }
+
+    /// Handle a single request with a fresh instance of the component.
+    pub async fn handle<B>(&self, request: Request<B>) -> Result<Response<HyperOutgoingBody>>
+    where
+        B: Body<Data = Bytes, Error = hyper::Error> + Send + Sync + 'static,
+    {
+        let mut store = Store::new(self.inner.pre.engine(), self.new_state());
+        let request = store
+            .data_mut()
+            .new_incoming_request(Scheme::Http, request)?;
+        let (sender, receiver) = tokio::sync::oneshot::channel();
+        let out = store.data_mut().new_response_outparam(sender)?;
+        let bindings = self.inner.pre.instantiate_async(&mut store).await?;
+
+        // The handler keeps running after it responds, to stream the body
+        let task = tokio::spawn(async move {
+            bindings
+                .wasi_http_incoming_handler()
+                .call_handle(store, request, out)
+                .await
+        });
+
+        match receiver.await {
+            Ok(Ok(response)) => Ok(response),
+            Ok(Err(code)) => Err(Error::Response(code)),
+            // The handler returned or trapped without responding
+            Err(_) => match task.await {
+                Ok(Ok(())) => Err(Error::NoResponse),
+                Ok(Err(e)) => Err(Error::Wasmtime(e)),
+                Err(e) => Err(Error::Wasmtime(e.into())),
+            },
+        }
+    }
+
+    async fn handle_or_500<B>(&self, request: Request<B>) -> Response<HyperOutgoingBody>
+    where
+        B: Body<Data = Bytes, Error = hyper::Error> + Send + Sync + 'static,
+    {
+        match self.handle(request).await {
+            Ok(response) => response,
+            Err(_) => {
+                let mut response = Response::new(HyperOutgoingBody::default());
+                *response.status_mut() = hyper::StatusCode::INTERNAL_SERVER_ERROR;
+                response
+            }
+        }
+    }
+
+    fn new_state(&self) -> HostState<A> {
+        let mut wasi = WasiCtxBuilder::new();
+        if self.inner.inherit_env {
+            wasi.inherit_env();
+        }
+        if self.inner.inherit_stdio {
+            wasi.inherit_stdio();
+        }
+        for (key, value) in &self.inner.env {
+            wasi.env(key, value);
+        }
+        HostState {
+            agent: self.inner.agent.clone(),
+            allowlist: self.inner.allowlist.clone(),
+            wasi: wasi.build(),
+            http: WasiHttpCtx::new(),
+            table: ResourceTable::new(),
+        }
+    }
+}
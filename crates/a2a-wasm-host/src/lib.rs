@@ -0,0 +1,96 @@
+// crates/a2a-wasm-host/src/lib.rs
+//! Embed the A2A WASM component in a native Rust host.
+//!
+//! The `a2a-wasm-component` crate builds a component that speaks the A2A
+//! protocol bindings (JSON-RPC, REST, SSE) over `wasi:http/incoming-handler`
+//! and imports its agent logic through the `a2a:protocol/agent` interface.
+//! This crate runs that component under wasmtime: implement [`Agent`] for
+//! your agent logic, and [`A2aHost`] serves the component over a native HTTP
+//! listener, calling your agent for every request.
+//!
+//! # Usage
+//!
+//! ```no_run
+//! use a2a_wasm_host::types::{Error, MessageSendParams, RequestContext, SendResponse, Task};
+//! use a2a_wasm_host::{A2aHost, Agent};
+//!
+//! struct EchoAgent;
+//!
+//! impl Agent for EchoAgent {
+//!     async fn get_agent_card(&self, _tenant: Option<String>) -> Result<String, Error> {
+//!         Ok(r#"{"name":"echo","description":"Echo agent","version":"1.0.0"}"#.into())
+//!     }
+//!
+//!     async fn on_message(
+//!         &self,
+//!         _context: RequestContext,
+//!         params: MessageSendParams,
+//!     ) -> Result<SendResponse, Error> {
+//!         Ok(SendResponse::Message(params.message))
+//!     }
+//!
+//!     async fn on_get_task(
+//!         &self,
+//!         _context: RequestContext,
+//!         _name: String,
+//!         _history_length: Option<u32>,
+//!     ) -> Result<Option<Task>, Error> {
+//!         Ok(None)
+//!     }
+//!
+//!     async fn on_cancel_task(
+//!         &self,
+//!         _context: RequestContext,
+//!         _name: String,
+//!     ) -> Result<Option<Task>, Error> {
+//!         Ok(None)
+//!     }
+//! }
+//!
+//! # async fn run() -> a2a_wasm_host::Result<()> {
+//! let host = A2aHost::builder(EchoAgent)
+//!     .component_file("target/wasm32-wasip2/release/a2a_wasm_component.wasm")
+//!     .allow_outgoing("*.example.com")
+//!     .build()?;
+//!
+//! let listener = tokio::net::TcpListener::bind("127.0.0.1:8080").await?;
+//! host.serve(listener).await
+//! # }
+//! ```
+//!
+//! # Outgoing HTTP
+//!
+//! The component makes outgoing requests when it acts as a client of other
+//! agents (the exported `a2a:protocol/client` interface). Outgoing requests
+//! are denied unless their authority matches the host's [`Allowlist`]; see
+//! [`A2aHostBuilder::allow_outgoing`] for the pattern syntax.
+
+pub mod agent;
+pub mod allowlist;
+pub mod error;
+pub mod host;
+mod state;
+
+pub use agent::{Agent, EventStream};
+pub use allowlist::Allowlist;
+pub use error::{Error, Result};
+pub use host::{A2aHost, A2aHostBuilder};
+pub use wasmtime_wasi_http::body::HyperOutgoingBody;
+
+/// The WIT types the agent interface is written in.
+pub use bindings::a2a::protocol::types;
+
+mod bindings {
+    wasmtime::component::bindgen!({
+        world: "a2a-component",
+        path: "../a2a-wasm-component/wit",
+        async: true,
+        with: {
+            "wasi:http/types": wasmtime_wasi_http::bindings::http::types,
+            "wasi:io/error": wasmtime_wasi_http::bindings::io::error,
+            "wasi:io/streams": wasmtime_wasi_http::bindings::io::streams,
+            "wasi:io/poll": wasmtime_wasi_http::bindings::io::poll,
+            "a2a:protocol/agent/event-stream": crate::agent::EventStream,
+        },
+    });
+}
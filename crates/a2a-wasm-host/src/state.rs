@@ -0,0 +1,143 @@
+// crates/a2a-wasm-host/src/state.rs
+//! Per-request store state: WASI contexts and the agent import.
+
+use crate::agent::{Agent, EventStream};
+use crate::allowlist::Allowlist;
+use crate::bindings::a2a::protocol::agent::{Host, HostEventStream};
+use crate::types::{Error, MessageSendParams, RequestContext, SendResponse, StreamResponse, Task};
+use futures_core::Stream;
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::sync::Arc;
+use wasmtime::component::{Resource, ResourceTable};
+use wasmtime_wasi::{WasiCtx, WasiView};
+use wasmtime_wasi_http::bindings::http::types::ErrorCode;
+use wasmtime_wasi_http::body::HyperOutgoingBody;
+use wasmtime_wasi_http::types::{
+    HostFutureIncomingResponse, OutgoingRequestConfig, default_send_request,
+};
+use wasmtime_wasi_http::{HttpResult, WasiHttpCtx, WasiHttpView};
+
+/// State of the store a single request runs in.
+pub(crate) struct HostState<A> {
+    pub(crate) agent: Arc<A>,
+    pub(crate) allowlist: Arc<Allowlist>,
+    pub(crate) wasi: WasiCtx,
+    pub(crate) http: WasiHttpCtx,
+    pub(crate) table: ResourceTable,
+}
+
+impl<A: Agent> WasiView for HostState<A> {
+    fn ctx(&mut self) -> &mut WasiCtx {
+        &mut self.wasi
+    }
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+}
+
+impl<A: Agent> WasiHttpView for HostState<A> {
+    fn ctx(&mut self) -> &mut WasiHttpCtx {
+        &mut self.http
+    }
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+
+    fn send_request(
+        &mut self,
+        request: hyper::Request<HyperOutgoingBody>,
+        config: OutgoingRequestConfig,
+    ) -> HttpResult<HostFutureIncomingResponse> {
+        let uri = request.uri();
+        let default_port = if config.use_tls { 443 } else { 80 };
+        let allowed = uri.host().is_some_and(|host| {
+            self.allowlist
+                .is_allowed(host, uri.port_u16().unwrap_or(default_port))
+        });
+        if !allowed {
+            return Err(ErrorCode::HttpRequestDenied.into());
+        }
+        Ok(default_send_request(request, config))
+    }
+}
+
+impl<A: Agent> HostState<A> {
+    fn push_stream(&mut self, stream: EventStream) -> Result<Resource<EventStream>, Error> {
+        self.table.push(stream).map_err(|e| Error {
+            code: -32603,
+            message: format!("Failed to register event stream: {e}"),
+            data: None,
+        })
+    }
+}
+
+impl<A: Agent> Host for HostState<A> {
+    async fn get_agent_card(&mut self, tenant: Option<String>) -> Result<String, Error> {
+        self.agent.get_agent_card(tenant).await
+    }
+
+    async fn on_message(
+        &mut self,
+        context: RequestContext,
+        params: MessageSendParams,
+    ) -> Result<SendResponse, Error> {
+        self.agent.on_message(context, params).await
+    }
+
+    async fn on_message_stream(
+        &mut self,
+        context: RequestContext,
+        params: MessageSendParams,
+    ) -> Result<Resource<EventStream>, Error> {
+        let stream = self.agent.on_message_stream(context, params).await?;
+        self.push_stream(stream)
+    }
+
+    async fn on_get_task(
+        &mut self,
+        context: RequestContext,
+        name: String,
+        history_length: Option<u32>,
+    ) -> Result<Option<Task>, Error> {
+        self.agent.on_get_task(context, name, history_length).await
+    }
+
+    async fn on_cancel_task(
+        &mut self,
+        context: RequestContext,
+        name: String,
+    ) -> Result<Option<Task>, Error> {
+        self.agent.on_cancel_task(context, name).await
+    }
+
+    async fn on_subscribe_task(
+        &mut self,
+        context: RequestContext,
+        name: String,
+    ) -> Result<Resource<EventStream>, Error> {
+        let stream = self.agent.on_subscribe_task(context, name).await?;
+        self.push_stream(stream)
+    }
+}
+
+impl<A: Agent> HostEventStream for HostState<A> {
+    async fn next(
+        &mut self,
+        stream: Resource<EventStream>,
+    ) -> Result<Option<StreamResponse>, Error> {
+        let stream = self.table.get_mut(&stream).map_err(|e| Error {
+            code: -32603,
+            message: format!("Unknown event stream: {e}"),
+            data: None,
+        })?;
+        poll_fn(|cx| Pin::new(&mut *stream).poll_next(cx))
+            .await
+            .transpose()
+    }
+
+    async fn drop(&mut self, stream: Resource<EventStream>) -> wasmtime::Result<()> {
+        self.table.delete(stream)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+// examples/echo-agent/src/lib.rs
+//! An agent component that echoes messages back.
+//!
+//! `SendMessage` replies with the text of the message; streaming replies
+//! with one message per word. Build it and compose it with the protocol
+//! component:
+//!
+//! ```text
+//! cargo build -p a2a-wasm-component --target wasm32-wasip2 --release
+//! cargo build -p echo-agent --target wasm32-wasip2 --release
+//! wac plug target/wasm32-wasip2/release/a2a_wasm_component.wasm \
+//!     --plug target/wasm32-wasip2/release/echo_agent.wasm -o echo.wasm
+//! wasmtime serve -S cli echo.wasm
+//! ```
+
+use a2a_agent_guest::types::{
+    Error, Message, MessageSendParams, Part, RequestContext, Role, SendResponse, StreamResponse,
+    Task, TextPart,
+};
+use a2a_agent_guest::{Agent, EventStream, export_agent};
+
+struct EchoAgent;
+
+impl Agent for EchoAgent {
+    fn get_agent_card(tenant: Option<String>) -> Result<String, Error> {
+        let path = tenant
+            .map(|tenant| format!("/{tenant}"))
+            .unwrap_or_default();
+        Ok(format!(
+            r#"{{"name":"echo-agent","description":"Echoes messages back","url":"http://localhost:8080{path}","version":"1.0.0","capabilities":{{"streaming":true}},"defaultInputModes":["text"],"defaultOutputModes":["text"],"skills":[]}}"#
+        ))
+    }
+
+    fn on_message(
+        _context: RequestContext,
+        params: MessageSendParams,
+    ) -> Result<SendResponse, Error> {
+        let text = message_text(&params.message);
+        Ok(SendResponse::Message(reply(&params.message, text)))
+    }
+
+    fn on_message_stream(
+        _context: RequestContext,
+        params: MessageSendParams,
+    ) -> Result<EventStream, Error> {
+        let message = params.message;
+        let words: Vec<String> = message_text(&message)
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+        // Replies are built as the protocol component pulls them
+        Ok(EventStream::from_events(words.into_iter().map(
+            move |word| StreamResponse::Message(reply(&message, word)),
+        )))
+    }
+
+    fn on_get_task(
+        _context: RequestContext,
+        _name: String,
+        _history_length: Option<u32>,
+    ) -> Result<Option<Task>, Error> {
+        // Replies are messages, so there are no tasks
+        Ok(None)
+    }
+
+    fn on_cancel_task(_context: RequestContext, _name: String) -> Result<Option<Task>, Error> {
+        Ok(None)
+    }
+}
+
+export_agent!(EchoAgent);
+
+/// The text parts of a message, joined by newlines.
+fn message_text(message: &Message) -> String {
+    message
+        .parts
+        .iter()
+        .filter_map(|part| match part {
+            Part::Text(text) => Some(text.text.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn reply(message: &Message, text: String) -> Message {
+    Message {
+        role: Role::Agent,
+        parts: vec![Part::Text(TextPart {
+            text,
+            metadata: None,
+        })],
+        message_id: None,
+        task_id: None,
+        context_id: message.context_id.clone(),
+        metadata: None,
+    }
+}